@@ -0,0 +1,173 @@
+//! `sentinel diff` - compare a server's advertised tools to the registry.
+//!
+//! Reads a JSON dump of a server's tool list, checks every tool against the
+//! registry and prints which tools are unknown and which have drifted, with
+//! the drift severity and individual changes. Meant for scheduled integrity
+//! checks: the exit status is non-zero when any tool drifted critically.
+//!
+//! ## Dump Format
+//!
+//! Any of the following, where each tool is a `ToolSchema` object:
+//!
+//! - a bare array of tools
+//! - an object with a `tools` array (a `tools/list` result)
+//! - a JSON-RPC response whose `result` holds a `tools` array
+
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use sentinel_registry::canonicalize::parse_strict;
+use sentinel_registry::{DriftLevel, DriftReport, RegistryGuard, ToolSchema, VerifyResult};
+use serde_json::Value;
+
+/// Outcome of a diff run.
+#[derive(Debug, Default)]
+pub struct DiffSummary {
+    /// Number of tools in the dump.
+    pub checked: usize,
+    /// Tools matching the registry exactly.
+    pub unchanged: usize,
+    /// Tools not present in the registry.
+    pub unknown: Vec<String>,
+    /// Registered tools whose schema drifted, with their reports.
+    pub drifted: Vec<(String, DriftReport)>,
+}
+
+impl DiffSummary {
+    /// Returns true if any tool drifted at `Critical` severity.
+    pub fn has_critical(&self) -> bool {
+        self.drifted
+            .iter()
+            .any(|(_, report)| report.level == DriftLevel::Critical)
+    }
+}
+
+/// Terminal styles used in the report.
+#[derive(Clone, Copy)]
+enum Style {
+    Red,
+    Yellow,
+    Cyan,
+    Magenta,
+    Green,
+}
+
+impl Style {
+    const fn code(self) -> &'static str {
+        match self {
+            Self::Red => "1;31",
+            Self::Yellow => "33",
+            Self::Cyan => "36",
+            Self::Magenta => "35",
+            Self::Green => "32",
+        }
+    }
+
+    fn paint(self, text: &str, color: bool) -> String {
+        if color {
+            format!("\x1b[{}m{}\x1b[0m", self.code(), text)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Label and style for a drift level.
+fn level_label(level: DriftLevel) -> (&'static str, Style) {
+    match level {
+        DriftLevel::Critical => ("CRITICAL", Style::Red),
+        DriftLevel::Major => ("MAJOR", Style::Yellow),
+        DriftLevel::Minor | DriftLevel::None => ("MINOR", Style::Cyan),
+    }
+}
+
+/// Parses a tool dump in any of the supported shapes.
+fn parse_dump(raw: &str) -> anyhow::Result<Vec<ToolSchema>> {
+    let value = parse_strict(raw)?;
+    let tools = match value {
+        Value::Array(_) => value,
+        Value::Object(mut map) => match map.remove("tools") {
+            Some(tools) => tools,
+            None => match map.remove("result") {
+                Some(Value::Object(mut result)) => result
+                    .remove("tools")
+                    .context("`result` has no `tools` array")?,
+                _ => bail!("expected a tool array, a `tools` object or a JSON-RPC response"),
+            },
+        },
+        _ => bail!("expected a tool array, a `tools` object or a JSON-RPC response"),
+    };
+    serde_json::from_value(tools).context("invalid tool schema in dump")
+}
+
+/// Checks every tool in the dump at `tools_path` against `registry`.
+pub fn diff(registry: &RegistryGuard, tools_path: &Path) -> anyhow::Result<DiffSummary> {
+    let raw = std::fs::read_to_string(tools_path)
+        .with_context(|| format!("failed to read {}", tools_path.display()))?;
+    let tools = parse_dump(&raw).with_context(|| format!("in {}", tools_path.display()))?;
+
+    let mut summary = DiffSummary {
+        checked: tools.len(),
+        unknown: tools
+            .iter()
+            .filter(|tool| registry.verify_tool(tool) == VerifyResult::Unknown)
+            .map(|tool| tool.name.clone())
+            .collect(),
+        drifted: registry.detect_drift_all(&tools),
+        ..DiffSummary::default()
+    };
+    summary.unchanged = summary
+        .checked
+        .saturating_sub(summary.unknown.len() + summary.drifted.len());
+    summary
+        .drifted
+        .sort_by(|(a, ra), (b, rb)| rb.level.cmp(&ra.level).then_with(|| a.cmp(b)));
+    Ok(summary)
+}
+
+/// Writes a human-readable report of `summary` to `out`.
+pub fn print_summary(
+    summary: &DiffSummary,
+    out: &mut impl Write,
+    color: bool,
+) -> std::io::Result<()> {
+    writeln!(
+        out,
+        "Checked {} tools: {} unchanged, {} drifted, {} unknown",
+        summary.checked,
+        summary.unchanged,
+        summary.drifted.len(),
+        summary.unknown.len()
+    )?;
+
+    for (name, report) in &summary.drifted {
+        let (label, style) = level_label(report.level);
+        writeln!(
+            out,
+            "  {} {}",
+            style.paint(&format!("{:<9}", label), color),
+            name
+        )?;
+        for change in &report.changes {
+            writeln!(out, "    - {}", change)?;
+        }
+    }
+    for name in &summary.unknown {
+        writeln!(
+            out,
+            "  {} {}",
+            Style::Magenta.paint(&format!("{:<9}", "UNKNOWN"), color),
+            name
+        )?;
+    }
+
+    let verdict = if summary.has_critical() {
+        Style::Red.paint("FAIL: critical drift detected", color)
+    } else if summary.drifted.is_empty() && summary.unknown.is_empty() {
+        Style::Green.paint("OK: all tools match the registry", color)
+    } else {
+        Style::Yellow.paint("WARN: non-critical differences found", color)
+    };
+    writeln!(out, "{}", verdict)
+}
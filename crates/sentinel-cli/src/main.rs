@@ -1,6 +1,22 @@
 //! MCP Sentinel CLI - Command-line interface for the security gateway
 
+mod diff;
+
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anyhow::Context;
 use clap::Parser;
+use sentinel_core::{HealthStatus, SentinelConfig};
+use sentinel_registry::storage::SledStorage;
+use sentinel_registry::{ReadOnlyRegistry, RegistryGuard};
+
+/// Exit status of `diff` when any tool drifted critically.
+const EXIT_CRITICAL_DRIFT: u8 = 2;
+
+/// Exit status of `status` when any component is unhealthy.
+const EXIT_UNHEALTHY: u8 = 3;
 
 #[derive(Parser)]
 #[command(name = "sentinel")]
@@ -25,10 +41,35 @@ enum Commands {
         config: String,
     },
     /// Show sentinel status
-    Status,
+    ///
+    /// Opens the registry read-only and reports NOT READY if it is missing,
+    /// unreadable or empty. Exits with status 3 if any component is
+    /// unhealthy, so it can serve as a readiness probe.
+    Status {
+        /// Registry database path (must not be open in a running gateway)
+        #[arg(short, long, default_value_os_t = SentinelConfig::default().registry.db_path)]
+        registry: PathBuf,
+        /// Print the status as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Compare a server's tool list to the registry
+    ///
+    /// Exits with status 2 if any tool drifted critically. The registry must
+    /// already exist; a missing one is an error, not an empty registry.
+    Diff {
+        /// JSON dump of the server's tools
+        tools: PathBuf,
+        /// Registry database path (must not be open in a running gateway)
+        #[arg(short, long, default_value_os_t = SentinelConfig::default().registry.db_path)]
+        registry: PathBuf,
+        /// Disable colored output
+        #[arg(long)]
+        no_color: bool,
+    },
 }
 
-fn main() -> anyhow::Result<()> {
+fn main() -> anyhow::Result<ExitCode> {
     let cli = Cli::parse();
 
     tracing_subscriber::fmt::init();
@@ -40,13 +81,49 @@ fn main() -> anyhow::Result<()> {
         Some(Commands::Check { config }) => {
             println!("Checking config: {}", config);
         }
-        Some(Commands::Status) => {
-            println!("Sentinel status: READY");
+        Some(Commands::Status { registry, json }) => {
+            let health = match ReadOnlyRegistry::open(&registry) {
+                Ok(verifier) => HealthStatus::of_registry(&verifier),
+                Err(e) => HealthStatus::unavailable(e.to_string()),
+            };
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&health)?);
+            } else {
+                let state = if health.healthy { "READY" } else { "NOT READY" };
+                println!("Sentinel status: {}", state);
+                for (name, component) in health.components() {
+                    let mark = if component.healthy { "ok" } else { "FAIL" };
+                    println!("  {:<8} {:<4} {}", name, mark, component.detail);
+                }
+            }
+
+            if !health.healthy {
+                return Ok(ExitCode::from(EXIT_UNHEALTHY));
+            }
+        }
+        Some(Commands::Diff {
+            tools,
+            registry,
+            no_color,
+        }) => {
+            let guard = SledStorage::open_existing(&registry)
+                .and_then(RegistryGuard::with_storage)
+                .with_context(|| format!("failed to open registry {}", registry.display()))?;
+            let summary = diff::diff(&guard, &tools)?;
+
+            let stdout = std::io::stdout();
+            let color = !no_color && std::env::var_os("NO_COLOR").is_none() && stdout.is_terminal();
+            diff::print_summary(&summary, &mut stdout.lock(), color)?;
+
+            if summary.has_critical() {
+                return Ok(ExitCode::from(EXIT_CRITICAL_DRIFT));
+            }
         }
         None => {
             println!("MCP Sentinel v0.1.0 - Use --help for commands");
         }
     }
 
-    Ok(())
+    Ok(ExitCode::SUCCESS)
 }
@@ -0,0 +1,110 @@
+//! End-to-end tests for `sentinel diff`.
+
+use std::process::Command;
+
+use sentinel_registry::{RegistryGuard, ToolSchema};
+use serde_json::json;
+
+fn tool(name: &str, required: &[&str]) -> ToolSchema {
+    ToolSchema {
+        name: name.to_string(),
+        description: format!("The {} tool", name),
+        input_schema: json!({
+            "type": "object",
+            "properties": {"path": {"type": "string"}, "mode": {"type": "string"}},
+            "required": required,
+        }),
+        output_schema: json!({"type": "string"}),
+    }
+}
+
+fn run_diff(registry: &std::path::Path, dump: &serde_json::Value) -> (Option<i32>, String) {
+    let dir = tempfile::tempdir().unwrap();
+    let dump_path = dir.path().join("tools.json");
+    std::fs::write(&dump_path, dump.to_string()).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_sentinel"))
+        .arg("diff")
+        .arg(&dump_path)
+        .arg("--registry")
+        .arg(registry)
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn test_diff_reports_drifted_and_unknown_tools() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = dir.path().join("registry");
+    {
+        let mut registry = RegistryGuard::new(&db).unwrap();
+        registry
+            .register_tool(&tool("read_file", &["path"]))
+            .unwrap();
+        registry.register_tool(&tool("list_dir", &[])).unwrap();
+        registry.flush().unwrap();
+    }
+
+    // read_file now takes a different input type; exfil is not registered
+    let mut drifted = tool("read_file", &["path"]);
+    drifted.input_schema = json!({"type": "array", "items": {"type": "string"}});
+    let dump = json!({"tools": [
+        drifted,
+        tool("list_dir", &[]),
+        tool("exfil", &[]),
+    ]});
+    let (code, stdout) = run_diff(&db, &dump);
+
+    assert_eq!(code, Some(2), "{}", stdout);
+    assert!(
+        stdout.contains("Checked 3 tools: 1 unchanged, 1 drifted, 1 unknown"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("CRITICAL  read_file"), "{}", stdout);
+    assert!(stdout.contains("UNKNOWN   exfil"), "{}", stdout);
+    assert!(
+        stdout.contains("FAIL: critical drift detected"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains('\x1b'), "no color when piped");
+}
+
+#[test]
+fn test_diff_exits_zero_without_critical_drift() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = dir.path().join("registry");
+    {
+        let mut registry = RegistryGuard::new(&db).unwrap();
+        registry
+            .register_tool(&tool("read_file", &["path"]))
+            .unwrap();
+        registry.flush().unwrap();
+    }
+
+    let (code, stdout) = run_diff(
+        &db,
+        &json!([tool("read_file", &["path"]), tool("new", &[])]),
+    );
+
+    assert_eq!(code, Some(0), "{}", stdout);
+    assert!(stdout.contains("UNKNOWN   new"), "{}", stdout);
+    assert!(stdout.contains("WARN"), "{}", stdout);
+}
+
+#[test]
+fn test_diff_fails_on_missing_registry() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = dir.path().join("registry");
+
+    let (code, stdout) = run_diff(&db, &json!([tool("read_file", &["path"])]));
+
+    assert_ne!(code, Some(0), "{}", stdout);
+    assert!(!stdout.contains("Checked"), "{}", stdout);
+    assert!(!db.exists(), "diff must not create a registry");
+}
@@ -0,0 +1,83 @@
+//! End-to-end tests for `sentinel status`.
+
+use std::process::Command;
+
+use sentinel_registry::{RegistryGuard, ToolSchema};
+
+fn run_status(registry: &std::path::Path) -> (Option<i32>, serde_json::Value) {
+    let output = Command::new(env!("CARGO_BIN_EXE_sentinel"))
+        .arg("status")
+        .arg("--registry")
+        .arg(registry)
+        .arg("--json")
+        .output()
+        .unwrap();
+    (
+        output.status.code(),
+        serde_json::from_slice(&output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn test_status_reports_ready_registry() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = dir.path().join("registry");
+    {
+        let mut registry = RegistryGuard::new(&db).unwrap();
+        let tool = ToolSchema::builder("read_file").build().unwrap();
+        registry.register_tool(&tool).unwrap();
+    }
+
+    let (code, health) = run_status(&db);
+    assert_eq!(code, Some(0));
+    assert_eq!(health["healthy"], true);
+    assert_eq!(health["registry"]["detail"], "1 tools registered");
+}
+
+#[test]
+fn test_status_not_ready_on_missing_registry() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = dir.path().join("registry");
+
+    let (code, health) = run_status(&db);
+    assert_eq!(code, Some(3));
+    assert_eq!(health["healthy"], false);
+    assert_eq!(health["registry"]["healthy"], false);
+    assert!(!db.exists(), "status must not create a registry");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_sentinel"))
+        .arg("status")
+        .arg("--registry")
+        .arg(&db)
+        .output()
+        .unwrap();
+    assert!(String::from_utf8_lossy(&output.stdout).contains("NOT READY"));
+}
+
+#[test]
+fn test_status_not_ready_on_empty_registry() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = dir.path().join("registry");
+    drop(RegistryGuard::new(&db).unwrap());
+
+    let (code, health) = run_status(&db);
+    assert_eq!(code, Some(3));
+    assert_eq!(health["registry"]["detail"], "no tools registered");
+}
+
+#[test]
+fn test_status_fails_on_corrupt_registry() {
+    let dir = tempfile::tempdir().unwrap();
+    let db = dir.path().join("registry");
+    {
+        let db = sled::open(&db).unwrap();
+        let hashes = db.open_tree("hashes").unwrap();
+        hashes.insert("read_file", &b"not-a-hash"[..]).unwrap();
+        db.flush().unwrap();
+    }
+
+    let (code, health) = run_status(&db);
+    assert_eq!(code, Some(3));
+    assert_eq!(health["healthy"], false);
+    assert_eq!(health["registry"]["healthy"], false);
+}
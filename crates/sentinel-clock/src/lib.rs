@@ -0,0 +1,128 @@
+//! # Sentinel Clock
+//!
+//! Pluggable time source for time-based Sentinel features (TTLs, time
+//! budgets, rate limits).
+//!
+//! Components take an [`Arc<dyn Clock>`](SharedClock) instead of calling
+//! `Instant::now()` directly, so production code runs on [`SystemClock`]
+//! while tests drive a [`MockClock`] forward by hand.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use sentinel_clock::{Clock, MockClock};
+//! use std::time::Duration;
+//!
+//! let clock = MockClock::new();
+//! let start = clock.now();
+//!
+//! clock.advance(Duration::from_secs(60));
+//! assert_eq!(clock.now() - start, Duration::from_secs(60));
+//! ```
+//!
+//! ## Security Notes
+//!
+//! - Only monotonic time (`Instant`) is exposed; wall-clock jumps cannot
+//!   extend or shorten expiry windows
+//! - `MockClock` is for tests; never inject it in production
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A source of monotonic time.
+pub trait Clock: Send + Sync + Debug {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// A clock shared between components.
+pub type SharedClock = Arc<dyn Clock>;
+
+/// Clock backed by `Instant::now()`. The default everywhere.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl SystemClock {
+    /// Returns the system clock as a [`SharedClock`].
+    pub fn shared() -> SharedClock {
+        Arc::new(Self)
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Manually advanced clock for deterministic tests.
+///
+/// Time stands still until [`advance`](Self::advance) is called. Clones
+/// share the same time, so a test can keep one handle and inject another.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    /// Current instant, shared between clones.
+    now: Arc<Mutex<Instant>>,
+}
+
+impl MockClock {
+    /// Creates a mock clock frozen at the current instant.
+    pub fn new() -> Self {
+        Self {
+            now: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    /// Moves the clock forward.
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(|e| e.into_inner());
+        *now += by;
+    }
+
+    /// Returns a handle to this clock as a [`SharedClock`].
+    pub fn shared(&self) -> SharedClock {
+        Arc::new(self.clone())
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mock_clock_stands_still() {
+        let clock = MockClock::new();
+        assert_eq!(clock.now(), clock.now());
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_time() {
+        let clock = MockClock::new();
+        let shared = clock.shared();
+        let start = shared.now();
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(shared.now() - start, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_system_clock_is_monotonic() {
+        let clock = SystemClock;
+        let a = clock.now();
+        let b = clock.now();
+        assert!(b >= a);
+    }
+}
@@ -1,10 +1,62 @@
 //! Configuration types for MCP Sentinel.
+//!
+//! Every section and key is optional and falls back to its default, so a
+//! config file only lists what it overrides. Unknown keys are rejected
+//! rather than ignored: a misspelled setting fails to load instead of
+//! silently doing nothing.
 
+use sentinel_council::WaluigiMode;
 use serde::{Deserialize, Serialize};
+
+use crate::verdict::BlockReasonKind;
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 /// Configuration for the Sentinel security facade.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+///
+/// # Schema
+///
+/// The full set of keys, with their defaults, in TOML:
+///
+/// ```toml
+/// tool_denylist = []            # tool names that always block
+/// analysis_gas_overhead = 0     # gas charged per analyzed call
+///
+/// [registry]
+/// db_path = "./sentinel_registry.db"
+/// allow_unknown_tools = false
+/// max_allowed_drift = "Minor"   # "None" | "Minor" | "Major"
+/// min_description_len = 0       # shortest tool description registered
+/// max_tenants = 64              # open tenant registries; 0 for no limit
+///
+/// [monitor]
+/// gas_limit = 10000
+/// max_context_bytes = 1000000
+/// max_depth = 100
+/// detect_cycles = true
+/// gas_warn_threshold = 0.8
+/// session_idle_ttl_secs = 900   # 0 keeps idle sessions until removed
+/// max_sessions = 0              # 0 for no limit
+///
+/// [council]
+/// enabled = true                # false skips the council; see `CouncilConfig::enabled`
+/// min_votes_for_approval = 2    # 1..=3
+/// waluigi_threshold = 0.7       # 0.0..=1.0
+/// detect_waluigi = true
+/// waluigi_mode = "Veto"         # "Veto" | "WeightedVote"
+/// on_no_consensus = "Review"    # "Review" | "Block"
+///
+/// [global]
+/// fail_closed = true
+/// audit_logging = true
+/// short_circuit = true
+/// on_infra_error = "FailClosed" # "FailClosed" | "FailOpenWithReview"
+/// mode = "Enforce"              # "Enforce" | "Observe"
+/// ```
+///
+/// Deserialization fails on any key not listed here.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct SentinelConfig {
     /// Registry Guard configuration.
     pub registry: RegistryConfig,
@@ -17,10 +69,147 @@ pub struct SentinelConfig {
 
     /// Global settings.
     pub global: GlobalConfig,
+
+    /// Tool names that are always blocked, whatever their registration.
+    ///
+    /// Checked before every other stage; a match consumes no gas.
+    pub tool_denylist: HashSet<String>,
+
+    /// Gas charged once per analyzed call for the security checks
+    /// themselves, on top of the tool call's own cost. Default `0`.
+    ///
+    /// Charged after the denylist and before the registry, so a denylisted
+    /// call stays free. If the remaining gas cannot cover it, the call is
+    /// blocked with `GasExhausted`.
+    pub analysis_gas_overhead: u64,
+}
+
+impl SentinelConfig {
+    /// Preset for high-assurance deployments. Starts from the defaults and sets:
+    ///
+    /// | Setting | Value |
+    /// |---------|-------|
+    /// | `registry.allow_unknown_tools` | `false` (unknown tools block) |
+    /// | `registry.max_allowed_drift` | `None` |
+    /// | `monitor.gas_limit` | `2_000` |
+    /// | `council.min_votes_for_approval` | `3` (unanimity) |
+    /// | `council.waluigi_threshold` | `0.5` |
+    /// | `council.detect_waluigi` | `true` |
+    /// | `global.short_circuit` | `true` |
+    /// | `global.on_infra_error` | `FailClosed` |
+    ///
+    /// Input scanning is configured on the firewall itself
+    /// (`FirewallConfig`), which is not part of this config.
+    pub fn strict() -> Self {
+        let mut config = Self::default();
+        config.registry.allow_unknown_tools = false;
+        config.registry.max_allowed_drift = DriftThreshold::None;
+        config.monitor.gas_limit = 2_000;
+        config.council.min_votes_for_approval = 3;
+        config.council.waluigi_threshold = 0.5;
+        config.council.detect_waluigi = true;
+        config.global.short_circuit = true;
+        config.global.on_infra_error = InfraErrorPolicy::FailClosed;
+        config
+    }
+
+    /// Lists the block reasons this configuration can produce.
+    ///
+    /// A reason is left out only when the configuration rules it out: for
+    /// example `CouncilRejected` with the council disabled, `UnknownTool`
+    /// with `allow_unknown_tools`, or `DeniedTool` with an empty denylist.
+    /// A reason in the set may still never occur for a given workload.
+    ///
+    /// `SchemaDrift` is never listed, since the pipeline reports a changed
+    /// schema as `HashMismatch`. `Multiple` is never listed: the pipeline
+    /// keeps only the first block, even without `short_circuit`.
+    /// `OriginMismatch` is not listed because it depends on
+    /// [`Sentinel::with_origin`](crate::Sentinel::with_origin), not on the
+    /// configuration; add it when the sentinel is built with an origin.
+    /// `SecurityViolation` is never listed either: only a
+    /// [review handler](crate::Sentinel::with_review_handler) produces it,
+    /// and a handler may return any reason. In
+    /// [`EvaluationMode::Observe`] nothing is blocked, so the set is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_core::{BlockReasonKind, SentinelConfig};
+    ///
+    /// let mut config = SentinelConfig::default();
+    /// assert!(config.reachable_block_reasons().contains(&BlockReasonKind::UnknownTool));
+    ///
+    /// config.registry.allow_unknown_tools = true;
+    /// assert!(!config.reachable_block_reasons().contains(&BlockReasonKind::UnknownTool));
+    /// ```
+    pub fn reachable_block_reasons(&self) -> HashSet<BlockReasonKind> {
+        if self.global.mode == EvaluationMode::Observe {
+            return HashSet::new();
+        }
+
+        let council = &self.council;
+        let mut reasons = HashSet::from([
+            BlockReasonKind::HashMismatch,
+            BlockReasonKind::GasExhausted,
+            BlockReasonKind::CycleDetected,
+            BlockReasonKind::ContextOverflow,
+        ]);
+        let conditional = [
+            (BlockReasonKind::DeniedTool, !self.tool_denylist.is_empty()),
+            (
+                BlockReasonKind::UnknownTool,
+                !self.registry.allow_unknown_tools,
+            ),
+            (BlockReasonKind::CouncilRejected, council.enabled),
+            (
+                BlockReasonKind::NoConsensus,
+                council.enabled && council.on_no_consensus == NoConsensusPolicy::Block,
+            ),
+            (
+                BlockReasonKind::WaluigiEffect,
+                council.enabled && council.detect_waluigi,
+            ),
+            (
+                BlockReasonKind::InfrastructureError,
+                self.global.on_infra_error == InfraErrorPolicy::FailClosed,
+            ),
+        ];
+        reasons.extend(
+            conditional
+                .into_iter()
+                .filter(|&(_, reachable)| reachable)
+                .map(|(kind, _)| kind),
+        );
+        reasons
+    }
+
+    /// Preset that flags rather than blocks where it can. Starts from the
+    /// defaults and sets:
+    ///
+    /// | Setting | Value |
+    /// |---------|-------|
+    /// | `registry.allow_unknown_tools` | `true` (unknown tools go to review) |
+    /// | `monitor.gas_limit` | `100_000` |
+    /// | `council.min_votes_for_approval` | `1` |
+    /// | `global.short_circuit` | `false` (every check runs and reports) |
+    /// | `global.on_infra_error` | `FailOpenWithReview` |
+    ///
+    /// Security blocks (hash mismatches, cycles, council rejections,
+    /// Waluigi vetoes) still apply.
+    pub fn permissive() -> Self {
+        let mut config = Self::default();
+        config.registry.allow_unknown_tools = true;
+        config.monitor.gas_limit = 100_000;
+        config.council.min_votes_for_approval = 1;
+        config.global.short_circuit = false;
+        config.global.on_infra_error = InfraErrorPolicy::FailOpenWithReview;
+        config
+    }
 }
 
 /// Registry Guard configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct RegistryConfig {
     /// Path to the registry database.
     pub db_path: PathBuf,
@@ -30,6 +219,14 @@ pub struct RegistryConfig {
 
     /// Maximum drift level to allow without blocking.
     pub max_allowed_drift: DriftThreshold,
+
+    /// Shortest tool description accepted at registration, in characters
+    /// (0 for no minimum).
+    pub min_description_len: usize,
+
+    /// Most tenants that may be open at once (0 for no limit). Each open
+    /// tenant holds a database handle and a State Monitor.
+    pub max_tenants: usize,
 }
 
 impl Default for RegistryConfig {
@@ -38,6 +235,8 @@ impl Default for RegistryConfig {
             db_path: PathBuf::from("./sentinel_registry.db"),
             allow_unknown_tools: false,
             max_allowed_drift: DriftThreshold::Minor,
+            min_description_len: 0,
+            max_tenants: 64,
         }
     }
 }
@@ -54,7 +253,8 @@ pub enum DriftThreshold {
 }
 
 /// State Monitor configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct MonitorConfig {
     /// Maximum gas budget per request.
     pub gas_limit: u64,
@@ -67,6 +267,22 @@ pub struct MonitorConfig {
 
     /// Enable cycle detection.
     pub detect_cycles: bool,
+
+    /// Gas utilization (0.0-1.0) at which a one-time `HighGasUsage`
+    /// review flag is raised.
+    pub gas_warn_threshold: f64,
+
+    /// Seconds a session may sit idle before it is evicted; `0` keeps
+    /// sessions until removed. Halted sessions are never evicted.
+    pub session_idle_ttl_secs: u64,
+
+    /// Most live sessions at once; a new session beyond it is refused.
+    /// `0` for no limit.
+    pub max_sessions: usize,
+}
+
+const fn default_gas_warn_threshold() -> f64 {
+    0.8
 }
 
 impl Default for MonitorConfig {
@@ -76,35 +292,80 @@ impl Default for MonitorConfig {
             max_context_bytes: 1_000_000, // 1MB
             max_depth: 100,
             detect_cycles: true,
+            gas_warn_threshold: default_gas_warn_threshold(),
+            session_idle_ttl_secs: 900,
+            max_sessions: 0,
         }
     }
 }
 
 /// Cognitive Council configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct CouncilConfig {
+    /// Whether tool calls are put to the council at all.
+    ///
+    /// When off, the council stage is skipped: no vote, no Waluigi check.
+    /// `SecurityReport::council` is `None` and
+    /// [`SentinelConfig::reachable_block_reasons`] drops the council reasons.
+    ///
+    /// # Security Notes
+    ///
+    /// Disabling the council removes the only check on what a call's
+    /// arguments ask for. `CouncilRejected`, `NoConsensus` and
+    /// `WaluigiEffect` can no longer block, so a registered tool with a
+    /// sound schema runs whatever it is given. Only turn it off where calls
+    /// are screened some other way.
+    pub enabled: bool,
+
     /// Minimum votes required for approval (out of 3).
     pub min_votes_for_approval: u8,
 
-    /// Waluigi detection threshold (0.0 - 1.0).
+    /// Waluigi score (0.0 - 1.0) at or above which the council vetoes a
+    /// tool call as alignment inversion, blocking it with
+    /// `BlockReason::WaluigiEffect`. Tool-call arguments are screened.
     pub waluigi_threshold: f64,
 
     /// Enable Waluigi detection.
     pub detect_waluigi: bool,
+
+    /// Whether a Waluigi detection vetoes the call outright or counts as
+    /// one reject vote in the council's consensus. Scores at or above
+    /// `sentinel_council::WALUIGI_VETO_CEILING` veto in either mode.
+    pub waluigi_mode: WaluigiMode,
+
+    /// How to treat a council vote that reaches no consensus (too few
+    /// non-abstaining evaluators).
+    pub on_no_consensus: NoConsensusPolicy,
+}
+
+/// Verdict for a council vote that reached no consensus.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoConsensusPolicy {
+    /// Block the call as if the council had rejected it.
+    Block,
+    /// Escalate to a human with a split-vote Review flag. The default,
+    /// since no consensus is not a rejection.
+    #[default]
+    Review,
 }
 
 impl Default for CouncilConfig {
     fn default() -> Self {
         Self {
+            enabled: true,
             min_votes_for_approval: 2,
             waluigi_threshold: 0.7,
             detect_waluigi: true,
+            waluigi_mode: WaluigiMode::Veto,
+            on_no_consensus: NoConsensusPolicy::Review,
         }
     }
 }
 
 /// Global Sentinel settings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct GlobalConfig {
     /// Fail-closed mode: errors result in Block instead of Allow.
     pub fail_closed: bool,
@@ -114,6 +375,38 @@ pub struct GlobalConfig {
 
     /// Short-circuit on first failure (don't run remaining checks).
     pub short_circuit: bool,
+
+    /// How to treat infrastructure errors (e.g. an unreadable registry).
+    ///
+    /// Security failures always block, including registry data that fails
+    /// to decode; this only governs errors that say nothing about the safety
+    /// of the call itself.
+    pub on_infra_error: InfraErrorPolicy,
+
+    /// Whether verdicts are enforced or only observed (dry run).
+    pub mode: EvaluationMode,
+}
+
+/// Whether the pipeline's verdicts take effect.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EvaluationMode {
+    /// Verdicts are returned as reached. The default.
+    #[default]
+    Enforce,
+    /// Every call is allowed; the verdict the pipeline reached is kept as
+    /// a shadow verdict in [`Evaluation`](crate::Evaluation) and
+    /// [`SecurityReport`](crate::SecurityReport), and logged. For rolling out new policy without blocking traffic.
+    Observe,
+}
+
+/// Policy for non-security errors raised while analyzing a call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InfraErrorPolicy {
+    /// Block the call. The safe default.
+    #[default]
+    FailClosed,
+    /// Let the call through as a Review verdict so a human can decide.
+    FailOpenWithReview,
 }
 
 impl Default for GlobalConfig {
@@ -122,6 +415,8 @@ impl Default for GlobalConfig {
             fail_closed: true,
             audit_logging: true,
             short_circuit: true,
+            on_infra_error: InfraErrorPolicy::FailClosed,
+            mode: EvaluationMode::Enforce,
         }
     }
 }
@@ -130,14 +425,70 @@ impl Default for GlobalConfig {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_disabling_council_removes_council_reasons() {
+        let mut config = SentinelConfig::default();
+        config.council.on_no_consensus = NoConsensusPolicy::Block;
+        let council_reasons = [
+            BlockReasonKind::CouncilRejected,
+            BlockReasonKind::NoConsensus,
+            BlockReasonKind::WaluigiEffect,
+        ];
+
+        let enabled = config.reachable_block_reasons();
+        assert!(council_reasons.iter().all(|kind| enabled.contains(kind)));
+
+        config.council.enabled = false;
+        let disabled = config.reachable_block_reasons();
+        assert!(council_reasons.iter().all(|kind| !disabled.contains(kind)));
+        assert_eq!(disabled.len(), enabled.len() - council_reasons.len());
+
+        config.global.mode = EvaluationMode::Observe;
+        assert!(config.reachable_block_reasons().is_empty());
+    }
+
+    #[test]
+    fn test_reachable_block_reasons_omits_pipeline_independent_kinds() {
+        for short_circuit in [true, false] {
+            let mut config = SentinelConfig::default();
+            config.global.short_circuit = short_circuit;
+            let reasons = config.reachable_block_reasons();
+
+            // Only the first block is kept, so `Multiple` never occurs
+            assert!(!reasons.contains(&BlockReasonKind::Multiple));
+            // Depends on `Sentinel::with_origin`, not on the config
+            assert!(!reasons.contains(&BlockReasonKind::OriginMismatch));
+        }
+    }
+
     #[test]
     fn test_default_config() {
         let config = SentinelConfig::default();
         assert!(config.global.fail_closed);
+        assert_eq!(config.global.on_infra_error, InfraErrorPolicy::FailClosed);
+        assert_eq!(config.global.mode, EvaluationMode::Enforce);
         assert_eq!(config.council.min_votes_for_approval, 2);
+        assert_eq!(config.council.on_no_consensus, NoConsensusPolicy::Review);
         assert_eq!(config.monitor.gas_limit, 10_000);
     }
 
+    #[test]
+    fn test_presets() {
+        let strict = SentinelConfig::strict();
+        assert!(!strict.registry.allow_unknown_tools);
+        assert_eq!(strict.registry.max_allowed_drift, DriftThreshold::None);
+        assert_eq!(strict.council.min_votes_for_approval, 3);
+        assert!(strict.monitor.gas_limit < SentinelConfig::default().monitor.gas_limit);
+
+        let permissive = SentinelConfig::permissive();
+        assert!(permissive.registry.allow_unknown_tools);
+        assert!(!permissive.global.short_circuit);
+        assert_eq!(
+            permissive.global.on_infra_error,
+            InfraErrorPolicy::FailOpenWithReview
+        );
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = SentinelConfig::default();
@@ -145,4 +496,54 @@ mod tests {
         let parsed: SentinelConfig = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.monitor.gas_limit, config.monitor.gas_limit);
     }
+
+    #[test]
+    fn test_config_toml_round_trip() {
+        let mut config = SentinelConfig::strict();
+        config.tool_denylist.insert("exec".to_string());
+        config.analysis_gas_overhead = 3;
+        config.global.mode = EvaluationMode::Observe;
+
+        let toml = toml::to_string(&config).unwrap();
+        let parsed: SentinelConfig = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed, config);
+    }
+
+    #[test]
+    fn test_partial_toml_keeps_defaults() {
+        let parsed: SentinelConfig = toml::from_str("[monitor]\ngas_limit = 500\n").unwrap();
+
+        let mut expected = SentinelConfig::default();
+        expected.monitor.gas_limit = 500;
+        assert_eq!(parsed, expected);
+        assert_eq!(
+            toml::from_str::<SentinelConfig>("").unwrap(),
+            SentinelConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_unknown_toml_key_is_rejected() {
+        let err = toml::from_str::<SentinelConfig>("[monitor]\ngas_limt = 500\n").unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("unknown field `gas_limt`"), "{}", message);
+        assert!(
+            message.contains("gas_limit"),
+            "expected keys listed: {}",
+            message
+        );
+
+        let err = toml::from_str::<SentinelConfig>("tool_deny_list = [\"exec\"]\n").unwrap_err();
+        assert!(err.to_string().contains("unknown field `tool_deny_list`"));
+
+        let err = toml::from_str::<SentinelConfig>("[firewall]\nenabled = true\n").unwrap_err();
+        assert!(err.to_string().contains("unknown field `firewall`"));
+    }
+
+    #[test]
+    fn test_example_config_parses_to_defaults() {
+        let example = include_str!("../../../config/sentinel.example.toml");
+        let parsed = toml::from_str::<SentinelConfig>(example).unwrap();
+        assert_eq!(parsed, SentinelConfig::default());
+    }
 }
@@ -29,10 +29,18 @@ pub enum SentinelError {
     #[error("Internal error: {0}")]
     Internal(String),
 
-    /// Registry error passthrough.
+    /// Upstream transport failed (gateway forwarding).
+    #[error("Upstream error: {0}")]
+    Upstream(String),
+
+    /// Registry error, as a message.
     #[error("Registry error: {0}")]
     Registry(String),
 
+    /// Registry error passthrough, keeping the registry error as the source.
+    #[error("Registry error: {0}")]
+    RegistryFailure(#[from] sentinel_registry::RegistryError),
+
     /// Monitor error passthrough.
     #[error("Monitor error: {0}")]
     Monitor(#[from] sentinel_monitor::MonitorError),
@@ -41,3 +49,59 @@ pub enum SentinelError {
     #[error("Council error: {0}")]
     Council(#[from] sentinel_council::CouncilError),
 }
+
+impl SentinelError {
+    /// Returns a message safe to show to gateway clients.
+    ///
+    /// `Display` (and the [`source`](std::error::Error::source) chain) keep
+    /// full detail for logs. This message drops internals such as file
+    /// paths, upstream I/O errors and configuration values. Security
+    /// rejections keep their reason, since explaining a denial is the point.
+    pub fn user_message(&self) -> String {
+        match self {
+            SentinelError::SchemaViolation(msg) => format!("Schema verification failed: {}", msg),
+            SentinelError::StateViolation(msg) => format!("State violation: {}", msg),
+            SentinelError::CouncilRejection(msg) => format!("Council rejection: {}", msg),
+            SentinelError::WaluigiDetected(msg) => format!("Waluigi effect detected: {}", msg),
+            SentinelError::Config(_) => "Sentinel is misconfigured".to_string(),
+            SentinelError::Internal(_) => "Internal error".to_string(),
+            SentinelError::Upstream(_) => "Upstream server unavailable".to_string(),
+            SentinelError::Registry(_) => "Tool registry error".to_string(),
+            SentinelError::RegistryFailure(e) => e.user_message(),
+            SentinelError::Monitor(e) => e.user_message(),
+            SentinelError::Council(e) => e.user_message(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sentinel_registry::RegistryError;
+    use std::error::Error as _;
+
+    #[test]
+    fn test_registry_error_source_chain() {
+        let err = SentinelError::from(RegistryError::DatabaseLocked {
+            path: "/srv/secret/registry.db".to_string(),
+        });
+
+        let source = err.source().expect("registry error is the source");
+        assert!(source.downcast_ref::<RegistryError>().is_some());
+        assert!(err.to_string().contains("/srv/secret"));
+        assert!(!err.user_message().contains("/srv/secret"));
+    }
+
+    #[test]
+    fn test_upstream_detail_not_exposed() {
+        let err = SentinelError::Upstream("failed to spawn upstream: /opt/mcp/bin/server".into());
+        assert_eq!(err.user_message(), "Upstream server unavailable");
+    }
+
+    #[test]
+    fn test_registry_message_variant_hides_detail() {
+        let err = SentinelError::Registry("lock held on /srv/secret/registry.db".into());
+        assert!(err.to_string().contains("/srv/secret"));
+        assert_eq!(err.user_message(), "Tool registry error");
+    }
+}
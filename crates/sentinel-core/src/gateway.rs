@@ -0,0 +1,579 @@
+//! Gateway forwarding for screened MCP traffic.
+//!
+//! The [`Gateway`] sits between an MCP client and an upstream server. Every
+//! `tools/call` request is run through the [`Sentinel`] pipeline; blocked
+//! calls are answered locally with a JSON-RPC error, everything else is
+//! forwarded through an [`Upstream`].
+//!
+//! ## Transports
+//!
+//! The gateway depends only on the [`Upstream`] trait, so the transport is
+//! pluggable:
+//!
+//! - [`StdioUpstream`]: MCP server running as a child process (NDJSON)
+//! - HTTP/SSE or websocket transports can implement the same trait
+//!
+//! ## Security Notes
+//!
+//! - Blocked calls never reach the upstream
+//! - Review verdicts are forwarded, matching the Go proxy router
+//! - Upstream failures surface as JSON-RPC errors, not as allowed traffic
+//! - Every screened tool call closes its monitor step, whatever the outcome
+
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::future::Future;
+use std::process::Stdio;
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader, Lines};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+use sentinel_registry::ToolSchema;
+
+use crate::{error::SentinelError, sentinel::Sentinel, Result};
+
+pub use crate::verdict::BLOCKED_ERROR_CODE;
+
+/// A transport that delivers JSON-RPC messages to the upstream MCP server.
+///
+/// Implementations must be safe to share across tasks. For requests, `send`
+/// resolves to the upstream's response; for notifications (no `id`) it
+/// resolves to `Value::Null` once the message is written.
+pub trait Upstream: Send + Sync {
+    /// Sends a message upstream and waits for its response.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::Upstream`] if the message cannot be delivered
+    /// or the response cannot be read.
+    fn send(&self, msg: &Value) -> impl Future<Output = Result<Value>> + Send;
+}
+
+/// Upstream MCP server running as a child process.
+///
+/// Messages are exchanged as newline-delimited JSON over the child's stdin
+/// and stdout. Requests are serialized: one request is written and its
+/// response read before the next is sent.
+///
+/// The response is matched by JSON-RPC `id`. Notifications and server
+/// requests that arrive while waiting, and responses to other ids, are
+/// skipped.
+pub struct StdioUpstream {
+    /// Child process handle; killed when the upstream is dropped.
+    _child: Child,
+    /// Pipes to the child, locked for the duration of one exchange.
+    pipes: Mutex<StdioPipes>,
+}
+
+/// The child's stdin and line-buffered stdout.
+struct StdioPipes {
+    stdin: ChildStdin,
+    stdout: Lines<BufReader<ChildStdout>>,
+}
+
+impl StdioUpstream {
+    /// Spawns `program` with `args` and connects to its stdio.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::Upstream`] if the process cannot be started.
+    pub fn spawn<I, S>(program: impl AsRef<OsStr>, args: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| SentinelError::Upstream(format!("failed to spawn upstream: {}", e)))?;
+
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| SentinelError::Upstream("upstream stdin unavailable".to_string()))?;
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| SentinelError::Upstream("upstream stdout unavailable".to_string()))?;
+
+        Ok(Self {
+            _child: child,
+            pipes: Mutex::new(StdioPipes {
+                stdin,
+                stdout: BufReader::new(stdout).lines(),
+            }),
+        })
+    }
+}
+
+impl Upstream for StdioUpstream {
+    async fn send(&self, msg: &Value) -> Result<Value> {
+        let mut line = serde_json::to_string(msg)
+            .map_err(|e| SentinelError::Upstream(format!("failed to encode message: {}", e)))?;
+        line.push('\n');
+
+        let mut pipes = self.pipes.lock().await;
+        pipes
+            .stdin
+            .write_all(line.as_bytes())
+            .await
+            .map_err(|e| SentinelError::Upstream(format!("write failed: {}", e)))?;
+        pipes
+            .stdin
+            .flush()
+            .await
+            .map_err(|e| SentinelError::Upstream(format!("flush failed: {}", e)))?;
+
+        let Some(id) = msg.get("id") else {
+            return Ok(Value::Null);
+        };
+
+        loop {
+            let line = pipes
+                .stdout
+                .next_line()
+                .await
+                .map_err(|e| SentinelError::Upstream(format!("read failed: {}", e)))?
+                .ok_or_else(|| SentinelError::Upstream("upstream closed its output".to_string()))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response: Value = serde_json::from_str(&line).map_err(|e| {
+                SentinelError::Upstream(format!("invalid upstream response: {}", e))
+            })?;
+            if is_response_to(&response, id) {
+                return Ok(response);
+            }
+            debug!("Skipping upstream message while waiting for id {}", id);
+        }
+    }
+}
+
+/// Screens MCP messages with a [`Sentinel`] and forwards allowed ones.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let upstream = StdioUpstream::spawn("my-mcp-server", ["--stdio"])?;
+/// let mut gateway = Gateway::new(sentinel, upstream).with_schema(read_file_schema);
+///
+/// // Proxy stdin/stdout of this process to the upstream server
+/// let stdin = tokio::io::BufReader::new(tokio::io::stdin());
+/// gateway.run(stdin, tokio::io::stdout()).await?;
+/// ```
+pub struct Gateway<U: Upstream> {
+    /// Security pipeline applied to tool calls.
+    sentinel: Sentinel,
+
+    /// Transport to the upstream MCP server.
+    upstream: U,
+
+    /// Known tool schemas, used to verify calls by name.
+    schemas: HashMap<String, ToolSchema>,
+}
+
+impl<U: Upstream> Gateway<U> {
+    /// Creates a gateway forwarding to `upstream`.
+    pub fn new(sentinel: Sentinel, upstream: U) -> Self {
+        Self {
+            sentinel,
+            upstream,
+            schemas: HashMap::new(),
+        }
+    }
+
+    /// Adds the schema the upstream advertises for a tool.
+    ///
+    /// Calls to tools without a known schema are verified against a bare
+    /// schema carrying only the name, which the registry treats as unknown.
+    pub fn with_schema(mut self, schema: ToolSchema) -> Self {
+        self.schemas.insert(schema.name.clone(), schema);
+        self
+    }
+
+    /// Returns the underlying Sentinel.
+    pub fn sentinel(&self) -> &Sentinel {
+        &self.sentinel
+    }
+
+    /// Returns the underlying Sentinel mutably.
+    pub fn sentinel_mut(&mut self) -> &mut Sentinel {
+        &mut self.sentinel
+    }
+
+    /// Screens one message and forwards it if allowed.
+    ///
+    /// # Returns
+    ///
+    /// The response to deliver to the client: the upstream's response, a
+    /// local error for a blocked request, or `Value::Null` when there is
+    /// nothing to send back (notifications).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Sentinel pipeline or the upstream fails. The
+    /// monitor step opened for a tool call is ended either way.
+    pub async fn handle(&mut self, msg: &Value) -> Result<Value> {
+        let response = match self.screen(msg) {
+            Ok(Some(blocked)) => Ok(blocked),
+            Ok(None) => self.upstream.send(msg).await,
+            Err(e) => Err(e),
+        };
+
+        let ended = if is_tool_call(msg) {
+            self.end_open_step(&response)
+        } else {
+            Ok(())
+        };
+        let response = response?;
+        ended?;
+        Ok(response)
+    }
+
+    /// Runs the proxy loop over newline-delimited JSON.
+    ///
+    /// Reads messages from `reader` until EOF, handles each, and writes
+    /// non-null responses to `writer`. Lines that are not valid JSON are
+    /// answered with a JSON-RPC parse error, and requests that fail (for
+    /// example because the upstream is down) with an internal error carrying
+    /// [`SentinelError::user_message`]. Failed notifications are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if reading from `reader` or writing to `writer`
+    /// fails.
+    pub async fn run<R, W>(&mut self, reader: R, mut writer: W) -> Result<()>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut lines = reader.lines();
+
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .map_err(|e| SentinelError::Upstream(format!("read failed: {}", e)))?
+        {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response = match serde_json::from_str::<Value>(&line) {
+                Ok(msg) => match self.handle(&msg).await {
+                    Ok(response) => response,
+                    Err(e) => {
+                        warn!("Failed to handle message: {}", e);
+                        match msg.get("id") {
+                            Some(id) => {
+                                error_response(id, -32603, "Internal error", &e.user_message())
+                            }
+                            None => Value::Null,
+                        }
+                    }
+                },
+                Err(e) => error_response(&Value::Null, -32700, "Parse error", &e.to_string()),
+            };
+            if response.is_null() {
+                continue;
+            }
+
+            let mut out = response.to_string();
+            out.push('\n');
+            writer
+                .write_all(out.as_bytes())
+                .await
+                .map_err(|e| SentinelError::Upstream(format!("write failed: {}", e)))?;
+            writer
+                .flush()
+                .await
+                .map_err(|e| SentinelError::Upstream(format!("flush failed: {}", e)))?;
+        }
+
+        Ok(())
+    }
+
+    /// Ends the monitor step left open by a screened tool call.
+    ///
+    /// The step is closed for forwarded, blocked and failed calls alike, so
+    /// one failure cannot wedge the monitor for every later call.
+    fn end_open_step(&mut self, response: &Result<Value>) -> Result<()> {
+        if !self.sentinel.step_in_progress() {
+            return Ok(());
+        }
+
+        let result = match response {
+            Ok(response) => response.to_string(),
+            Err(e) => format!("error: {}", e),
+        };
+        self.sentinel.end_step(&result)
+    }
+
+    /// Runs a tool call through the Sentinel.
+    ///
+    /// Returns `Some(response)` if the message must not be forwarded.
+    fn screen(&mut self, msg: &Value) -> Result<Option<Value>> {
+        if !is_tool_call(msg) {
+            return Ok(None);
+        }
+
+        let params = msg.get("params");
+        let name = params
+            .and_then(|p| p.get("name"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let arguments = params
+            .and_then(|p| p.get("arguments"))
+            .cloned()
+            .unwrap_or_else(|| json!({}));
+
+        let schema = self
+            .schemas
+            .get(&name)
+            .cloned()
+            .unwrap_or_else(|| ToolSchema {
+                name: name.clone(),
+                description: String::new(),
+                input_schema: json!({}),
+                output_schema: json!({}),
+            });
+
+        let verdict = self
+            .sentinel
+            .analyze_tool_call(&name, &schema, &arguments)?;
+        let Some(error) = verdict.jsonrpc_error() else {
+            debug!("Forwarding tool call '{}'", name);
+            return Ok(None);
+        };
+
+        warn!("Blocked tool call '{}': {:?}", name, error.data);
+        match msg.get("id") {
+            Some(id) => Ok(Some(json!({ "jsonrpc": "2.0", "id": id, "error": error }))),
+            // Blocked notifications are dropped silently
+            None => Ok(Some(Value::Null)),
+        }
+    }
+}
+
+/// Returns true if the message is a `tools/call` request or notification.
+fn is_tool_call(msg: &Value) -> bool {
+    msg.get("method").and_then(Value::as_str) == Some("tools/call")
+}
+
+/// Returns true if `msg` is the response (not a request) with the given id.
+fn is_response_to(msg: &Value, id: &Value) -> bool {
+    msg.get("method").is_none() && msg.get("id") == Some(id)
+}
+
+/// Builds a JSON-RPC error response.
+fn error_response(id: &Value, code: i64, message: &str, data: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {
+            "code": code,
+            "message": message,
+            "data": data,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SentinelConfig;
+    use tempfile::TempDir;
+
+    /// Upstream that records every message and echoes it back as the result.
+    #[derive(Default)]
+    struct EchoUpstream {
+        sent: std::sync::Mutex<Vec<Value>>,
+    }
+
+    impl Upstream for EchoUpstream {
+        async fn send(&self, msg: &Value) -> Result<Value> {
+            self.sent.lock().unwrap().push(msg.clone());
+            Ok(json!({ "jsonrpc": "2.0", "id": msg["id"], "result": msg["params"] }))
+        }
+    }
+
+    /// Upstream that fails the first message and echoes the rest.
+    #[derive(Default)]
+    struct FlakyUpstream {
+        calls: std::sync::Mutex<usize>,
+    }
+
+    impl Upstream for FlakyUpstream {
+        async fn send(&self, msg: &Value) -> Result<Value> {
+            let mut calls = self.calls.lock().unwrap();
+            *calls += 1;
+            if *calls == 1 {
+                return Err(SentinelError::Upstream("connection reset".to_string()));
+            }
+            Ok(json!({ "jsonrpc": "2.0", "id": msg["id"], "result": msg["params"] }))
+        }
+    }
+
+    fn test_gateway(temp_dir: &TempDir) -> Gateway<EchoUpstream> {
+        test_gateway_with(temp_dir, EchoUpstream::default())
+    }
+
+    fn test_gateway_with<U: Upstream>(temp_dir: &TempDir, upstream: U) -> Gateway<U> {
+        let mut config = SentinelConfig::default();
+        config.registry.db_path = temp_dir.path().join("gateway_registry.db");
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let schema = ToolSchema {
+            name: "read_file".to_string(),
+            description: "Read a file".to_string(),
+            input_schema: json!({ "type": "object" }),
+            output_schema: json!({ "type": "string" }),
+        };
+        sentinel.register_tool(&schema).unwrap();
+
+        Gateway::new(sentinel, upstream).with_schema(schema)
+    }
+
+    fn tool_call(id: u64, name: &str) -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": "tools/call",
+            "params": { "name": name, "arguments": { "path": "/tmp/notes.txt" } }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_allowed_call_forwarded() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut gateway = test_gateway(&temp_dir);
+
+        let response = gateway.handle(&tool_call(1, "read_file")).await.unwrap();
+
+        assert_eq!(response["result"]["name"], "read_file");
+        assert_eq!(gateway.upstream.sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_blocked_call_not_forwarded() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut gateway = test_gateway(&temp_dir);
+
+        // Unknown tools are blocked by default
+        let response = gateway.handle(&tool_call(2, "delete_all")).await.unwrap();
+
+        assert_eq!(response["id"], 2);
+        assert_eq!(response["error"]["code"], BLOCKED_ERROR_CODE);
+        assert!(gateway.upstream.sent.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_non_tool_messages_pass_through() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut gateway = test_gateway(&temp_dir);
+
+        let msg = json!({ "jsonrpc": "2.0", "id": 3, "method": "tools/list" });
+        gateway.handle(&msg).await.unwrap();
+
+        assert_eq!(gateway.upstream.sent.lock().unwrap()[0], msg);
+        assert_eq!(gateway.sentinel().step_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_run_loop_over_ndjson() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut gateway = test_gateway(&temp_dir);
+
+        let input = format!(
+            "{}\n{}\nnot json\n",
+            tool_call(1, "read_file"),
+            tool_call(2, "delete_all")
+        );
+        let mut output = Vec::new();
+        gateway.run(input.as_bytes(), &mut output).await.unwrap();
+
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 3);
+        assert!(responses[0].get("result").is_some());
+        assert_eq!(responses[1]["error"]["code"], BLOCKED_ERROR_CODE);
+        assert_eq!(responses[2]["error"]["code"], -32700);
+        assert_eq!(gateway.upstream.sent.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_run_survives_upstream_error() {
+        let temp_dir = TempDir::new().unwrap();
+        let list_dir = ToolSchema {
+            name: "list_dir".to_string(),
+            description: "List a directory".to_string(),
+            input_schema: json!({ "type": "object" }),
+            output_schema: json!({ "type": "array" }),
+        };
+        let mut gateway =
+            test_gateway_with(&temp_dir, FlakyUpstream::default()).with_schema(list_dir.clone());
+        gateway.sentinel_mut().register_tool(&list_dir).unwrap();
+
+        let input = format!(
+            "{}\n{}\n",
+            tool_call(1, "read_file"),
+            tool_call(2, "list_dir")
+        );
+        let mut output = Vec::new();
+        gateway.run(input.as_bytes(), &mut output).await.unwrap();
+
+        let responses: Vec<Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], 1);
+        assert_eq!(responses[0]["error"]["code"], -32603);
+        assert_eq!(responses[0]["error"]["data"], "Upstream server unavailable");
+        // The failed call's step was closed, so the next call goes through
+        assert_eq!(responses[1]["result"]["name"], "list_dir");
+        assert!(!gateway.sentinel().step_in_progress());
+    }
+
+    #[tokio::test]
+    async fn test_blocked_call_closes_step() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut gateway = test_gateway(&temp_dir);
+
+        gateway.handle(&tool_call(1, "delete_all")).await.unwrap();
+        assert!(!gateway.sentinel().step_in_progress());
+
+        let response = gateway.handle(&tool_call(2, "read_file")).await.unwrap();
+        assert_eq!(response["result"]["name"], "read_file");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_stdio_upstream_round_trip() {
+        // Emits a notification and an unrelated response before the reply
+        let script = r#"read line
+printf '%s\n' \
+  '{"jsonrpc":"2.0","method":"notifications/progress"}' \
+  '{"jsonrpc":"2.0","id":6,"result":{}}' \
+  '{"jsonrpc":"2.0","id":7,"method":"sampling/createMessage"}' \
+  '{"jsonrpc":"2.0","id":7,"result":{"ok":true}}'
+cat > /dev/null"#;
+        let upstream = StdioUpstream::spawn("sh", ["-c", script]).unwrap();
+        let msg = json!({ "jsonrpc": "2.0", "id": 7, "method": "ping" });
+
+        let response = upstream.send(&msg).await.unwrap();
+        assert_eq!(response["id"], 7);
+        assert_eq!(response["result"]["ok"], true);
+    }
+}
@@ -0,0 +1,144 @@
+//! Readiness reporting for deployment probes.
+//!
+//! A [`HealthStatus`] is produced by [`Sentinel::health`](crate::Sentinel::health)
+//! and says whether each pipeline component can currently do its job. It
+//! serializes to JSON, so it can back a readiness endpoint or an exec
+//! probe (`sentinel status --json`) directly.
+//!
+//! ## Scope
+//!
+//! Health is about the Sentinel itself, not about any tool call: a halted
+//! monitor is unhealthy because every further call will be blocked until
+//! it is reset, while a blocked call is not a health problem.
+
+use sentinel_registry::ReadOnlyRegistry;
+use serde::Serialize;
+
+/// State of one pipeline component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComponentHealth {
+    /// Whether the component can serve requests.
+    pub healthy: bool,
+
+    /// What was checked, or why the check failed.
+    pub detail: String,
+}
+
+impl ComponentHealth {
+    /// A component that passed its check.
+    pub(crate) fn ok(detail: impl Into<String>) -> Self {
+        Self {
+            healthy: true,
+            detail: detail.into(),
+        }
+    }
+
+    /// A component that failed its check.
+    pub(crate) fn failed(detail: impl Into<String>) -> Self {
+        Self {
+            healthy: false,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Readiness of a Sentinel, per component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HealthStatus {
+    /// True only if every component is healthy.
+    pub healthy: bool,
+
+    /// Whether the registry database can be read.
+    pub registry: ComponentHealth,
+
+    /// Whether the state monitor still accepts steps.
+    pub monitor: ComponentHealth,
+
+    /// Whether the running configuration passes validation.
+    pub config: ComponentHealth,
+}
+
+impl HealthStatus {
+    /// Combines component results into an overall status.
+    pub(crate) fn new(
+        registry: ComponentHealth,
+        monitor: ComponentHealth,
+        config: ComponentHealth,
+    ) -> Self {
+        Self {
+            healthy: registry.healthy && monitor.healthy && config.healthy,
+            registry,
+            monitor,
+            config,
+        }
+    }
+
+    /// Readiness of a registry on its own, for offline probes.
+    ///
+    /// Used by `sentinel status`, which inspects a registry database
+    /// without running a Sentinel. The registry is checked as by
+    /// [`Sentinel::health`](crate::Sentinel::health) with unknown tools
+    /// disallowed, so an empty registry is not ready; the monitor and
+    /// config are reported as not checked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_core::HealthStatus;
+    /// use sentinel_registry::{ReadOnlyRegistry, RegistryGuard, ToolSchema};
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let path = dir.path().join("registry");
+    /// RegistryGuard::new(&path)?.register_tool(&ToolSchema::builder("read_file").build()?)?;
+    ///
+    /// let health = HealthStatus::of_registry(&ReadOnlyRegistry::open(&path)?);
+    /// assert!(health.healthy);
+    /// assert_eq!(health.registry.detail, "1 tools registered");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn of_registry(verifier: &ReadOnlyRegistry) -> Self {
+        Self::new(
+            registry_health(verifier, false),
+            ComponentHealth::ok("not checked"),
+            ComponentHealth::ok("not checked"),
+        )
+    }
+
+    /// Readiness when the registry could not be opened at all.
+    ///
+    /// Reported by offline probes alongside [`of_registry`](Self::of_registry);
+    /// `reason` becomes the registry component's detail.
+    pub fn unavailable(reason: impl Into<String>) -> Self {
+        Self::new(
+            ComponentHealth::failed(reason),
+            ComponentHealth::ok("not checked"),
+            ComponentHealth::ok("not checked"),
+        )
+    }
+
+    /// Returns each component with its name, in report order.
+    pub fn components(&self) -> [(&'static str, &ComponentHealth); 3] {
+        [
+            ("registry", &self.registry),
+            ("monitor", &self.monitor),
+            ("config", &self.config),
+        ]
+    }
+}
+
+/// Checks that a registry can be read and can vouch for tool calls.
+///
+/// An empty registry fails unless unknown tools are allowed, since every
+/// call would then be blocked.
+pub(crate) fn registry_health(
+    verifier: &ReadOnlyRegistry,
+    allow_unknown_tools: bool,
+) -> ComponentHealth {
+    match verifier.get_root().and_then(|_| verifier.list_tools()) {
+        Ok(tools) if tools.is_empty() && !allow_unknown_tools => {
+            ComponentHealth::failed("no tools registered")
+        }
+        Ok(tools) => ComponentHealth::ok(format!("{} tools registered", tools.len())),
+        Err(e) => ComponentHealth::failed(format!("registry unreadable: {}", e)),
+    }
+}
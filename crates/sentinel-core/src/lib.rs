@@ -58,6 +58,11 @@
 //! - All checks execute in order: Registry → Monitor → Council
 //! - Any component can veto (short-circuit to Block)
 //! - The pipeline is fail-closed: errors result in Block, not Allow
+//!   (infrastructure errors may opt into Review via `on_infra_error`)
+//! - A council without consensus yields Review by default, or Block via
+//!   `on_no_consensus`
+//! - In `Observe` mode every call is allowed and the real verdict is kept
+//!   as the `shadow_verdict` of an `Evaluation` or report
 //! - Verdicts include full reasoning for audit trails
 //!
 //! ## References
@@ -69,16 +74,27 @@
 
 mod config;
 mod error;
+pub mod gateway;
+mod health;
+mod report;
 mod sentinel;
+mod session;
 mod verdict;
 
-pub use config::SentinelConfig;
+pub use config::{EvaluationMode, InfraErrorPolicy, NoConsensusPolicy, SentinelConfig};
 pub use error::SentinelError;
-pub use sentinel::Sentinel;
-pub use verdict::{BlockReason, ReviewFlag, Verdict};
+pub use gateway::{Gateway, StdioUpstream, Upstream};
+pub use health::{ComponentHealth, HealthStatus};
+pub use report::{AuditEvent, Evaluation, SecurityReport};
+pub use sentinel::{AuditHook, ReviewHandler, Sentinel};
+pub use session::SessionManager;
+pub use verdict::{
+    BlockReason, BlockReasonKind, JsonRpcError, ReviewFlag, Verdict, BLOCKED_ERROR_CODE,
+    BLOCKED_ERROR_MESSAGE, REVIEW_HEADER,
+};
 
 // Re-export component types for convenience
-pub use sentinel_council::{ActionProposal, CognitiveCouncil, CouncilVerdict};
+pub use sentinel_council::{ActionProposal, CognitiveCouncil, CouncilVerdict, WaluigiMode};
 pub use sentinel_monitor::{MonitorStatus, OperationType, StateMonitor, StateMonitorConfig};
 pub use sentinel_registry::{DriftLevel, RegistryGuard, ToolSchema, VerifyResult};
 
@@ -0,0 +1,103 @@
+//! Combined per-call security reports.
+//!
+//! A [`SecurityReport`] gathers what every pipeline stage concluded about a
+//! single tool call, alongside the final [`Verdict`]. It is produced by
+//! [`Sentinel::analyze_report`](crate::Sentinel::analyze_report) and is
+//! intended for dashboards and audit logs.
+//!
+//! An [`AuditEvent`] is the smaller record emitted for every blocked call,
+//! carrying the correlation id that ties it to the tracing span and to
+//! upstream logs.
+//!
+//! ## Scope
+//!
+//! Input scanning lives in `sentinel-firewall`, which the core does not
+//! depend on; callers that run the firewall record its result alongside
+//! this report.
+
+use serde::Serialize;
+
+use sentinel_council::CouncilVerdict;
+use sentinel_monitor::MonitorStatus;
+use sentinel_registry::{DriftReport, VerifyResult};
+
+use crate::config::EvaluationMode;
+use crate::verdict::Verdict;
+
+/// Everything a single full analysis produced.
+///
+/// Unlike [`Sentinel::analyze_tool_call`](crate::Sentinel::analyze_tool_call),
+/// the report runs every stage even after one blocks, so each field reflects
+/// that stage's own conclusion.
+#[derive(Debug, Clone, Serialize)]
+pub struct SecurityReport {
+    /// Name of the tool that was analyzed.
+    pub tool_name: String,
+
+    /// Registry verification result, or `None` if the lookup failed
+    /// (the failure is reflected in `verdict`).
+    pub registry: Option<VerifyResult>,
+
+    /// Drift against the registered schema, present when the hash mismatched.
+    pub drift: Option<DriftReport>,
+
+    /// Monitor snapshot taken after the step was recorded.
+    pub monitor: MonitorStatus,
+
+    /// Raw verdict of the Cognitive Council, or `None` if the council is
+    /// disabled.
+    pub council: Option<CouncilVerdict>,
+
+    /// Final verdict combining all stages.
+    ///
+    /// Always `Allow` in [`EvaluationMode::Observe`].
+    pub verdict: Verdict,
+
+    /// Mode the verdict was produced in.
+    pub mode: EvaluationMode,
+
+    /// In `Observe` mode, the verdict the pipeline reached, including a
+    /// genuine `Allow`. `None` when enforcing.
+    pub shadow_verdict: Option<Verdict>,
+
+    /// Identifier shared by this call's tracing span and audit event.
+    pub correlation_id: String,
+}
+
+/// Outcome of one analyzed tool call, as returned by
+/// [`Sentinel::evaluate_tool_call`](crate::Sentinel::evaluate_tool_call).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Evaluation {
+    /// Verdict to act on. Always `Allow` in [`EvaluationMode::Observe`].
+    pub verdict: Verdict,
+
+    /// Mode the verdict was produced in.
+    pub mode: EvaluationMode,
+
+    /// In `Observe` mode, the verdict the pipeline reached, including a
+    /// genuine `Allow`. `None` when enforcing.
+    pub shadow_verdict: Option<Verdict>,
+
+    /// Identifier shared by this call's tracing span and audit event.
+    pub correlation_id: String,
+}
+
+/// Record of a blocked tool call, passed to the
+/// [`AuditHook`](crate::AuditHook).
+///
+/// Emitted when `global.audit_logging` is on, including for blocks that
+/// [`EvaluationMode::Observe`] let through.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditEvent {
+    /// Caller-supplied or generated (UUID v4) correlation id.
+    pub correlation_id: String,
+
+    /// Name of the tool that was blocked.
+    pub tool_name: String,
+
+    /// The Block verdict the pipeline reached.
+    pub verdict: Verdict,
+
+    /// Mode the call was analyzed in; in `Observe` the block was not enforced.
+    pub mode: EvaluationMode,
+}
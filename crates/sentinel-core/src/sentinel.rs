@@ -5,17 +5,35 @@
 //! simple API for analyzing MCP messages.
 
 use crate::{
-    config::SentinelConfig,
+    config::{EvaluationMode, InfraErrorPolicy, NoConsensusPolicy, SentinelConfig},
     error::SentinelError,
+    health::{registry_health, ComponentHealth, HealthStatus},
+    report::{AuditEvent, Evaluation, SecurityReport},
+    session::SessionManager,
     verdict::{BlockReason, ReviewFlag, Verdict},
     Result,
 };
 
-use sentinel_council::{ActionProposal, CognitiveCouncil, CouncilVerdict};
-use sentinel_monitor::{OperationType, StateMonitor, StateMonitorConfig};
-use sentinel_registry::{RegistryGuard, ToolSchema, VerifyResult};
+use sentinel_council::{
+    ActionProposal, CognitiveCouncil, ConsensusEngine, Consequentialist, CouncilVerdict,
+    Deontologist, Logicist, ScopeEvaluator, WaluigiDetector,
+};
+use sentinel_monitor::{OperationClassifier, OperationType, StateMonitor, StateMonitorConfig};
+use sentinel_registry::{RegistryError, RegistryGuard, ToolSchema, VerifyResult};
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use tracing::{debug, info, info_span, warn};
+
+/// Callback that resolves a Review verdict, see
+/// [`Sentinel::with_review_handler`].
+pub type ReviewHandler = Box<dyn Fn(&[ReviewFlag]) -> Verdict + Send + Sync>;
 
-use tracing::{debug, info, warn};
+/// Callback that receives an [`AuditEvent`] for every blocked call, see
+/// [`Sentinel::with_audit_hook`].
+pub type AuditHook = Box<dyn Fn(&AuditEvent) + Send + Sync>;
 
 /// The unified MCP Sentinel security facade.
 ///
@@ -27,12 +45,37 @@ use tracing::{debug, info, warn};
 /// # Security Model
 ///
 /// The analysis pipeline is:
+/// 0. Denylist (tool names that are never allowed)
 /// 1. Registry verification (schema integrity)
 /// 2. State monitoring (resource limits)
 /// 3. Council evaluation (alignment verification)
 ///
 /// Any component can block execution. The pipeline is fail-closed:
-/// errors result in Block verdicts, not Allow.
+/// errors result in Block verdicts, not Allow. Infrastructure errors
+/// (as opposed to security failures) can instead be downgraded to Review
+/// with [`InfraErrorPolicy::FailOpenWithReview`].
+///
+/// Review flags raised by any phase are carried through to the final
+/// verdict, so a call flagged by the registry stays flagged even if the
+/// council approves it. A [`ReviewHandler`] can resolve such a Review to
+/// Allow or Block automatically.
+///
+/// In [`EvaluationMode::Observe`] every call is allowed; the verdict the
+/// pipeline reached is logged and reported as the shadow verdict of
+/// [`evaluate_tool_call`](Self::evaluate_tool_call) and
+/// [`analyze_report`](Self::analyze_report).
+///
+/// # Tenants
+///
+/// A gateway shared by several tenants can keep a separate trusted tool
+/// set per tenant with
+/// [`analyze_tool_call_for_tenant`](Self::analyze_tool_call_for_tenant).
+/// Tenants are opened explicitly with [`open_tenant`](Self::open_tenant);
+/// each has its own registry under `<db_path>.tenants/<tenant>`, built with
+/// the default registry's settings, and its own State Monitor. A tool
+/// registered for one tenant is unknown to every other, and one tenant
+/// cannot exhaust another's gas. At most `registry.max_tenants` tenants are
+/// open at once.
 ///
 /// # Example
 ///
@@ -53,11 +96,39 @@ pub struct Sentinel {
     /// Registry Guard for schema verification.
     registry: RegistryGuard,
 
+    /// Open tenants, each with its own registry and monitor.
+    tenants: HashMap<String, Tenant>,
+
     /// State Monitor for execution tracking.
     monitor: StateMonitor,
 
     /// Cognitive Council for alignment verification.
     council: CognitiveCouncil,
+
+    /// Per-session State Monitors for [`analyze_in_session`](Self::analyze_in_session).
+    sessions: SessionManager,
+
+    /// Optional resolver for Review verdicts.
+    review_handler: Option<ReviewHandler>,
+
+    /// Assigns the operation type (and so the gas cost) of each tool call;
+    /// every call is charged as `ToolCall` when unset.
+    classifier: Option<Box<dyn OperationClassifier>>,
+
+    /// Optional sink for audit events.
+    audit_hook: Option<AuditHook>,
+
+    /// Server the screened tools come from; tools are pinned to it when set.
+    origin: Option<String>,
+}
+
+/// A tenant's registry and execution state.
+struct Tenant {
+    /// The tenant's trusted tool set.
+    registry: RegistryGuard,
+
+    /// The tenant's gas and execution tracking.
+    monitor: StateMonitor,
 }
 
 impl Sentinel {
@@ -69,17 +140,13 @@ impl Sentinel {
     /// - Registry database cannot be opened
     /// - Configuration is invalid
     pub fn new(config: SentinelConfig) -> Result<Self> {
-        let registry = RegistryGuard::new(&config.registry.db_path)
-            .map_err(|e| SentinelError::Registry(e.to_string()))?;
-
-        // Configure state monitor from SentinelConfig
-        let monitor_config = StateMonitorConfig::new()
-            .with_gas_budget(config.monitor.gas_limit)
-            .with_context_capacity(config.monitor.max_context_bytes / 1000) // Approximate frames
-            .with_auto_flush(true);
+        let registry = RegistryGuard::new(&config.registry.db_path)?
+            .with_min_description_len(config.registry.min_description_len);
 
-        let monitor = StateMonitor::with_config(monitor_config);
-        let council = CognitiveCouncil::new();
+        let monitor = Self::build_monitor(&config);
+        let council = Self::build_council(&config)?;
+        let mut sessions = SessionManager::new(Self::monitor_config(&config));
+        Self::configure_sessions(&mut sessions, &config);
 
         info!(
             "Sentinel initialized with {} gas limit",
@@ -89,14 +156,234 @@ impl Sentinel {
         Ok(Self {
             config,
             registry,
+            tenants: HashMap::new(),
             monitor,
             council,
+            sessions,
+            review_handler: None,
+            classifier: None,
+            audit_hook: None,
+            origin: None,
         })
     }
 
+    /// Install a classifier that charges each tool call's gas.
+    ///
+    /// Without one, every tool call costs [`OperationType::ToolCall`].
+    ///
+    /// # Security Notes
+    ///
+    /// Tool names are chosen by the server. A classifier that prices calls
+    /// by name, such as [`DefaultClassifier`](sentinel_monitor::DefaultClassifier),
+    /// lets a server that names its tools `get_*` or `list_*` be charged as
+    /// cheap reads. Pin the tools you know with overrides before opting in.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_core::{OperationType, Sentinel, SentinelConfig};
+    /// use sentinel_monitor::DefaultClassifier;
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let mut config = SentinelConfig::default();
+    /// config.registry.db_path = dir.path().join("registry.db");
+    ///
+    /// let sentinel = Sentinel::new(config)?.with_classifier(Box::new(
+    ///     DefaultClassifier::new().with_override("deploy", OperationType::NetworkIo),
+    /// ));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_classifier(mut self, classifier: Box<dyn OperationClassifier>) -> Self {
+        self.classifier = Some(classifier);
+        self
+    }
+
+    /// Pin tools to the upstream server this Sentinel screens.
+    ///
+    /// With an origin set, [`register_tool`](Self::register_tool) pins each
+    /// tool to it and every call is verified against the pin, so a tool
+    /// first registered from another server is blocked with
+    /// [`BlockReason::OriginMismatch`] even if its schema is identical.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_core::{Sentinel, SentinelConfig};
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let mut config = SentinelConfig::default();
+    /// config.registry.db_path = dir.path().join("registry.db");
+    ///
+    /// let sentinel = Sentinel::new(config)?.with_origin("stdio:fs-server");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_origin(mut self, origin: impl Into<String>) -> Self {
+        self.origin = Some(origin.into());
+        self
+    }
+
+    /// Install a handler that resolves Review verdicts.
+    ///
+    /// The handler is called with the accumulated flags whenever the
+    /// pipeline would return a Review, and its verdict becomes the final
+    /// one. It can only escalate to Block or resolve to Allow: it is never
+    /// consulted for a Block, and if it returns a Review the original
+    /// Review is kept unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sentinel = Sentinel::new(config)?.with_review_handler(Box::new(|flags| {
+    ///     if flags.iter().any(|f| matches!(f, ReviewFlag::UnregisteredTool { .. })) {
+    ///         Verdict::block(BlockReason::SecurityViolation {
+    ///             description: "unregistered tool".to_string(),
+    ///         })
+    ///     } else {
+    ///         Verdict::review(flags.to_vec())
+    ///     }
+    /// }));
+    /// ```
+    pub fn with_review_handler(mut self, handler: ReviewHandler) -> Self {
+        self.review_handler = Some(handler);
+        self
+    }
+
+    /// Install a sink for the audit events of blocked calls.
+    ///
+    /// Every Block is also logged at `warn` with its correlation id,
+    /// hook or not. Nothing is emitted when `global.audit_logging` is off.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sentinel = Sentinel::new(config)?.with_audit_hook(Box::new(|event| {
+    ///     audit_log.append(serde_json::to_string(event).unwrap());
+    /// }));
+    /// ```
+    pub fn with_audit_hook(mut self, hook: AuditHook) -> Self {
+        self.audit_hook = Some(hook);
+        self
+    }
+
+    /// Swap in a new configuration without reopening the registry.
+    ///
+    /// Monitor, council, and global settings take effect for subsequent calls.
+    /// The current execution context carries over: gas already consumed is
+    /// charged against the new `gas_limit`, and the step history, context
+    /// frames and halted flag are kept, so a reload never un-halts a
+    /// session. The open registry and everything registered in it are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::Config`] if the new configuration changes
+    /// `registry.db_path` (which requires a restart) or is otherwise invalid.
+    /// On error the running configuration is left untouched.
+    pub fn reload_config(&mut self, new: SentinelConfig) -> Result<()> {
+        if new.registry.db_path != self.config.registry.db_path {
+            return Err(SentinelError::Config(format!(
+                "cannot move registry from {} to {} on reload; restart to change db_path",
+                self.config.registry.db_path.display(),
+                new.registry.db_path.display()
+            )));
+        }
+
+        let council = Self::build_council(&new)?;
+        self.monitor = Self::reload_monitor(&self.monitor, &new);
+        for tenant in self.tenants.values_mut() {
+            tenant.monitor = Self::reload_monitor(&tenant.monitor, &new);
+        }
+        self.sessions.set_monitor_config(Self::monitor_config(&new));
+        Self::configure_sessions(&mut self.sessions, &new);
+        self.council = council;
+        self.config = new;
+
+        info!(
+            "Sentinel configuration reloaded with {} gas limit",
+            self.config.monitor.gas_limit
+        );
+        Ok(())
+    }
+
+    /// Carry a monitor's state over to the settings of `config`.
+    fn reload_monitor(monitor: &StateMonitor, config: &SentinelConfig) -> StateMonitor {
+        let mut snapshot = monitor.snapshot();
+        snapshot.gas.set_initial(config.monitor.gas_limit);
+        StateMonitor::restore(snapshot, Self::monitor_config(config))
+    }
+
+    /// Configure the State Monitor from SentinelConfig.
+    fn build_monitor(config: &SentinelConfig) -> StateMonitor {
+        StateMonitor::with_config(Self::monitor_config(config))
+    }
+
+    /// Translate SentinelConfig into State Monitor settings.
+    fn monitor_config(config: &SentinelConfig) -> StateMonitorConfig {
+        StateMonitorConfig::new()
+            .with_gas_budget(config.monitor.gas_limit)
+            .with_gas_warn_threshold(config.monitor.gas_warn_threshold)
+            .with_context_capacity(config.monitor.max_context_bytes / 1000) // Approximate frames
+            .with_auto_flush(true)
+    }
+
+    /// Apply the session settings of `config`.
+    fn configure_sessions(sessions: &mut SessionManager, config: &SentinelConfig) {
+        let monitor = &config.monitor;
+        sessions.set_idle_ttl(
+            (monitor.session_idle_ttl_secs > 0)
+                .then(|| Duration::from_secs(monitor.session_idle_ttl_secs)),
+        );
+        sessions.set_max_sessions((monitor.max_sessions > 0).then_some(monitor.max_sessions));
+    }
+
+    /// Check the settings that cannot be represented safely.
+    fn validate_config(config: &SentinelConfig) -> Result<()> {
+        let council_config = &config.council;
+        if !(1..=3).contains(&council_config.min_votes_for_approval) {
+            return Err(SentinelError::Config(format!(
+                "min_votes_for_approval must be between 1 and 3, got {}",
+                council_config.min_votes_for_approval
+            )));
+        }
+        if !(0.0..=1.0).contains(&council_config.waluigi_threshold) {
+            return Err(SentinelError::Config(format!(
+                "waluigi_threshold must be between 0.0 and 1.0, got {}",
+                council_config.waluigi_threshold
+            )));
+        }
+        Ok(())
+    }
+
+    /// Configure the Cognitive Council from SentinelConfig.
+    fn build_council(config: &SentinelConfig) -> Result<CognitiveCouncil> {
+        Self::validate_config(config)?;
+        let council_config = &config.council;
+
+        let consensus = ConsensusEngine::with_threshold(
+            f64::from(council_config.min_votes_for_approval) / 3.0,
+            2,
+        );
+        let mut waluigi = WaluigiDetector::new();
+        waluigi.set_threshold(council_config.waluigi_threshold);
+
+        let mut council = CognitiveCouncil::with_components(
+            vec![
+                Box::new(Deontologist::new()),
+                Box::new(Consequentialist::new()),
+                Box::new(Logicist::new()),
+                Box::new(ScopeEvaluator::new()),
+            ],
+            consensus,
+            waluigi,
+        );
+        council.set_waluigi_enabled(council_config.detect_waluigi);
+        council.set_waluigi_mode(council_config.waluigi_mode);
+        Ok(council)
+    }
+
     /// Analyze a tool call for security threats.
     ///
     /// This runs the full security pipeline:
+    /// 0. Denylist (is the tool name in `tool_denylist`?)
     /// 1. Registry verification (is the tool schema valid?)
     /// 2. State monitoring (are we in a safe state?)
     /// 3. Council evaluation (is this action aligned?)
@@ -110,292 +397,2177 @@ impl Sentinel {
     /// # Returns
     ///
     /// A [`Verdict`] indicating whether the call should be allowed, blocked, or reviewed.
+    /// Always `Allow` in [`EvaluationMode::Observe`]; use
+    /// [`evaluate_tool_call`](Self::evaluate_tool_call) to also get the
+    /// shadow verdict from the same evaluation.
+    ///
+    /// A correlation id is generated for the call; use
+    /// [`analyze_tool_call_with_correlation`](Self::analyze_tool_call_with_correlation)
+    /// to supply one.
     pub fn analyze_tool_call(
         &mut self,
         tool_name: &str,
         schema: &ToolSchema,
         params: &serde_json::Value,
+    ) -> Result<Verdict> {
+        self.analyze_tool_call_with_correlation(tool_name, schema, params, None)
+    }
+
+    /// Analyze a tool call under a caller-supplied correlation id.
+    ///
+    /// Same as [`analyze_tool_call`](Self::analyze_tool_call).
+    ///
+    /// # Arguments
+    ///
+    /// * `correlation_id` - Id tying this call to gateway and upstream logs;
+    ///   a UUID v4 is generated if `None`. It is recorded on the tracing
+    ///   span and on the [`AuditEvent`] of a Block.
+    pub fn analyze_tool_call_with_correlation(
+        &mut self,
+        tool_name: &str,
+        schema: &ToolSchema,
+        params: &serde_json::Value,
+        correlation_id: Option<String>,
+    ) -> Result<Verdict> {
+        Ok(self
+            .evaluate_tool_call(tool_name, schema, params, correlation_id)?
+            .verdict)
+    }
+
+    /// Analyze a tool call, keeping the verdict the pipeline reached.
+    ///
+    /// Runs the pipeline once, exactly as
+    /// [`analyze_tool_call_with_correlation`](Self::analyze_tool_call_with_correlation),
+    /// and returns the enforced verdict together with the shadow verdict.
+    /// In [`EvaluationMode::Observe`] this is how a suppressed Block or
+    /// Review is told apart from a genuine Allow without analyzing (and
+    /// charging gas for) the call twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use sentinel_core::{EvaluationMode, Sentinel, SentinelConfig, ToolSchema};
+    ///
+    /// let mut config = SentinelConfig::default();
+    /// config.global.mode = EvaluationMode::Observe;
+    /// let mut sentinel = Sentinel::new(config)?;
+    ///
+    /// let schema = ToolSchema::builder("unknown_tool").build()?;
+    /// let evaluation =
+    ///     sentinel.evaluate_tool_call("unknown_tool", &schema, &serde_json::json!({}), None)?;
+    /// assert!(evaluation.verdict.is_allowed());
+    /// assert!(evaluation.shadow_verdict.is_some_and(|v| v.is_blocked()));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn evaluate_tool_call(
+        &mut self,
+        tool_name: &str,
+        schema: &ToolSchema,
+        params: &serde_json::Value,
+        correlation_id: Option<String>,
+    ) -> Result<Evaluation> {
+        let correlation_id = correlation_id.unwrap_or_else(new_correlation_id);
+        let span =
+            info_span!("analyze_tool_call", correlation_id = %correlation_id, tool = tool_name);
+        let _entered = span.enter();
+
+        let verdict = self.run_pipeline(tool_name, schema, params)?;
+        self.audit(&correlation_id, tool_name, &verdict);
+        let (verdict, shadow_verdict) = self.apply_mode(tool_name, verdict);
+        Ok(Evaluation {
+            verdict,
+            mode: self.config.global.mode,
+            shadow_verdict,
+            correlation_id,
+        })
+    }
+
+    /// Log a Block and pass its [`AuditEvent`] to the audit hook.
+    ///
+    /// Called with the pipeline's verdict before the evaluation mode applies.
+    fn audit(&self, correlation_id: &str, tool_name: &str, verdict: &Verdict) {
+        if !self.config.global.audit_logging || !verdict.is_blocked() {
+            return;
+        }
+
+        warn!(
+            correlation_id,
+            tool = tool_name,
+            mode = ?self.config.global.mode,
+            "Blocked tool call: {:?}",
+            verdict
+        );
+        if let Some(hook) = &self.audit_hook {
+            hook(&AuditEvent {
+                correlation_id: correlation_id.to_string(),
+                tool_name: tool_name.to_string(),
+                verdict: verdict.clone(),
+                mode: self.config.global.mode,
+            });
+        }
+    }
+
+    /// The pipeline behind `analyze_tool_call`, before the evaluation mode applies.
+    fn run_pipeline(
+        &mut self,
+        tool_name: &str,
+        schema: &ToolSchema,
+        params: &serde_json::Value,
     ) -> Result<Verdict> {
         debug!("Analyzing tool call: {}", tool_name);
 
+        // Phase 0: Denylist, an absolute kill switch ahead of everything
+        if let Some(verdict) = self.check_denylist(tool_name) {
+            return Ok(verdict);
+        }
+
+        let mut blocked = None;
+        let mut flags = Vec::new();
+
+        // Overhead of the analysis itself, fail-closed
+        let overhead_verdict = self.charge_analysis_overhead()?;
+        if self.absorb(overhead_verdict, &mut blocked, &mut flags) {
+            return Ok(blocked.unwrap_or_else(Verdict::allow));
+        }
+
         // Phase 1: Registry verification
         let registry_verdict = self.check_registry(tool_name, schema)?;
-        if let Some(verdict) = registry_verdict {
-            if self.config.global.short_circuit && verdict.is_blocked() {
-                return Ok(verdict);
-            }
+        if self.absorb(registry_verdict, &mut blocked, &mut flags) {
+            return Ok(blocked.unwrap_or_else(Verdict::allow));
         }
 
         // Phase 2: State monitoring (includes cycle detection)
         let monitor_verdict = self.check_monitor(tool_name)?;
-        if let Some(verdict) = monitor_verdict {
-            if self.config.global.short_circuit && verdict.is_blocked() {
-                return Ok(verdict);
-            }
+        if self.absorb(monitor_verdict, &mut blocked, &mut flags) {
+            return Ok(blocked.unwrap_or_else(Verdict::allow));
         }
 
         // Phase 3: Council evaluation
         let council_verdict = self.check_council(tool_name, params)?;
-        if let Some(verdict) = council_verdict {
-            return Ok(verdict);
-        }
+        self.absorb(council_verdict, &mut blocked, &mut flags);
 
-        // All checks passed
-        info!("Tool call '{}' approved by Sentinel", tool_name);
-        Ok(Verdict::allow())
+        Ok(self.conclude(tool_name, blocked, flags))
     }
 
-    /// Check schema integrity with the Registry Guard.
-    fn check_registry(&mut self, tool_name: &str, schema: &ToolSchema) -> Result<Option<Verdict>> {
-        debug!("Registry check for: {}", tool_name);
-
-        // Verify the tool schema
-        match self.registry.verify_tool(schema) {
-            VerifyResult::Valid => {
-                debug!("Schema verified for: {}", tool_name);
-                Ok(None)
-            }
-            VerifyResult::Invalid { expected, actual } => {
-                warn!(
-                    "Hash mismatch for '{}': expected {:?}, got {:?}",
-                    tool_name, expected, actual
-                );
-                Ok(Some(Verdict::block(BlockReason::HashMismatch {
-                    tool_name: tool_name.to_string(),
-                    expected: format!("{:?}", expected),
-                    actual: format!("{:?}", actual),
-                })))
-            }
-            VerifyResult::Unknown => {
-                if self.config.registry.allow_unknown_tools {
-                    debug!("Unknown tool '{}' allowed by config", tool_name);
-                    Ok(Some(Verdict::review(vec![ReviewFlag::NewTool {
-                        tool_name: tool_name.to_string(),
-                    }])))
-                } else {
-                    warn!("Unknown tool blocked: {}", tool_name);
-                    Ok(Some(Verdict::block(BlockReason::UnknownTool {
-                        tool_name: tool_name.to_string(),
-                    })))
-                }
-            }
-        }
+    /// Analyze a tool call and report what every stage concluded.
+    ///
+    /// Runs the same pipeline as [`analyze_tool_call`](Self::analyze_tool_call)
+    /// but never short-circuits, so the report carries the registry, monitor,
+    /// and council results even when an earlier stage blocks. The final
+    /// verdict is combined exactly as in `analyze_tool_call`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool_name` - The name of the tool being called
+    /// * `schema` - The tool's schema (for verification)
+    /// * `params` - The parameters being passed to the tool
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the State Monitor fails for a reason that is not
+    /// a security verdict.
+    pub fn analyze_report(
+        &mut self,
+        tool_name: &str,
+        schema: &ToolSchema,
+        params: &serde_json::Value,
+    ) -> Result<SecurityReport> {
+        self.analyze_report_with_correlation(tool_name, schema, params, None)
     }
 
-    /// Check execution state with the State Monitor.
+    /// Build a security report under a caller-supplied correlation id.
     ///
-    /// This also performs cycle detection - cycles are detected inside begin_step().
-    fn check_monitor(&mut self, tool_name: &str) -> Result<Option<Verdict>> {
-        debug!("Monitor check for: {}", tool_name);
+    /// Same as [`analyze_report`](Self::analyze_report); the id is handled as
+    /// in [`analyze_tool_call_with_correlation`](Self::analyze_tool_call_with_correlation)
+    /// and returned in the report.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`analyze_report`](Self::analyze_report).
+    pub fn analyze_report_with_correlation(
+        &mut self,
+        tool_name: &str,
+        schema: &ToolSchema,
+        params: &serde_json::Value,
+        correlation_id: Option<String>,
+    ) -> Result<SecurityReport> {
+        let correlation_id = correlation_id.unwrap_or_else(new_correlation_id);
+        let span = info_span!("analyze_report", correlation_id = %correlation_id, tool = tool_name);
+        let _entered = span.enter();
+        debug!("Building security report for: {}", tool_name);
 
-        // Begin a new step - this consumes gas and checks for cycles
-        match self.monitor.begin_step(tool_name, OperationType::ToolCall) {
-            Ok(()) => {}
-            Err(sentinel_monitor::MonitorError::GasExhausted {
-                required,
-                available,
-                ..
-            }) => {
-                warn!("Gas exhausted: need {}, have {}", required, available);
-                return Ok(Some(Verdict::block(BlockReason::GasExhausted {
-                    used: self.config.monitor.gas_limit - available,
-                    limit: self.config.monitor.gas_limit,
-                })));
-            }
-            Err(sentinel_monitor::MonitorError::CycleDetected { step, description }) => {
-                warn!("Cycle detected at step {}: {}", step, description);
-                return Ok(Some(Verdict::block(BlockReason::CycleDetected {
-                    cycle: description,
-                })));
-            }
-            Err(sentinel_monitor::MonitorError::ContextOverflow { current, limit }) => {
-                warn!("Context overflow: {} exceeds {}", current, limit);
-                return Ok(Some(Verdict::block(BlockReason::ContextOverflow {
-                    size: current,
-                    max: limit,
-                })));
+        let mut blocked = None;
+        let mut flags = Vec::new();
+
+        self.absorb(self.check_denylist(tool_name), &mut blocked, &mut flags);
+        if blocked.is_none() {
+            let overhead_verdict = self.charge_analysis_overhead()?;
+            self.absorb(overhead_verdict, &mut blocked, &mut flags);
+        }
+
+        let registry = match self.verify_registration(schema) {
+            Ok(result) => {
+                let verdict = self.registry_verdict(tool_name, &result);
+                self.absorb(verdict, &mut blocked, &mut flags);
+                Some(result)
             }
             Err(e) => {
-                return Err(SentinelError::Monitor(e));
+                let verdict = self.registry_error(tool_name, e);
+                self.absorb(Some(verdict), &mut blocked, &mut flags);
+                None
             }
-        }
+        };
+        let drift = matches!(registry, Some(VerifyResult::Invalid { .. }))
+            .then(|| self.registry.detect_drift(schema));
 
-        // Check for high gas usage (>80%)
-        let gas_remaining = self.monitor.gas_remaining();
-        let gas_limit = self.config.monitor.gas_limit;
-        let gas_used = gas_limit.saturating_sub(gas_remaining);
-        let usage_pct = ((gas_used as f64 / gas_limit as f64) * 100.0) as u8;
+        let monitor_verdict = self.check_monitor(tool_name)?;
+        self.absorb(monitor_verdict, &mut blocked, &mut flags);
+        let monitor = self.monitor.status_report();
 
-        if usage_pct > 80 {
-            debug!("High gas usage: {}%", usage_pct);
-            return Ok(Some(Verdict::review(vec![ReviewFlag::HighGasUsage {
-                percentage: usage_pct,
-            }])));
+        let council = self
+            .config
+            .council
+            .enabled
+            .then(|| self.council_evaluate(tool_name, params));
+        if let Some(council) = &council {
+            let council_verdict = self.council_verdict(tool_name, council.clone());
+            self.absorb(council_verdict, &mut blocked, &mut flags);
         }
 
-        Ok(None)
+        let verdict = self.conclude(tool_name, blocked, flags);
+        self.audit(&correlation_id, tool_name, &verdict);
+        let (verdict, shadow_verdict) = self.apply_mode(tool_name, verdict);
+        Ok(SecurityReport {
+            tool_name: tool_name.to_string(),
+            registry,
+            drift,
+            monitor,
+            council,
+            verdict,
+            mode: self.config.global.mode,
+            shadow_verdict,
+            correlation_id,
+        })
     }
 
-    /// Evaluate action safety with the Cognitive Council.
-    fn check_council(
-        &self,
-        tool_name: &str,
-        params: &serde_json::Value,
-    ) -> Result<Option<Verdict>> {
-        debug!("Council check for: {}", tool_name);
-
-        let proposal = ActionProposal::new(tool_name, params.to_string());
-
-        match self.council.evaluate(&proposal) {
-            CouncilVerdict::Approved {
-                tally: _,
-                waluigi_score: _,
-            } => {
-                debug!("Council approved: {}", tool_name);
-                Ok(None)
-            }
-            CouncilVerdict::Rejected {
-                reason,
-                tally,
-                waluigi_score: _,
-            } => {
-                warn!("Council rejected '{}': {}", tool_name, reason);
-                Ok(Some(Verdict::block(BlockReason::CouncilRejected {
-                    votes: format!("{:?}", tally),
-                    reason,
-                })))
-            }
-            CouncilVerdict::WaluigiVeto { score, patterns } => {
-                warn!("Waluigi effect detected for '{}': {:?}", tool_name, score);
-                Ok(Some(Verdict::block(BlockReason::WaluigiEffect {
-                    score: score.value(),
-                    patterns,
-                })))
-            }
-            CouncilVerdict::NoConsensus { tally, reason } => {
-                debug!("No consensus for '{}': {}", tool_name, reason);
-                Ok(Some(Verdict::review(vec![ReviewFlag::SplitVote {
-                    votes: format!("{:?}", tally),
-                }])))
+    /// Apply the configured [`EvaluationMode`] to a final verdict.
+    ///
+    /// Returns the verdict to act on and, in Observe mode, the verdict the
+    /// pipeline reached, whatever it was.
+    fn apply_mode(&self, tool_name: &str, verdict: Verdict) -> (Verdict, Option<Verdict>) {
+        match self.config.global.mode {
+            EvaluationMode::Enforce => (verdict, None),
+            EvaluationMode::Observe if verdict.is_allowed() => (Verdict::allow(), Some(verdict)),
+            EvaluationMode::Observe => {
+                info!(
+                    "Observe mode: allowing '{}', would have returned {:?}",
+                    tool_name, verdict
+                );
+                (Verdict::allow(), Some(verdict))
             }
         }
     }
 
-    /// Register a tool schema with the Registry Guard.
+    /// Combine the accumulated phase results into the final verdict.
     ///
-    /// This should be called during initialization to register known-good schemas.
-    pub fn register_tool(&mut self, schema: &ToolSchema) -> Result<()> {
-        self.registry
-            .register_tool(schema)
-            .map_err(|e| SentinelError::Registry(e.to_string()))?;
-        info!("Registered tool: {}", schema.name);
+    /// A Review is passed to the review handler, if one is installed.
+    fn conclude(
+        &self,
+        tool_name: &str,
+        blocked: Option<Verdict>,
+        flags: Vec<ReviewFlag>,
+    ) -> Verdict {
+        if let Some(verdict) = blocked {
+            return verdict;
+        }
+        if !flags.is_empty() {
+            return self.resolve_review(tool_name, flags);
+        }
+
+        // All checks passed
+        info!("Tool call '{}' approved by Sentinel", tool_name);
+        Verdict::allow()
+    }
+
+    /// Analyze a tool call against one session's execution state.
+    ///
+    /// Runs the same pipeline as [`analyze_tool_call`](Self::analyze_tool_call),
+    /// but gas, cycles, and halts are tracked per `session_id`: a session
+    /// halted by a cycle does not affect any other. The session is created
+    /// on first use; idle sessions are evicted first (see
+    /// [`sessions_mut`](Self::sessions_mut)).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::StateViolation`] if `session_id` is new and
+    /// `monitor.max_sessions` live sessions already exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - Transport-level identifier of the MCP session
+    /// * `tool_name` - The name of the tool being called
+    /// * `schema` - The tool's schema (for verification)
+    /// * `params` - The parameters being passed to the tool
+    pub fn analyze_in_session(
+        &mut self,
+        session_id: &str,
+        tool_name: &str,
+        schema: &ToolSchema,
+        params: &serde_json::Value,
+    ) -> Result<Verdict> {
+        self.sessions.evict_idle();
+
+        // Run the pipeline with the session's monitor in place of the default
+        std::mem::swap(&mut self.monitor, self.sessions.monitor_mut(session_id)?);
+        let verdict = self.analyze_tool_call(tool_name, schema, params);
+        std::mem::swap(&mut self.monitor, self.sessions.monitor_mut(session_id)?);
+
+        verdict
+    }
+
+    /// Open a tenant, creating its registry if it does not exist yet.
+    ///
+    /// The registry is opened at `<db_path>.tenants/<tenant>` with the
+    /// default registry's settings (TTL, drift config, audit retention,
+    /// minimum description length), and the tenant gets a fresh State
+    /// Monitor. Opening an open tenant does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::Config`] for an invalid tenant id,
+    /// [`SentinelError::StateViolation`] if `registry.max_tenants` tenants
+    /// are already open, or a registry error if the database cannot be
+    /// opened.
+    ///
+    /// # Security Notes
+    ///
+    /// Tenant ids become directory names, so they are restricted to ASCII
+    /// letters, digits, `-` and `_`; anything else (including `..`) is
+    /// rejected rather than sanitized. Open tenants only from an
+    /// authenticated provisioning path: unlike analysis, this creates files.
+    pub fn open_tenant(&mut self, tenant: &str) -> Result<()> {
+        let path = self.tenant_db_path(tenant)?;
+        if self.tenants.contains_key(tenant) {
+            return Ok(());
+        }
+        let max = self.config.registry.max_tenants;
+        if max > 0 && self.tenants.len() >= max {
+            return Err(SentinelError::StateViolation(
+                "tenant limit reached".to_string(),
+            ));
+        }
+
+        debug!(
+            "Opening registry for tenant '{}' at {}",
+            tenant,
+            path.display()
+        );
+        let tenant_state = Tenant {
+            registry: self.registry.open_like(path)?,
+            monitor: Self::build_monitor(&self.config),
+        };
+        self.tenants.insert(tenant.to_string(), tenant_state);
+        Ok(())
+    }
+
+    /// Close a tenant, releasing its registry and monitor.
+    ///
+    /// The registry stays on disk and can be opened again.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tenant was open.
+    pub fn close_tenant(&mut self, tenant: &str) -> bool {
+        self.tenants.remove(tenant).is_some()
+    }
+
+    /// Check whether a tenant is open.
+    pub fn is_tenant_open(&self, tenant: &str) -> bool {
+        self.tenants.contains_key(tenant)
+    }
+
+    /// Analyze a tool call against one tenant's registry.
+    ///
+    /// Runs the same pipeline as [`analyze_tool_call`](Self::analyze_tool_call),
+    /// with the tenant's registry and State Monitor in place of the default
+    /// ones. End the step with [`end_step_for_tenant`](Self::end_step_for_tenant).
+    ///
+    /// # Arguments
+    ///
+    /// * `tenant` - Tenant identifier, derived from authentication
+    /// * `tool_name` - The name of the tool being called
+    /// * `schema` - The tool's schema (for verification)
+    /// * `params` - The parameters being passed to the tool
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::Config`] if the tenant is not open; nothing
+    /// is created on disk.
+    pub fn analyze_tool_call_for_tenant(
+        &mut self,
+        tenant: &str,
+        tool_name: &str,
+        schema: &ToolSchema,
+        params: &serde_json::Value,
+    ) -> Result<Verdict> {
+        let mut state = self
+            .tenants
+            .remove(tenant)
+            .ok_or_else(|| Self::tenant_not_open(tenant))?;
+
+        // Run the pipeline with the tenant's registry and monitor in place
+        std::mem::swap(&mut self.registry, &mut state.registry);
+        std::mem::swap(&mut self.monitor, &mut state.monitor);
+        let verdict = self.analyze_tool_call(tool_name, schema, params);
+        std::mem::swap(&mut self.registry, &mut state.registry);
+        std::mem::swap(&mut self.monitor, &mut state.monitor);
+
+        self.tenants.insert(tenant.to_string(), state);
+        verdict
+    }
+
+    /// Mark the current step of a tenant as completed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::Config`] if the tenant is not open, or a
+    /// monitor error if no step is in progress.
+    pub fn end_step_for_tenant(&mut self, tenant: &str, result: &str) -> Result<()> {
+        self.tenant_mut(tenant)?.monitor.end_step(result)?;
+        Ok(())
+    }
+
+    /// Register a tool schema in one tenant's registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::Config`] if the tenant is not open, or any
+    /// error from registration.
+    pub fn register_tool_for_tenant(&mut self, tenant: &str, schema: &ToolSchema) -> Result<()> {
+        self.tenant_registry_mut(tenant)?.register_tool(schema)?;
+        info!("Registered tool for tenant '{}': {}", tenant, schema.name);
+        Ok(())
+    }
+
+    /// Get an open tenant's registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::Config`] if the tenant is not open.
+    pub fn tenant_registry_mut(&mut self, tenant: &str) -> Result<&mut RegistryGuard> {
+        Ok(&mut self.tenant_mut(tenant)?.registry)
+    }
+
+    /// Get an open tenant.
+    fn tenant_mut(&mut self, tenant: &str) -> Result<&mut Tenant> {
+        self.tenants
+            .get_mut(tenant)
+            .ok_or_else(|| Self::tenant_not_open(tenant))
+    }
+
+    /// Error for a tenant that has not been opened.
+    fn tenant_not_open(tenant: &str) -> SentinelError {
+        SentinelError::Config(format!(
+            "tenant {:?} is not open; call open_tenant first",
+            tenant
+        ))
+    }
+
+    /// Directory of a tenant's registry: `<db_path>.tenants/<tenant>`.
+    fn tenant_db_path(&self, tenant: &str) -> Result<PathBuf> {
+        let valid = !tenant.is_empty()
+            && tenant
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(SentinelError::Config(format!(
+                "invalid tenant id {:?}: use ASCII letters, digits, '-' and '_'",
+                tenant
+            )));
+        }
+
+        let db_path = &self.config.registry.db_path;
+        let mut dir = db_path.file_name().unwrap_or_default().to_os_string();
+        dir.push(".tenants");
+        Ok(db_path.with_file_name(dir).join(tenant))
+    }
+
+    /// Mark the current step of a session as completed.
+    pub fn end_step_in_session(&mut self, session_id: &str, result: &str) -> Result<()> {
+        self.sessions.monitor_mut(session_id)?.end_step(result)?;
         Ok(())
     }
 
-    /// Mark the current step as completed.
-    ///
-    /// This should be called after successful tool execution.
-    pub fn end_step(&mut self, result: &str) -> Result<()> {
-        self.monitor.end_step(result)?;
-        Ok(())
+    /// Get the per-session monitors.
+    pub fn sessions(&self) -> &SessionManager {
+        &self.sessions
+    }
+
+    /// Get the per-session monitors for configuration (e.g. idle TTL).
+    pub fn sessions_mut(&mut self) -> &mut SessionManager {
+        &mut self.sessions
+    }
+
+    /// Let the review handler settle a Review; a Review it returns is ignored.
+    fn resolve_review(&self, tool_name: &str, flags: Vec<ReviewFlag>) -> Verdict {
+        let Some(handler) = &self.review_handler else {
+            return Verdict::review(flags);
+        };
+        match handler(&flags) {
+            verdict @ Verdict::Block { .. } => {
+                warn!("Review of '{}' escalated to Block by handler", tool_name);
+                verdict
+            }
+            Verdict::Allow => {
+                info!("Review of '{}' resolved to Allow by handler", tool_name);
+                Verdict::allow()
+            }
+            Verdict::Review { .. } => Verdict::review(flags),
+        }
+    }
+
+    /// Block the call if its name is denylisted.
+    fn check_denylist(&self, tool_name: &str) -> Option<Verdict> {
+        if !self.config.tool_denylist.contains(tool_name) {
+            return None;
+        }
+        warn!("Tool '{}' is denylisted", tool_name);
+        Some(Verdict::block(BlockReason::DeniedTool {
+            tool_name: tool_name.to_string(),
+        }))
+    }
+
+    /// Fold one phase's verdict into the running result.
+    ///
+    /// The first Block wins; Review flags accumulate. Returns true when the
+    /// pipeline should stop (a Block under `short_circuit`).
+    fn absorb(
+        &self,
+        verdict: Option<Verdict>,
+        blocked: &mut Option<Verdict>,
+        flags: &mut Vec<ReviewFlag>,
+    ) -> bool {
+        match verdict {
+            Some(verdict @ Verdict::Block { .. }) => {
+                if blocked.is_none() {
+                    *blocked = Some(verdict);
+                }
+                self.config.global.short_circuit
+            }
+            Some(Verdict::Review { flags: new }) => {
+                flags.extend(new);
+                false
+            }
+            Some(Verdict::Allow) | None => false,
+        }
+    }
+
+    /// Apply the configured [`InfraErrorPolicy`] to a non-security failure.
+    fn infra_error(&self, component: &str, error: impl std::fmt::Display) -> Verdict {
+        let error = error.to_string();
+        match self.config.global.on_infra_error {
+            InfraErrorPolicy::FailClosed => {
+                warn!("{} error, failing closed: {}", component, error);
+                Verdict::block(BlockReason::InfrastructureError {
+                    component: component.to_string(),
+                    error,
+                })
+            }
+            InfraErrorPolicy::FailOpenWithReview => {
+                warn!("{} error, failing open for review: {}", component, error);
+                Verdict::review(vec![ReviewFlag::InfrastructureError {
+                    component: component.to_string(),
+                    error,
+                }])
+            }
+        }
+    }
+
+    /// Run only the registry integrity check for a tool call.
+    ///
+    /// A cheap gate for steady-state traffic: no gas is consumed, no step is
+    /// recorded, and the council is not consulted. Use it to decide whether
+    /// the full [`analyze_tool_call`](Self::analyze_tool_call) pipeline is
+    /// needed; a `Valid` result says nothing about the safety of the
+    /// parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool_name` - The name of the tool being called
+    /// * `schema` - The tool's schema (for verification)
+    ///
+    /// # Returns
+    ///
+    /// The registry's [`VerifyResult`]. A schema whose name differs from
+    /// `tool_name` does not vouch for the call and yields `Unknown`.
+    pub fn verify_only(&self, tool_name: &str, schema: &ToolSchema) -> VerifyResult {
+        if schema.name != tool_name {
+            warn!(
+                "Schema '{}' presented for tool call '{}'",
+                schema.name, tool_name
+            );
+            return VerifyResult::Unknown;
+        }
+
+        self.registry.verify_tool(schema)
+    }
+
+    /// Check schema integrity with the Registry Guard.
+    fn check_registry(&mut self, tool_name: &str, schema: &ToolSchema) -> Result<Option<Verdict>> {
+        debug!("Registry check for: {}", tool_name);
+
+        // Verify the tool schema; a storage failure is not a verdict on the tool
+        match self.verify_registration(schema) {
+            Ok(result) => Ok(self.registry_verdict(tool_name, &result)),
+            Err(e) => Ok(Some(self.registry_error(tool_name, e))),
+        }
+    }
+
+    /// Look up a schema in the registry, checking the origin pin when this
+    /// sentinel was built [`with_origin`](Self::with_origin).
+    fn verify_registration(
+        &self,
+        schema: &ToolSchema,
+    ) -> std::result::Result<VerifyResult, RegistryError> {
+        match &self.origin {
+            Some(origin) => self.registry.verify_tool_from(schema, origin),
+            None => self.registry.try_verify_tool(schema),
+        }
+    }
+
+    /// Map a registry read failure to a phase verdict.
+    ///
+    /// Stored data that fails to decode blocks as a hash mismatch whatever
+    /// the [`InfraErrorPolicy`]; only an unavailable registry is subject to it.
+    fn registry_error(&self, tool_name: &str, error: RegistryError) -> Verdict {
+        if !error.is_integrity_failure() {
+            return self.infra_error("registry", error);
+        }
+        warn!("Registry integrity failure for '{}': {}", tool_name, error);
+        Verdict::block(BlockReason::HashMismatch {
+            tool_name: tool_name.to_string(),
+            expected: "unreadable stored hash".to_string(),
+            actual: error.to_string(),
+        })
+    }
+
+    /// Map a registry verification result to a phase verdict.
+    fn registry_verdict(&self, tool_name: &str, result: &VerifyResult) -> Option<Verdict> {
+        match result {
+            VerifyResult::Valid => {
+                debug!("Schema verified for: {}", tool_name);
+                None
+            }
+            VerifyResult::Invalid { expected, actual } => {
+                warn!(
+                    "Hash mismatch for '{}': expected {:?}, got {:?}",
+                    tool_name, expected, actual
+                );
+                Some(Verdict::block(BlockReason::HashMismatch {
+                    tool_name: tool_name.to_string(),
+                    expected: format!("{:?}", expected),
+                    actual: format!("{:?}", actual),
+                }))
+            }
+            VerifyResult::Drifted(report) => {
+                warn!(
+                    "Schema drift for '{}': {:?} {:?}",
+                    tool_name, report.level, report.changes
+                );
+                Some(Verdict::block(BlockReason::SchemaDrift {
+                    tool_name: tool_name.to_string(),
+                    drift_level: format!("{:?}", report.level),
+                }))
+            }
+            VerifyResult::OriginMismatch { expected, actual } => {
+                warn!(
+                    "Origin mismatch for '{}': registered from {}, presented by {}",
+                    tool_name, expected, actual
+                );
+                Some(Verdict::block(BlockReason::OriginMismatch {
+                    tool_name: tool_name.to_string(),
+                    expected: expected.clone(),
+                    actual: actual.clone(),
+                }))
+            }
+            VerifyResult::Unknown => {
+                if self.config.registry.allow_unknown_tools {
+                    debug!("Unknown tool '{}' allowed by config", tool_name);
+                    Some(Verdict::review(vec![ReviewFlag::UnregisteredTool {
+                        name: tool_name.to_string(),
+                    }]))
+                } else {
+                    warn!("Unknown tool blocked: {}", tool_name);
+                    Some(Verdict::block(BlockReason::UnknownTool {
+                        tool_name: tool_name.to_string(),
+                    }))
+                }
+            }
+        }
+    }
+
+    /// Charge `analysis_gas_overhead` to the monitor.
+    ///
+    /// Blocks with `GasExhausted` if the remaining gas cannot cover it.
+    fn charge_analysis_overhead(&mut self) -> Result<Option<Verdict>> {
+        let overhead = self.config.analysis_gas_overhead;
+        if overhead == 0 {
+            return Ok(None);
+        }
+
+        match self.monitor.consume_gas(overhead) {
+            Ok(_) => Ok(None),
+            Err(sentinel_monitor::MonitorError::GasExhausted {
+                required,
+                available,
+                ..
+            }) => {
+                warn!(
+                    "Gas exhausted by analysis overhead: need {}, have {}",
+                    required, available
+                );
+                Ok(Some(self.gas_exhausted(available)))
+            }
+            Err(e) => Err(SentinelError::Monitor(e)),
+        }
+    }
+
+    /// Block verdict for a budget with `available` gas left.
+    fn gas_exhausted(&self, available: u64) -> Verdict {
+        Verdict::block(BlockReason::GasExhausted {
+            used: self.config.monitor.gas_limit.saturating_sub(available),
+            limit: self.config.monitor.gas_limit,
+        })
+    }
+
+    /// Check execution state with the State Monitor.
+    ///
+    /// This also performs cycle detection - cycles are detected inside begin_step().
+    fn check_monitor(&mut self, tool_name: &str) -> Result<Option<Verdict>> {
+        debug!("Monitor check for: {}", tool_name);
+
+        // Begin a new step - this consumes gas and checks for cycles
+        let op_type = self
+            .classifier
+            .as_ref()
+            .map_or(OperationType::ToolCall, |classifier| {
+                classifier.classify(tool_name)
+            });
+        match self.monitor.begin_step(tool_name, op_type) {
+            Ok(()) => {}
+            Err(sentinel_monitor::MonitorError::GasExhausted {
+                required,
+                available,
+                ..
+            }) => {
+                warn!("Gas exhausted: need {}, have {}", required, available);
+                return Ok(Some(self.gas_exhausted(available)));
+            }
+            Err(sentinel_monitor::MonitorError::CycleDetected { step, description }) => {
+                warn!("Cycle detected at step {}: {}", step, description);
+                return Ok(Some(Verdict::block(BlockReason::CycleDetected {
+                    cycle: description,
+                })));
+            }
+            Err(sentinel_monitor::MonitorError::ContextOverflow { current, limit }) => {
+                warn!("Context overflow: {} exceeds {}", current, limit);
+                return Ok(Some(Verdict::block(BlockReason::ContextOverflow {
+                    size: current,
+                    max: limit,
+                })));
+            }
+            Err(e) => {
+                return Err(SentinelError::Monitor(e));
+            }
+        }
+
+        // Flag high gas usage once, on the call that crosses the warn threshold
+        if self.monitor.gas_warning_crossed() {
+            let gas_limit = self.config.monitor.gas_limit;
+            let gas_used = gas_limit.saturating_sub(self.monitor.gas_remaining());
+            let usage_pct = ((gas_used as f64 / gas_limit as f64) * 100.0) as u8;
+            debug!("High gas usage: {}%", usage_pct);
+            return Ok(Some(Verdict::review(vec![ReviewFlag::HighGasUsage {
+                percentage: usage_pct,
+            }])));
+        }
+
+        Ok(None)
+    }
+
+    /// Evaluate action safety with the Cognitive Council.
+    fn check_council(
+        &self,
+        tool_name: &str,
+        params: &serde_json::Value,
+    ) -> Result<Option<Verdict>> {
+        if !self.config.council.enabled {
+            return Ok(None);
+        }
+        debug!("Council check for: {}", tool_name);
+
+        let verdict = self.council_evaluate(tool_name, params);
+        Ok(self.council_verdict(tool_name, verdict))
+    }
+
+    /// Put the tool call to the Cognitive Council.
+    ///
+    /// The arguments are model output, so they are also screened for
+    /// alignment inversion against `council.waluigi_threshold`.
+    fn council_evaluate(&self, tool_name: &str, params: &serde_json::Value) -> CouncilVerdict {
+        let args = params.to_string();
+        let proposal = ActionProposal::new(tool_name, args.clone()).with_response(args);
+        self.council.evaluate(&proposal)
+    }
+
+    /// Map a council verdict to a phase verdict.
+    fn council_verdict(&self, tool_name: &str, verdict: CouncilVerdict) -> Option<Verdict> {
+        match verdict {
+            CouncilVerdict::Approved {
+                tally: _,
+                waluigi_score: _,
+            } => {
+                debug!("Council approved: {}", tool_name);
+                None
+            }
+            CouncilVerdict::Rejected {
+                reason,
+                tally,
+                waluigi_score: _,
+            } => {
+                warn!("Council rejected '{}': {}", tool_name, reason);
+                Some(Verdict::block(BlockReason::CouncilRejected {
+                    votes: tally.to_string(),
+                    reason,
+                }))
+            }
+            CouncilVerdict::WaluigiVeto { score, patterns } => {
+                warn!("Waluigi effect detected for '{}': {:?}", tool_name, score);
+                Some(Verdict::block(BlockReason::WaluigiEffect {
+                    score: score.value(),
+                    patterns,
+                }))
+            }
+            CouncilVerdict::NoConsensus { tally, reason } => {
+                match self.config.council.on_no_consensus {
+                    NoConsensusPolicy::Block => {
+                        warn!("No consensus for '{}', blocking: {}", tool_name, reason);
+                        Some(Verdict::block(BlockReason::NoConsensus {
+                            votes: tally.to_string(),
+                            reason,
+                        }))
+                    }
+                    NoConsensusPolicy::Review => {
+                        debug!("No consensus for '{}': {}", tool_name, reason);
+                        Some(Verdict::review(vec![ReviewFlag::SplitVote {
+                            votes: tally.to_string(),
+                        }]))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Register a tool schema with the Registry Guard.
+    ///
+    /// This should be called during initialization to register known-good schemas.
+    /// With an origin set (see [`with_origin`](Self::with_origin)) the tool is
+    /// pinned to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::RegistryFailure`] if the schema is refused, e.g.
+    /// because the tool is pinned to a different origin.
+    pub fn register_tool(&mut self, schema: &ToolSchema) -> Result<()> {
+        match &self.origin {
+            Some(origin) => self.registry.register_tool_from(schema, origin)?,
+            None => self.registry.register_tool(schema)?,
+        };
+        info!("Registered tool: {}", schema.name);
+        Ok(())
+    }
+
+    /// Mark the current step as completed.
+    ///
+    /// This should be called after successful tool execution.
+    pub fn end_step(&mut self, result: &str) -> Result<()> {
+        self.monitor.end_step(result)?;
+        Ok(())
+    }
+
+    /// Check if a step was begun by `analyze_tool_call` and not yet ended.
+    ///
+    /// A step stays open when a later phase blocks the call, so callers
+    /// that drive `end_step` themselves use this to close it on every path.
+    pub fn step_in_progress(&self) -> bool {
+        self.monitor.step_in_progress()
+    }
+
+    /// Get the current gas remaining.
+    pub fn gas_remaining(&self) -> u64 {
+        self.monitor.gas_remaining()
+    }
+
+    /// Reset the state monitor for a new execution context.
+    pub fn reset_monitor(&mut self) {
+        self.monitor.reset();
+    }
+
+    /// Get the registry's Merkle root hash.
+    pub fn registry_root(&mut self) -> sentinel_registry::Hash {
+        self.registry.get_root()
+    }
+
+    /// Check if monitor is halted due to security violation.
+    pub fn is_halted(&self) -> bool {
+        self.monitor.is_halted()
+    }
+
+    /// Get the step count.
+    pub fn step_count(&self) -> u64 {
+        self.monitor.step_count()
+    }
+
+    /// Report whether this Sentinel is ready to analyze tool calls.
+    ///
+    /// Checks, without side effects:
+    /// - **registry**: every stored hash and the root epoch can be read
+    ///   and decoded (one read per registered tool), and at least one tool
+    ///   is registered unless `registry.allow_unknown_tools` is set
+    /// - **monitor**: the state monitor is not halted; a halt blocks every
+    ///   call until [`reset_monitor`](Self::reset_monitor)
+    /// - **config**: the running configuration passes the same validation
+    ///   as [`new`](Self::new)
+    ///
+    /// Tenant registries are not checked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_core::{Sentinel, SentinelConfig};
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let mut config = SentinelConfig::default();
+    /// config.registry.db_path = dir.path().join("registry.db");
+    /// let sentinel = Sentinel::new(config)?;
+    ///
+    /// // Nothing registered and unknown tools blocked: every call would fail
+    /// let health = sentinel.health();
+    /// assert!(!health.healthy);
+    /// eprintln!("{}", serde_json::to_string(&health)?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn health(&self) -> HealthStatus {
+        let registry = registry_health(
+            &self.registry.read_only_handle(),
+            self.config.registry.allow_unknown_tools,
+        );
+
+        let status = self.monitor.status_report();
+        let monitor = if status.halted {
+            ComponentHealth::failed(format!(
+                "halted after {} steps (cycle detected: {}, gas remaining: {})",
+                status.step_count, status.cycle_detected, status.gas_remaining
+            ))
+        } else {
+            ComponentHealth::ok(format!("{} gas remaining", status.gas_remaining))
+        };
+
+        let config = match Self::validate_config(&self.config) {
+            Ok(()) => ComponentHealth::ok("valid"),
+            Err(e) => ComponentHealth::failed(e.to_string()),
+        };
+
+        HealthStatus::new(registry, monitor, config)
+    }
+}
+
+/// Generate a fresh correlation id.
+fn new_correlation_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sentinel_monitor::DefaultClassifier;
+    use sentinel_registry::models::Result as RegistryResult;
+    use sentinel_registry::storage::Storage;
+    use sentinel_registry::{AuditEntry, Hash, HashAlgorithm, HashMigration};
+    use std::sync::{Arc, Mutex};
+    use tempfile::TempDir;
+
+    fn test_config(temp_dir: &TempDir) -> SentinelConfig {
+        let mut config = SentinelConfig::default();
+        config.registry.db_path = temp_dir.path().join("test_registry.db");
+        config.registry.allow_unknown_tools = true; // For testing
+        config
+    }
+
+    fn test_schema() -> ToolSchema {
+        ToolSchema {
+            name: "test_tool".to_string(),
+            description: "A test tool".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "input": { "type": "string" }
+                }
+            }),
+            output_schema: serde_json::json!({
+                "type": "string"
+            }),
+        }
+    }
+
+    #[test]
+    fn test_sentinel_creation() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let sentinel = Sentinel::new(config);
+        assert!(sentinel.is_ok());
+    }
+
+    #[test]
+    fn test_register_and_verify() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let schema = test_schema();
+        sentinel.register_tool(&schema).unwrap();
+
+        // Verify passes for registered tool
+        let params = serde_json::json!({ "input": "test" });
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &schema, &params)
+            .unwrap();
+
+        // Should allow (or review for new tool depending on config)
+        assert!(!verdict.is_blocked());
+    }
+
+    #[test]
+    fn test_tenant_registries_are_isolated() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.registry.allow_unknown_tools = false;
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let schema = test_schema();
+        let params = serde_json::json!({ "input": "test" });
+        sentinel.open_tenant("tenant-a").unwrap();
+        sentinel.open_tenant("tenant-b").unwrap();
+        sentinel
+            .register_tool_for_tenant("tenant-a", &schema)
+            .unwrap();
+
+        let verdict = sentinel
+            .analyze_tool_call_for_tenant("tenant-a", "test_tool", &schema, &params)
+            .unwrap();
+        assert!(verdict.is_allowed(), "{:?}", verdict);
+        sentinel.end_step_for_tenant("tenant-a", "ok").unwrap();
+
+        let verdict = sentinel
+            .analyze_tool_call_for_tenant("tenant-b", "test_tool", &schema, &params)
+            .unwrap();
+        assert!(matches!(
+            verdict,
+            Verdict::Block {
+                reason: BlockReason::UnknownTool { .. }
+            }
+        ));
+
+        // Nor does the default registry know it
+        assert_eq!(
+            sentinel.verify_only("test_tool", &schema),
+            VerifyResult::Unknown
+        );
+        assert!(temp_dir
+            .path()
+            .join("test_registry.db.tenants/tenant-a")
+            .is_dir());
+    }
+
+    #[test]
+    fn test_security_tenant_id_cannot_escape_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+
+        for tenant in ["", "..", "../other", "a/b", "a\\b", "tenant.a"] {
+            let err = sentinel.open_tenant(tenant).unwrap_err();
+            assert!(matches!(err, SentinelError::Config(_)), "{:?}", tenant);
+        }
+    }
+
+    #[test]
+    fn test_security_analyze_does_not_create_tenant() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+        let params = serde_json::json!({ "input": "test" });
+
+        let err = sentinel
+            .analyze_tool_call_for_tenant("stranger", "test_tool", &test_schema(), &params)
+            .unwrap_err();
+        assert!(matches!(err, SentinelError::Config(_)), "{:?}", err);
+        assert!(sentinel
+            .register_tool_for_tenant("stranger", &test_schema())
+            .is_err());
+        assert!(!temp_dir.path().join("test_registry.db.tenants").exists());
+    }
+
+    #[test]
+    fn test_security_max_tenants_enforced() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.registry.max_tenants = 1;
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        sentinel.open_tenant("a").unwrap();
+        sentinel.open_tenant("a").unwrap();
+        assert!(matches!(
+            sentinel.open_tenant("b"),
+            Err(SentinelError::StateViolation(_))
+        ));
+
+        assert!(sentinel.close_tenant("a"));
+        sentinel.open_tenant("b").unwrap();
+        assert!(!sentinel.is_tenant_open("a"));
+    }
+
+    #[test]
+    fn test_security_tenant_gas_is_isolated() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.monitor.gas_limit = 10;
+        let mut sentinel = Sentinel::new(config).unwrap();
+        sentinel.open_tenant("a").unwrap();
+        sentinel.open_tenant("b").unwrap();
+        let schema = test_schema();
+        let params = serde_json::json!({ "input": "test" });
+
+        // Tenant A spends its whole budget
+        let verdict = sentinel
+            .analyze_tool_call_for_tenant("a", "test_tool", &schema, &params)
+            .unwrap();
+        assert!(!verdict.is_blocked(), "{:?}", verdict);
+        sentinel.end_step_for_tenant("a", "ok").unwrap();
+        let verdict = sentinel
+            .analyze_tool_call_for_tenant("a", "other_tool", &schema, &params)
+            .unwrap();
+        assert!(verdict.is_blocked(), "{:?}", verdict);
+
+        // Tenant B and the default monitor are untouched
+        let verdict = sentinel
+            .analyze_tool_call_for_tenant("b", "test_tool", &schema, &params)
+            .unwrap();
+        assert!(!verdict.is_blocked(), "{:?}", verdict);
+        assert_eq!(sentinel.gas_remaining(), 10);
+    }
+
+    #[test]
+    fn test_tenant_registry_inherits_settings() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.registry.min_description_len = 50;
+        let mut sentinel = Sentinel::new(config).unwrap();
+        sentinel.open_tenant("a").unwrap();
+
+        assert!(sentinel
+            .register_tool_for_tenant("a", &test_schema())
+            .is_err());
+    }
+
+    #[test]
+    fn test_unknown_tool_blocked() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.registry.allow_unknown_tools = false;
+
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let schema = test_schema();
+        let params = serde_json::json!({});
+
+        let verdict = sentinel
+            .analyze_tool_call("unknown_tool", &schema, &params)
+            .unwrap();
+        assert!(verdict.is_blocked());
+    }
+
+    #[test]
+    fn test_session_halt_is_isolated() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+        let schema = test_schema();
+        sentinel.register_tool(&schema).unwrap();
+        let params = serde_json::json!({ "input": "test" });
+
+        // Session A loops on the same tool until the cycle halts it
+        let first = sentinel
+            .analyze_in_session("a", "test_tool", &schema, &params)
+            .unwrap();
+        assert!(!first.is_blocked(), "{:?}", first);
+        sentinel.end_step_in_session("a", "ok").unwrap();
+        let looped = sentinel
+            .analyze_in_session("a", "test_tool", &schema, &params)
+            .unwrap();
+        assert!(matches!(
+            looped,
+            Verdict::Block {
+                reason: BlockReason::CycleDetected { .. }
+            }
+        ));
+        assert!(sentinel.sessions().get("a").unwrap().is_halted());
+
+        // Session B and the default monitor are unaffected
+        let other = sentinel
+            .analyze_in_session("b", "test_tool", &schema, &params)
+            .unwrap();
+        assert!(!other.is_blocked(), "{:?}", other);
+        assert!(!sentinel.sessions().get("b").unwrap().is_halted());
+        assert!(!sentinel.is_halted());
+        assert_eq!(sentinel.step_count(), 0);
+    }
+
+    #[test]
+    fn test_security_max_sessions_enforced() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.monitor.max_sessions = 1;
+        let mut sentinel = Sentinel::new(config).unwrap();
+        let schema = test_schema();
+        sentinel.register_tool(&schema).unwrap();
+        let params = serde_json::json!({ "input": "test" });
+
+        sentinel
+            .analyze_in_session("a", "test_tool", &schema, &params)
+            .unwrap();
+        let refused = sentinel.analyze_in_session("b", "test_tool", &schema, &params);
+        assert!(
+            matches!(refused, Err(SentinelError::StateViolation(_))),
+            "{:?}",
+            refused
+        );
+        assert!(!sentinel.sessions().contains("b"));
+        assert_eq!(sentinel.sessions().len(), 1);
+    }
+
+    #[test]
+    fn test_security_shadow_origin_blocked() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema = test_schema();
+        let params = serde_json::json!({ "input": "test" });
+
+        let mut trusted = Sentinel::new(test_config(&temp_dir))
+            .unwrap()
+            .with_origin("stdio:fs-server");
+        trusted.register_tool(&schema).unwrap();
+        drop(trusted);
+
+        // Same schema, presented by a different server
+        let mut shadow = Sentinel::new(test_config(&temp_dir))
+            .unwrap()
+            .with_origin("https://shadow.example");
+        let verdict = shadow
+            .analyze_tool_call("test_tool", &schema, &params)
+            .unwrap();
+        assert!(
+            matches!(
+                verdict,
+                Verdict::Block {
+                    reason: BlockReason::OriginMismatch { .. }
+                }
+            ),
+            "{:?}",
+            verdict
+        );
+        assert!(shadow.register_tool(&schema).is_err());
+    }
+
+    #[test]
+    fn test_security_shadow_origin_blocked_in_report() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema = test_schema();
+        let params = serde_json::json!({ "input": "test" });
+
+        let mut trusted = Sentinel::new(test_config(&temp_dir))
+            .unwrap()
+            .with_origin("stdio:fs-server");
+        trusted.register_tool(&schema).unwrap();
+        drop(trusted);
+
+        let mut shadow = Sentinel::new(test_config(&temp_dir))
+            .unwrap()
+            .with_origin("https://shadow.example");
+        let report = shadow
+            .analyze_report("test_tool", &schema, &params)
+            .unwrap();
+        assert!(
+            matches!(report.registry, Some(VerifyResult::OriginMismatch { .. })),
+            "{:?}",
+            report.registry
+        );
+        assert!(
+            matches!(
+                report.verdict,
+                Verdict::Block {
+                    reason: BlockReason::OriginMismatch { .. }
+                }
+            ),
+            "{:?}",
+            report.verdict
+        );
+    }
+
+    #[test]
+    fn test_presets_handle_unknown_tools() {
+        let temp_dir = TempDir::new().unwrap();
+        let schema = test_schema();
+        let params = serde_json::json!({ "input": "test" });
+
+        let mut config = SentinelConfig::strict();
+        config.registry.db_path = temp_dir.path().join("strict.db");
+        let mut strict = Sentinel::new(config).unwrap();
+        let verdict = strict
+            .analyze_tool_call("test_tool", &schema, &params)
+            .unwrap();
+        assert!(matches!(
+            verdict,
+            Verdict::Block {
+                reason: BlockReason::UnknownTool { .. }
+            }
+        ));
+
+        let mut config = SentinelConfig::permissive();
+        config.registry.db_path = temp_dir.path().join("permissive.db");
+        let mut permissive = Sentinel::new(config).unwrap();
+        let verdict = permissive
+            .analyze_tool_call("test_tool", &schema, &params)
+            .unwrap();
+        assert!(!verdict.is_blocked(), "{:?}", verdict);
+    }
+
+    #[test]
+    fn test_unregistered_tool_allowed_for_review() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+
+        assert_eq!(
+            verdict,
+            Verdict::review(vec![ReviewFlag::UnregisteredTool {
+                name: "test_tool".to_string()
+            }])
+        );
+
+        // Once registered, the same call is a plain Allow
+        sentinel.register_tool(&test_schema()).unwrap();
+        sentinel.reset_monitor();
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+        assert!(verdict.is_allowed(), "{:?}", verdict);
+    }
+
+    #[test]
+    fn test_review_handler_resolves_review() {
+        let blocked = Verdict::block(BlockReason::SecurityViolation {
+            description: "unregistered tool".to_string(),
+        });
+        let handler_verdict = blocked.clone();
+
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir))
+            .unwrap()
+            .with_review_handler(Box::new(move |flags| {
+                if flags
+                    .iter()
+                    .any(|f| matches!(f, ReviewFlag::UnregisteredTool { .. }))
+                {
+                    handler_verdict.clone()
+                } else {
+                    // Returning Review leaves the verdict as the pipeline made it
+                    Verdict::review(Vec::new())
+                }
+            }));
+
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+        assert_eq!(verdict, blocked);
+
+        // Flags the handler does not act on stay under review
+        let flags = vec![ReviewFlag::HighGasUsage { percentage: 85 }];
+        assert_eq!(
+            sentinel.conclude("test_tool", None, flags.clone()),
+            Verdict::review(flags)
+        );
+    }
+
+    #[test]
+    fn test_review_without_handler_is_unchanged() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+        assert_eq!(
+            verdict,
+            Verdict::review(vec![ReviewFlag::UnregisteredTool {
+                name: "test_tool".to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn test_no_consensus_policy() {
+        for (policy, blocked) in [
+            (NoConsensusPolicy::Review, false),
+            (NoConsensusPolicy::Block, true),
+        ] {
+            let temp_dir = TempDir::new().unwrap();
+            let mut config = test_config(&temp_dir);
+            config.council.on_no_consensus = policy;
+            let mut sentinel = Sentinel::new(config).unwrap();
+            sentinel.register_tool(&test_schema()).unwrap();
+
+            // A council with no evaluators can never reach quorum
+            sentinel.council = CognitiveCouncil::with_components(
+                Vec::new(),
+                ConsensusEngine::new(),
+                WaluigiDetector::new(),
+            );
+
+            let verdict = sentinel
+                .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+                .unwrap();
+            if blocked {
+                assert!(
+                    matches!(
+                        verdict,
+                        Verdict::Block {
+                            reason: BlockReason::NoConsensus { .. }
+                        }
+                    ),
+                    "{:?}",
+                    verdict
+                );
+            } else {
+                assert_eq!(
+                    verdict,
+                    Verdict::review(vec![ReviewFlag::SplitVote {
+                        votes: "0 approve / 0 reject / 0 abstain".to_string()
+                    }])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_observe_mode_keeps_shadow_verdict() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.registry.allow_unknown_tools = false;
+        config.global.mode = EvaluationMode::Observe;
+        let mut sentinel = Sentinel::new(config).unwrap();
+        let params = serde_json::json!({});
+
+        // The unknown tool is let through...
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &params)
+            .unwrap();
+        assert!(verdict.is_allowed(), "{:?}", verdict);
+
+        // ...but the block it would have received is recoverable
+        let report = sentinel
+            .analyze_report("test_tool", &test_schema(), &params)
+            .unwrap();
+        assert!(report.verdict.is_allowed());
+        assert_eq!(report.mode, EvaluationMode::Observe);
+        assert!(matches!(
+            report.shadow_verdict,
+            Some(Verdict::Block {
+                reason: BlockReason::UnknownTool { .. }
+            })
+        ));
+
+        // A genuine allow shadows an Allow
+        sentinel.register_tool(&test_schema()).unwrap();
+        sentinel.reset_monitor();
+        let report = sentinel
+            .analyze_report("test_tool", &test_schema(), &params)
+            .unwrap();
+        assert!(report.verdict.is_allowed());
+        assert_eq!(report.shadow_verdict, Some(Verdict::Allow));
+    }
+
+    #[test]
+    fn test_evaluate_returns_shadow_from_one_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.registry.allow_unknown_tools = false;
+        config.global.mode = EvaluationMode::Observe;
+        let mut sentinel = Sentinel::new(config).unwrap();
+        let params = serde_json::json!({});
+
+        let evaluation = sentinel
+            .evaluate_tool_call("test_tool", &test_schema(), &params, None)
+            .unwrap();
+        assert!(evaluation.verdict.is_allowed());
+        assert_eq!(evaluation.mode, EvaluationMode::Observe);
+        assert!(matches!(
+            evaluation.shadow_verdict,
+            Some(Verdict::Block {
+                reason: BlockReason::UnknownTool { .. }
+            })
+        ));
+
+        // Enforcing, the verdict is returned as reached and nothing is shadowed
+        sentinel.config.global.mode = EvaluationMode::Enforce;
+        let evaluation = sentinel
+            .evaluate_tool_call("test_tool", &test_schema(), &params, None)
+            .unwrap();
+        assert!(evaluation.verdict.is_blocked());
+        assert_eq!(evaluation.shadow_verdict, None);
+    }
+
+    #[test]
+    fn test_council_rejection_carries_reasoning() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let schema = ToolSchema {
+            name: "delete_file".to_string(),
+            ..test_schema()
+        };
+        sentinel.register_tool(&schema).unwrap();
+
+        let params = serde_json::json!({ "path": "/etc/passwd" });
+        let verdict = sentinel
+            .analyze_tool_call("delete_file", &schema, &params)
+            .unwrap();
+
+        match verdict {
+            Verdict::Block {
+                reason: BlockReason::CouncilRejected { votes, reason },
+            } => {
+                assert!(votes.contains("reject"), "votes: {}", votes);
+                assert!(
+                    reason.contains("no_system_modification"),
+                    "reason: {}",
+                    reason
+                );
+            }
+            other => panic!("Expected CouncilRejected, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_disabled_council_is_skipped() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.council.enabled = false;
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let schema = ToolSchema {
+            name: "delete_file".to_string(),
+            ..test_schema()
+        };
+        sentinel.register_tool(&schema).unwrap();
+
+        // Rejected by the council when it runs
+        let params = serde_json::json!({ "path": "/etc/passwd" });
+        let report = sentinel
+            .analyze_report("delete_file", &schema, &params)
+            .unwrap();
+        assert!(report.council.is_none());
+        assert!(report.verdict.is_allowed(), "{:?}", report.verdict);
     }
 
-    /// Get the current gas remaining.
-    pub fn gas_remaining(&self) -> u64 {
-        self.monitor.gas_remaining()
+    #[test]
+    fn test_waluigi_threshold_from_config() {
+        // "jailbroken" alone scores 0.45
+        let params = serde_json::json!({ "note": "I am jailbroken" });
+
+        for (threshold, vetoed) in [(0.5, false), (0.4, true)] {
+            let temp_dir = TempDir::new().unwrap();
+            let mut config = test_config(&temp_dir);
+            config.council.waluigi_threshold = threshold;
+            let mut sentinel = Sentinel::new(config).unwrap();
+            sentinel.register_tool(&test_schema()).unwrap();
+
+            let verdict = sentinel
+                .analyze_tool_call("test_tool", &test_schema(), &params)
+                .unwrap();
+            match verdict {
+                Verdict::Block {
+                    reason: BlockReason::WaluigiEffect { score, patterns },
+                } => {
+                    assert!(vetoed, "vetoed at threshold {}", threshold);
+                    assert!((score - 0.45).abs() < 1e-9);
+                    assert_eq!(patterns, ["jailbreak_acknowledgment"]);
+                }
+                other => assert!(!vetoed, "expected veto, got {:?}", other),
+            }
+        }
     }
 
-    /// Reset the state monitor for a new execution context.
-    pub fn reset_monitor(&mut self) {
-        self.monitor.reset();
+    #[test]
+    fn test_block_audit_event_carries_correlation_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.registry.allow_unknown_tools = false;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let mut sentinel = Sentinel::new(config)
+            .unwrap()
+            .with_audit_hook(Box::new(move |event| {
+                sink.lock().unwrap().push(event.clone())
+            }));
+
+        let params = serde_json::json!({});
+        let verdict = sentinel
+            .analyze_tool_call_with_correlation(
+                "test_tool",
+                &test_schema(),
+                &params,
+                Some("req-42".to_string()),
+            )
+            .unwrap();
+        assert!(verdict.is_blocked());
+
+        sentinel.register_tool(&test_schema()).unwrap();
+        sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &params)
+            .unwrap();
+        sentinel.end_step("ok").unwrap();
+
+        // Only the block is audited
+        {
+            let events = events.lock().unwrap();
+            assert_eq!(events.len(), 1);
+            assert_eq!(events[0].correlation_id, "req-42");
+            assert_eq!(events[0].tool_name, "test_tool");
+            assert_eq!(events[0].verdict, verdict);
+        }
+
+        // Generated ids are UUIDs, returned in the report
+        let report = sentinel
+            .analyze_report("test_tool", &test_schema(), &params)
+            .unwrap();
+        assert!(uuid::Uuid::parse_str(&report.correlation_id).is_ok());
     }
 
-    /// Get the registry's Merkle root hash.
-    pub fn registry_root(&mut self) -> sentinel_registry::Hash {
-        self.registry.get_root()
+    #[test]
+    fn test_analyze_report_dangerous_call() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = test_config(&temp_dir);
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let schema = ToolSchema {
+            name: "delete_file".to_string(),
+            ..test_schema()
+        };
+        sentinel.register_tool(&schema).unwrap();
+
+        let params = serde_json::json!({ "path": "/etc/passwd" });
+        let report = sentinel
+            .analyze_report("delete_file", &schema, &params)
+            .unwrap();
+
+        assert_eq!(report.tool_name, "delete_file");
+        assert_eq!(report.registry, Some(VerifyResult::Valid));
+        assert!(report.drift.is_none());
+        assert_eq!(report.monitor.step_count, 1);
+        assert!(matches!(
+            report.council,
+            Some(CouncilVerdict::Rejected { .. })
+        ));
+        assert!(matches!(
+            report.verdict,
+            Verdict::Block {
+                reason: BlockReason::CouncilRejected { .. }
+            }
+        ));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert!(json["council"].get("Rejected").is_some());
+        assert!(json["verdict"].get("Block").is_some());
     }
 
-    /// Check if monitor is halted due to security violation.
-    pub fn is_halted(&self) -> bool {
-        self.monitor.is_halted()
+    #[test]
+    fn test_analyze_report_runs_all_stages_after_block() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.registry.allow_unknown_tools = false;
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let schema = test_schema();
+        let params = serde_json::json!({ "input": "hello" });
+        let report = sentinel
+            .analyze_report("test_tool", &schema, &params)
+            .unwrap();
+
+        assert_eq!(report.registry, Some(VerifyResult::Unknown));
+        assert_eq!(report.monitor.step_count, 1);
+        assert!(matches!(
+            report.verdict,
+            Verdict::Block {
+                reason: BlockReason::UnknownTool { .. }
+            }
+        ));
     }
 
-    /// Get the step count.
-    pub fn step_count(&self) -> u64 {
-        self.monitor.step_count()
+    /// Plant an undecodable hash for `test_tool` so registry reads fail.
+    fn corrupt_registry(temp_dir: &TempDir) {
+        let db = sled::open(temp_dir.path().join("test_registry.db")).unwrap();
+        let hashes = db.open_tree("hashes").unwrap();
+        hashes.insert("test_tool", &b"not-a-hash"[..]).unwrap();
+        db.flush().unwrap();
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use tempfile::TempDir;
+    /// Storage whose hash reads fail as if the database were unavailable.
+    struct UnavailableStorage;
 
-    fn test_config(temp_dir: &TempDir) -> SentinelConfig {
-        let mut config = SentinelConfig::default();
-        config.registry.db_path = temp_dir.path().join("test_registry.db");
-        config.registry.allow_unknown_tools = true; // For testing
-        config
+    fn unavailable() -> RegistryError {
+        RegistryError::Database(sled::Error::Unsupported("read failed".to_string()))
     }
 
-    fn test_schema() -> ToolSchema {
-        ToolSchema {
-            name: "test_tool".to_string(),
-            description: "A test tool".to_string(),
-            input_schema: serde_json::json!({
-                "type": "object",
-                "properties": {
-                    "input": { "type": "string" }
-                }
-            }),
-            output_schema: serde_json::json!({
-                "type": "string"
-            }),
+    impl Storage for UnavailableStorage {
+        fn store_tool(&self, _: &ToolSchema, _: Hash) -> RegistryResult<()> {
+            Ok(())
+        }
+        fn load_tool(&self, _: &str) -> RegistryResult<Option<(ToolSchema, Hash)>> {
+            Ok(None)
+        }
+        fn load_hash(&self, _: &str) -> RegistryResult<Option<Hash>> {
+            Err(unavailable())
+        }
+        fn store_origin(&self, _: &str, _: &str) -> RegistryResult<()> {
+            Ok(())
+        }
+        fn load_origin(&self, _: &str) -> RegistryResult<Option<String>> {
+            Ok(None)
+        }
+        fn list_tools(&self) -> RegistryResult<Vec<String>> {
+            Ok(Vec::new())
+        }
+        fn remove_tool(&self, _: &str) -> RegistryResult<bool> {
+            Ok(false)
+        }
+        fn load_epoch(&self) -> RegistryResult<u64> {
+            Ok(0)
+        }
+        fn bump_epoch(&self) -> RegistryResult<u64> {
+            Ok(1)
+        }
+        fn load_hash_algorithm(&self) -> RegistryResult<HashAlgorithm> {
+            Ok(HashAlgorithm::default())
+        }
+        fn replace_hashes(
+            &self,
+            _: &[(String, Hash)],
+            _: HashAlgorithm,
+            _: u64,
+        ) -> RegistryResult<HashMigration> {
+            Err(unavailable())
+        }
+        fn load_migrations(&self) -> RegistryResult<Vec<HashMigration>> {
+            Ok(Vec::new())
+        }
+        fn append_audit(&self, _: &AuditEntry) -> RegistryResult<()> {
+            Ok(())
+        }
+        fn store_history(&self, _: &str, _: &[ToolSchema]) -> RegistryResult<()> {
+            Ok(())
+        }
+        fn load_history(&self, _: &str) -> RegistryResult<Vec<ToolSchema>> {
+            Ok(Vec::new())
+        }
+        fn load_audit_log(&self) -> RegistryResult<Vec<AuditEntry>> {
+            Ok(Vec::new())
+        }
+        fn remove_audit(&self, _: &[u64]) -> RegistryResult<()> {
+            Ok(())
+        }
+        fn flush(&self) -> RegistryResult<usize> {
+            Ok(0)
         }
     }
 
+    fn unavailable_sentinel(config: SentinelConfig) -> Sentinel {
+        let mut sentinel = Sentinel::new(config).unwrap();
+        sentinel.registry = RegistryGuard::with_storage(UnavailableStorage).unwrap();
+        sentinel
+    }
+
     #[test]
-    fn test_sentinel_creation() {
+    fn test_infra_error_fails_closed_by_default() {
         let temp_dir = TempDir::new().unwrap();
-        let config = test_config(&temp_dir);
-        let sentinel = Sentinel::new(config);
-        assert!(sentinel.is_ok());
+        let mut sentinel = unavailable_sentinel(test_config(&temp_dir));
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+
+        assert!(matches!(
+            verdict,
+            Verdict::Block {
+                reason: BlockReason::InfrastructureError { .. }
+            }
+        ));
     }
 
     #[test]
-    fn test_register_and_verify() {
+    fn test_health_reports_each_component() {
         let temp_dir = TempDir::new().unwrap();
-        let config = test_config(&temp_dir);
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+        sentinel.register_tool(&test_schema()).unwrap();
+
+        let health = sentinel.health();
+        assert!(health.healthy, "{:?}", health);
+        assert_eq!(health.registry.detail, "1 tools registered");
+
+        // Repeating the same call trips cycle detection and halts the monitor
+        let params = serde_json::json!({ "input": "test" });
+        sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &params)
+            .unwrap();
+        sentinel.end_step("ok").unwrap();
+        sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &params)
+            .unwrap();
+        assert!(sentinel.is_halted());
+        assert!(!sentinel.health().monitor.healthy);
+        sentinel.reset_monitor();
+        assert!(sentinel.health().healthy);
+
+        // Bypass reload validation to simulate a config that went bad
+        sentinel.config.council.waluigi_threshold = 2.0;
+        let health = sentinel.health();
+        assert!(!health.healthy);
+        assert!(health.registry.healthy && health.monitor.healthy);
+        assert!(health.config.detail.contains("waluigi_threshold"));
+    }
+
+    #[test]
+    fn test_health_flags_corrupt_registry() {
+        let temp_dir = TempDir::new().unwrap();
+        corrupt_registry(&temp_dir);
+
+        let sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+        let health = sentinel.health();
+
+        assert!(!health.healthy);
+        assert!(!health.registry.healthy);
+        assert!(health.monitor.healthy && health.config.healthy);
+
+        let json = serde_json::to_value(&health).unwrap();
+        assert_eq!(json["registry"]["healthy"], false);
+    }
+
+    #[test]
+    fn test_infra_error_fail_open_with_review() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.global.on_infra_error = InfraErrorPolicy::FailOpenWithReview;
+        let mut sentinel = unavailable_sentinel(config);
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+
+        match verdict {
+            Verdict::Review { flags } => assert!(flags
+                .iter()
+                .any(|f| matches!(f, ReviewFlag::InfrastructureError { .. }))),
+            other => panic!("expected review, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_security_corrupt_hash_blocks_in_every_infra_mode() {
+        for policy in [
+            InfraErrorPolicy::FailClosed,
+            InfraErrorPolicy::FailOpenWithReview,
+        ] {
+            let temp_dir = TempDir::new().unwrap();
+            corrupt_registry(&temp_dir);
+
+            let mut config = test_config(&temp_dir);
+            config.global.on_infra_error = policy;
+            let mut sentinel = Sentinel::new(config).unwrap();
+            let verdict = sentinel
+                .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+                .unwrap();
+            assert!(
+                matches!(
+                    verdict,
+                    Verdict::Block {
+                        reason: BlockReason::HashMismatch { .. }
+                    }
+                ),
+                "{:?}: {:?}",
+                policy,
+                verdict
+            );
+
+            let report = sentinel
+                .analyze_report("test_tool", &test_schema(), &serde_json::json!({}))
+                .unwrap();
+            assert!(report.verdict.is_blocked(), "{:?}: {:?}", policy, report);
+        }
+    }
+
+    #[test]
+    fn test_classifier_sets_gas_cost() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir))
+            .unwrap()
+            .with_classifier(Box::new(
+                DefaultClassifier::new()
+                    .with_override("test_tool", sentinel_monitor::OperationType::Custom(7)),
+            ));
+        sentinel.register_tool(&test_schema()).unwrap();
+
+        let before = sentinel.gas_remaining();
+        sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+        assert_eq!(before - sentinel.gas_remaining(), 7);
+    }
+
+    #[test]
+    fn test_security_default_gas_ignores_tool_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+        let mut cheap_looking = test_schema();
+        cheap_looking.name = "get_everything".to_string();
+        sentinel.register_tool(&cheap_looking).unwrap();
+
+        // A read-like name does not buy StateRead pricing by default
+        let before = sentinel.gas_remaining();
+        sentinel
+            .analyze_tool_call("get_everything", &cheap_looking, &serde_json::json!({}))
+            .unwrap();
+        assert_eq!(
+            before - sentinel.gas_remaining(),
+            OperationType::ToolCall.cost()
+        );
+    }
+
+    #[test]
+    fn test_analysis_overhead_charged_per_call() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.analysis_gas_overhead = 5;
+        let mut sentinel = Sentinel::new(config).unwrap().with_classifier(Box::new(
+            DefaultClassifier::new()
+                .with_override("test_tool", sentinel_monitor::OperationType::Custom(7)),
+        ));
+        sentinel.register_tool(&test_schema()).unwrap();
+
+        let before = sentinel.gas_remaining();
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+        assert!(!verdict.is_blocked(), "{:?}", verdict);
+        sentinel.end_step("ok").unwrap();
+        assert_eq!(before - sentinel.gas_remaining(), 5 + 7);
+
+        // A fresh context, so the repeat is not a cycle
+        sentinel.reset_monitor();
+        let before = sentinel.gas_remaining();
+        sentinel
+            .analyze_report("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+        assert_eq!(before - sentinel.gas_remaining(), 5 + 7);
+    }
+
+    #[test]
+    fn test_security_analysis_overhead_fails_closed() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.monitor.gas_limit = 20;
+        config.analysis_gas_overhead = 25;
         let mut sentinel = Sentinel::new(config).unwrap();
+        sentinel.register_tool(&test_schema()).unwrap();
+
+        let verdict = sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &serde_json::json!({}))
+            .unwrap();
+        assert!(matches!(
+            verdict,
+            Verdict::Block {
+                reason: BlockReason::GasExhausted { limit: 20, .. }
+            }
+        ));
+        assert_eq!(sentinel.gas_remaining(), 20, "nothing charged");
+        assert_eq!(sentinel.step_count(), 0, "no step begun");
+    }
 
+    #[test]
+    fn test_reload_config_applies_new_gas_limit() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
         let schema = test_schema();
         sentinel.register_tool(&schema).unwrap();
 
-        // Verify passes for registered tool
-        let params = serde_json::json!({ "input": "test" });
-        let verdict = sentinel
+        let mut config = test_config(&temp_dir);
+        config.monitor.gas_limit = 15; // Room for one tool call (10 gas)
+        sentinel.reload_config(config).unwrap();
+        assert_eq!(sentinel.gas_remaining(), 15);
+
+        let params = serde_json::json!({ "input": "hello" });
+        let first = sentinel
             .analyze_tool_call("test_tool", &schema, &params)
             .unwrap();
+        assert!(
+            !first.is_blocked(),
+            "registered tool still verifies: {:?}",
+            first
+        );
+        sentinel.end_step("done").unwrap();
 
-        // Should allow (or review for new tool depending on config)
-        assert!(!verdict.is_blocked());
+        let second = sentinel
+            .analyze_tool_call("test_tool", &schema, &params)
+            .unwrap();
+        assert!(matches!(
+            second,
+            Verdict::Block {
+                reason: BlockReason::GasExhausted { .. }
+            }
+        ));
     }
 
     #[test]
-    fn test_unknown_tool_blocked() {
+    fn test_security_reload_keeps_monitor_state() {
         let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+        sentinel.register_tool(&test_schema()).unwrap();
+
+        let params = serde_json::json!({ "input": "test" });
+        sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &params)
+            .unwrap();
+        sentinel.end_step("ok").unwrap();
+        let spent = 10_000 - sentinel.gas_remaining();
+        sentinel
+            .analyze_tool_call("test_tool", &test_schema(), &params)
+            .unwrap();
+        assert!(sentinel.is_halted());
+        let steps = sentinel.step_count();
+
         let mut config = test_config(&temp_dir);
-        config.registry.allow_unknown_tools = false;
+        config.monitor.gas_limit = 20_000;
+        sentinel.reload_config(config).unwrap();
+
+        assert!(sentinel.is_halted(), "reload must not un-halt");
+        assert_eq!(sentinel.step_count(), steps);
+        assert!(sentinel.gas_remaining() <= 20_000 - spent);
+        let result = sentinel.analyze_tool_call("test_tool", &test_schema(), &params);
+        assert!(!matches!(result, Ok(Verdict::Allow)), "{:?}", result);
+    }
+
+    #[test]
+    fn test_reload_config_rejects_registry_move() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+
+        let mut config = test_config(&temp_dir);
+        config.registry.db_path = temp_dir.path().join("elsewhere.db");
+        config.monitor.gas_limit = 1;
+
+        let err = sentinel.reload_config(config).unwrap_err();
+        assert!(matches!(err, SentinelError::Config(_)));
+        assert_eq!(sentinel.gas_remaining(), 10_000, "old config kept");
+    }
+
+    #[test]
+    fn test_verify_only_consumes_no_gas() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut sentinel = Sentinel::new(test_config(&temp_dir)).unwrap();
+        let schema = test_schema();
+        sentinel.register_tool(&schema).unwrap();
 
+        let gas_before = sentinel.gas_remaining();
+        assert!(matches!(
+            sentinel.verify_only("test_tool", &schema),
+            VerifyResult::Valid
+        ));
+        assert_eq!(sentinel.gas_remaining(), gas_before);
+        assert_eq!(sentinel.step_count(), 0);
+
+        let mut changed = schema.clone();
+        changed.description = "Changed".to_string();
+        assert!(matches!(
+            sentinel.verify_only("test_tool", &changed),
+            VerifyResult::Invalid { .. }
+        ));
+        assert!(matches!(
+            sentinel.verify_only("other_tool", &schema),
+            VerifyResult::Unknown
+        ));
+    }
+
+    #[test]
+    fn test_denylisted_tool_blocked_before_any_stage() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.tool_denylist.insert("test_tool".to_string());
         let mut sentinel = Sentinel::new(config).unwrap();
 
         let schema = test_schema();
-        let params = serde_json::json!({});
+        sentinel.register_tool(&schema).unwrap();
+        let gas_before = sentinel.gas_remaining();
 
         let verdict = sentinel
-            .analyze_tool_call("unknown_tool", &schema, &params)
+            .analyze_tool_call(&schema.name, &schema, &serde_json::json!({}))
             .unwrap();
-        assert!(verdict.is_blocked());
+
+        assert_eq!(
+            verdict,
+            Verdict::block(BlockReason::DeniedTool {
+                tool_name: "test_tool".to_string()
+            })
+        );
+        assert_eq!(sentinel.gas_remaining(), gas_before, "no gas consumed");
+    }
+
+    #[test]
+    fn test_high_gas_usage_flagged_once() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut config = test_config(&temp_dir);
+        config.monitor.gas_limit = 50; // Five tool calls; warn at the fourth
+        let mut sentinel = Sentinel::new(config).unwrap();
+
+        let params = serde_json::json!({ "input": "hello" });
+        let mut flagged = Vec::new();
+        for i in 0..5 {
+            let mut schema = test_schema();
+            schema.name = format!("tool_{}", i);
+            sentinel.register_tool(&schema).unwrap();
+
+            let verdict = sentinel
+                .analyze_tool_call(&schema.name, &schema, &params)
+                .unwrap();
+            if let Verdict::Review { flags } = &verdict {
+                if flags
+                    .iter()
+                    .any(|f| matches!(f, ReviewFlag::HighGasUsage { percentage: 80 }))
+                {
+                    flagged.push(i);
+                }
+            }
+            sentinel.end_step("done").unwrap();
+        }
+
+        assert_eq!(flagged, vec![3]);
     }
 
     #[test]
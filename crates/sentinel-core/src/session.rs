@@ -0,0 +1,383 @@
+//! Per-session execution state.
+//!
+//! Each MCP session gets its own [`StateMonitor`], so a cycle or gas
+//! exhaustion that halts one session never blocks another. The
+//! [`SessionManager`] creates monitors on first use and evicts sessions
+//! that have been idle longer than a configurable TTL, unless they are
+//! halted.
+//!
+//! ## Load Shedding
+//!
+//! A gateway should refuse a new session under pressure rather than accept
+//! it and halt it moments later. [`SessionManager::monitor_mut`] refuses
+//! to create a session past two optional limits, which
+//! [`SessionManager::can_accept_session`] checks ahead of time:
+//!
+//! - **Max sessions** - live (not idle-expired) sessions must stay below it
+//! - **Gas pool** - the gas live, unhalted sessions may still spend, plus a
+//!   fresh session's full budget, must fit in the pool
+//!
+//! Host memory is not measured; the gas pool is the proxy for how much
+//! work the host has committed to.
+//!
+//! ## Security Notes
+//!
+//! - Session ids are opaque; callers must derive them from the transport
+//!   (connection, auth token), never from message content
+//! - Evicting an idle session discards its history, so a returning
+//!   session starts with a fresh gas budget
+//! - Halted sessions are kept until [`SessionManager::remove`]: evicting
+//!   one would let an agent wait out the TTL and come back un-halted
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use sentinel_clock::{SharedClock, SystemClock};
+use sentinel_monitor::{StateMonitor, StateMonitorConfig};
+use tracing::debug;
+
+use crate::{error::SentinelError, Result};
+
+/// A session's monitor and when it was last used.
+#[derive(Debug)]
+struct Session {
+    /// Execution state for this session.
+    monitor: StateMonitor,
+    /// Last time the session was analyzed.
+    last_active: Instant,
+}
+
+/// Maps session ids to independent State Monitors.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_core::{SessionManager, StateMonitorConfig};
+/// use std::time::Duration;
+///
+/// let mut sessions = SessionManager::new(StateMonitorConfig::new())
+///     .with_idle_ttl(Duration::from_secs(900));
+///
+/// sessions.monitor_mut("client-a")?;
+/// sessions.monitor_mut("client-b")?;
+/// assert_eq!(sessions.len(), 2);
+/// # Ok::<(), sentinel_core::SentinelError>(())
+/// ```
+#[derive(Debug)]
+pub struct SessionManager {
+    /// Live sessions by id.
+    sessions: HashMap<String, Session>,
+    /// Configuration for newly created monitors.
+    monitor_config: StateMonitorConfig,
+    /// Sessions idle longer than this are evicted, if set.
+    idle_ttl: Option<Duration>,
+    /// Most live sessions accepted at once, if set.
+    max_sessions: Option<usize>,
+    /// Total gas live sessions may hold unspent, if set.
+    gas_pool: Option<u64>,
+    /// Time source for idle tracking.
+    clock: SharedClock,
+}
+
+impl SessionManager {
+    /// Creates an empty manager whose sessions use `monitor_config`.
+    pub fn new(monitor_config: StateMonitorConfig) -> Self {
+        Self {
+            sessions: HashMap::new(),
+            monitor_config,
+            idle_ttl: None,
+            max_sessions: None,
+            gas_pool: None,
+            clock: SystemClock::shared(),
+        }
+    }
+
+    /// Evicts sessions idle for longer than `ttl`.
+    pub fn with_idle_ttl(mut self, ttl: Duration) -> Self {
+        self.idle_ttl = Some(ttl);
+        self
+    }
+
+    /// Refuses new sessions once `max` live sessions exist.
+    pub fn with_max_sessions(mut self, max: usize) -> Self {
+        self.max_sessions = Some(max);
+        self
+    }
+
+    /// Refuses new sessions whose budget would overcommit a `pool` of gas.
+    pub fn with_gas_pool(mut self, pool: u64) -> Self {
+        self.gas_pool = Some(pool);
+        self
+    }
+
+    /// Replaces the time source used for idle tracking.
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        let now = clock.now();
+        for session in self.sessions.values_mut() {
+            session.last_active = now;
+        }
+        self.clock = clock;
+        self
+    }
+
+    /// Sets the idle TTL (`None` keeps sessions until removed).
+    pub fn set_idle_ttl(&mut self, ttl: Option<Duration>) {
+        self.idle_ttl = ttl;
+    }
+
+    /// Sets the live session limit (`None` for no limit).
+    pub fn set_max_sessions(&mut self, max: Option<usize>) {
+        self.max_sessions = max;
+    }
+
+    /// Sets the gas pool (`None` for no limit).
+    pub fn set_gas_pool(&mut self, pool: Option<u64>) {
+        self.gas_pool = pool;
+    }
+
+    /// Sets the configuration for sessions created from now on.
+    ///
+    /// Existing sessions keep their monitors.
+    pub fn set_monitor_config(&mut self, config: StateMonitorConfig) {
+        self.monitor_config = config;
+    }
+
+    /// Returns the session's monitor, creating it on first use.
+    ///
+    /// Marks the session as active.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SentinelError::StateViolation`] if the session is new and
+    /// [`can_accept_session`](Self::can_accept_session) refuses it.
+    /// Existing sessions are always returned.
+    pub fn monitor_mut(&mut self, session_id: &str) -> Result<&mut StateMonitor> {
+        if !self.sessions.contains_key(session_id) && !self.can_accept_session() {
+            return Err(SentinelError::StateViolation(
+                "session limit reached".to_string(),
+            ));
+        }
+
+        let now = self.clock.now();
+        let session = self
+            .sessions
+            .entry(session_id.to_string())
+            .or_insert_with(|| {
+                debug!("Creating session: {}", session_id);
+                Session {
+                    monitor: StateMonitor::with_config(self.monitor_config.clone()),
+                    last_active: now,
+                }
+            });
+        session.last_active = now;
+        Ok(&mut session.monitor)
+    }
+
+    /// Returns the session's monitor without creating or touching it.
+    pub fn get(&self, session_id: &str) -> Option<&StateMonitor> {
+        self.sessions.get(session_id).map(|s| &s.monitor)
+    }
+
+    /// Returns true if the session exists.
+    pub fn contains(&self, session_id: &str) -> bool {
+        self.sessions.contains_key(session_id)
+    }
+
+    /// Ends a session, returning its monitor.
+    pub fn remove(&mut self, session_id: &str) -> Option<StateMonitor> {
+        self.sessions.remove(session_id).map(|s| s.monitor)
+    }
+
+    /// Evicts every session idle for longer than the TTL.
+    ///
+    /// Halted sessions are kept, so that coming back after the TTL does
+    /// not buy a halted session a fresh budget.
+    ///
+    /// # Returns
+    ///
+    /// The number of sessions evicted (always 0 without a TTL).
+    pub fn evict_idle(&mut self) -> usize {
+        let Some(ttl) = self.idle_ttl else {
+            return 0;
+        };
+
+        let now = self.clock.now();
+        let before = self.sessions.len();
+        self.sessions.retain(|id, session| {
+            let keep = session.monitor.is_halted()
+                || now.saturating_duration_since(session.last_active) <= ttl;
+            if !keep {
+                debug!("Evicting idle session: {}", id);
+            }
+            keep
+        });
+        before - self.sessions.len()
+    }
+
+    /// Returns true if a new session fits within the configured limits.
+    ///
+    /// Sessions idle past the TTL are not counted, even before
+    /// [`evict_idle`](Self::evict_idle) removes them. Always true when no
+    /// limits are set. Existing sessions are never refused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_core::{SessionManager, StateMonitorConfig};
+    ///
+    /// let mut sessions = SessionManager::new(StateMonitorConfig::new()).with_max_sessions(1);
+    /// assert!(sessions.can_accept_session());
+    ///
+    /// sessions.monitor_mut("client-a")?;
+    /// assert!(!sessions.can_accept_session());
+    /// assert!(sessions.monitor_mut("client-b").is_err());
+    /// # Ok::<(), sentinel_core::SentinelError>(())
+    /// ```
+    pub fn can_accept_session(&self) -> bool {
+        let live: Vec<&Session> = self.live_sessions().collect();
+
+        if self.max_sessions.is_some_and(|max| live.len() >= max) {
+            debug!("Refusing session: {} live sessions", live.len());
+            return false;
+        }
+        if let Some(pool) = self.gas_pool {
+            let held: u64 = live
+                .iter()
+                .filter(|s| !s.monitor.is_halted())
+                .map(|s| s.monitor.gas_remaining())
+                .sum();
+            if held.saturating_add(self.monitor_config.gas_budget) > pool {
+                debug!("Refusing session: {} of {} gas held", held, pool);
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Sessions not idle past the TTL.
+    fn live_sessions(&self) -> impl Iterator<Item = &Session> {
+        let now = self.clock.now();
+        self.sessions.values().filter(move |session| {
+            self.idle_ttl
+                .is_none_or(|ttl| now.saturating_duration_since(session.last_active) <= ttl)
+        })
+    }
+
+    /// Returns the number of live sessions.
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    /// Returns true if there are no live sessions.
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sentinel_clock::MockClock;
+    use sentinel_monitor::OperationType;
+
+    #[test]
+    fn test_sessions_are_independent() {
+        let mut sessions = SessionManager::new(StateMonitorConfig::new());
+        sessions
+            .monitor_mut("a")
+            .unwrap()
+            .begin_step("s1", OperationType::ToolCall)
+            .unwrap();
+
+        assert_eq!(sessions.get("a").unwrap().step_count(), 1);
+        assert_eq!(sessions.monitor_mut("b").unwrap().step_count(), 0);
+    }
+
+    #[test]
+    fn test_idle_sessions_evicted() {
+        let clock = MockClock::new();
+        let mut sessions = SessionManager::new(StateMonitorConfig::new())
+            .with_clock(clock.shared())
+            .with_idle_ttl(Duration::from_secs(60));
+
+        sessions.monitor_mut("idle").unwrap();
+        clock.advance(Duration::from_secs(45));
+        sessions.monitor_mut("active").unwrap();
+        clock.advance(Duration::from_secs(30));
+
+        assert_eq!(sessions.evict_idle(), 1);
+        assert!(!sessions.contains("idle"));
+        assert!(sessions.contains("active"));
+    }
+
+    #[test]
+    fn test_security_halted_sessions_not_evicted() {
+        let clock = MockClock::new();
+        let mut sessions = SessionManager::new(StateMonitorConfig::new())
+            .with_clock(clock.shared())
+            .with_idle_ttl(Duration::from_secs(60));
+
+        let monitor = sessions.monitor_mut("looping").unwrap();
+        monitor.begin_step("s", OperationType::ToolCall).unwrap();
+        monitor.end_step("ok").unwrap();
+        assert!(monitor.begin_step("s", OperationType::ToolCall).is_err());
+        assert!(monitor.is_halted());
+
+        clock.advance(Duration::from_secs(3600));
+        assert_eq!(sessions.evict_idle(), 0);
+        assert!(sessions.monitor_mut("looping").unwrap().is_halted());
+    }
+
+    #[test]
+    fn test_max_sessions_refuses_new_sessions() {
+        let clock = MockClock::new();
+        let mut sessions = SessionManager::new(StateMonitorConfig::new())
+            .with_clock(clock.shared())
+            .with_idle_ttl(Duration::from_secs(60))
+            .with_max_sessions(2);
+
+        sessions.monitor_mut("a").unwrap();
+        assert!(sessions.can_accept_session());
+        sessions.monitor_mut("b").unwrap();
+        assert!(!sessions.can_accept_session());
+        assert!(matches!(
+            sessions.monitor_mut("c"),
+            Err(SentinelError::StateViolation(_))
+        ));
+        assert!(
+            sessions.monitor_mut("a").is_ok(),
+            "existing sessions are kept"
+        );
+
+        // An idle-expired session no longer counts, even before eviction
+        clock.advance(Duration::from_secs(90));
+        sessions.monitor_mut("b").unwrap();
+        assert!(sessions.can_accept_session());
+
+        sessions.set_max_sessions(None);
+        sessions.monitor_mut("c").unwrap();
+        assert!(sessions.can_accept_session());
+    }
+
+    #[test]
+    fn test_gas_pool_refuses_overcommit() {
+        let config = StateMonitorConfig {
+            gas_budget: 100,
+            ..StateMonitorConfig::new()
+        };
+        let mut sessions = SessionManager::new(config).with_gas_pool(250);
+
+        sessions.monitor_mut("a").unwrap();
+        sessions.monitor_mut("b").unwrap();
+        assert!(!sessions.can_accept_session());
+
+        // Spending gas frees headroom
+        let monitor = sessions.monitor_mut("a").unwrap();
+        monitor
+            .begin_step("s1", OperationType::LlmInference)
+            .unwrap();
+        monitor.end_step("ok").unwrap();
+        assert!(sessions.get("a").unwrap().gas_remaining() <= 50);
+        assert!(sessions.can_accept_session());
+    }
+}
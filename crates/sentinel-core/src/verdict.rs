@@ -2,6 +2,15 @@
 
 use serde::{Deserialize, Serialize};
 
+/// JSON-RPC error code for blocked requests (Invalid Request).
+pub const BLOCKED_ERROR_CODE: i64 = -32600;
+
+/// JSON-RPC error message for blocked requests.
+pub const BLOCKED_ERROR_MESSAGE: &str = "Blocked by security";
+
+/// HTTP header gateways set on responses that were allowed pending review.
+pub const REVIEW_HEADER: &str = "X-Sentinel-Review";
+
 /// The final verdict from the Sentinel analysis pipeline.
 ///
 /// The Sentinel returns one of three verdicts after analyzing an MCP message:
@@ -56,6 +65,149 @@ impl Verdict {
     pub fn requires_review(&self) -> bool {
         matches!(self, Self::Review { .. })
     }
+
+    /// Combines two verdicts from independent analyzers.
+    ///
+    /// Verdicts form a lattice ordered `Allow < Review < Block`; the result
+    /// is the stricter of the two, with details merged:
+    ///
+    /// | `self`   | `other`  | Result |
+    /// |----------|----------|--------|
+    /// | `Allow`  | `Allow`  | `Allow` |
+    /// | `Allow`  | `Review` | `other` |
+    /// | `Review` | `Review` | `Review` with the union of both flag lists |
+    /// | any      | `Block`  | `Block` (accumulating reasons if both block) |
+    ///
+    /// The table is symmetric. When both verdicts block, the result carries
+    /// [`BlockReason::Multiple`] listing every reason, `self`'s first. A
+    /// `Review` merged into a `Block` contributes nothing: the request is
+    /// not executed, so there is nothing left to review. Flag unions keep
+    /// the first occurrence of each flag, `self`'s first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_core::{BlockReason, ReviewFlag, Verdict};
+    ///
+    /// let firewall = Verdict::review(vec![ReviewFlag::HighGasUsage { percentage: 85 }]);
+    /// let tool_call = Verdict::block(BlockReason::UnknownTool {
+    ///     tool_name: "rm".to_string(),
+    /// });
+    ///
+    /// assert!(firewall.merge(tool_call).is_blocked());
+    /// ```
+    pub fn merge(self, other: Verdict) -> Verdict {
+        match (self, other) {
+            (Self::Block { reason: a }, Self::Block { reason: b }) => {
+                let mut reasons = a.into_reasons();
+                reasons.extend(b.into_reasons());
+                Self::block(BlockReason::Multiple { reasons })
+            }
+            (block @ Self::Block { .. }, _) | (_, block @ Self::Block { .. }) => block,
+            (Self::Review { mut flags }, Self::Review { flags: other }) => {
+                for flag in other {
+                    if !flags.contains(&flag) {
+                        flags.push(flag);
+                    }
+                }
+                Self::review(flags)
+            }
+            (review @ Self::Review { .. }, Self::Allow)
+            | (Self::Allow, review @ Self::Review { .. }) => review,
+            (Self::Allow, Self::Allow) => Self::Allow,
+        }
+    }
+
+    /// Maps the verdict to an HTTP status code for gateway responses.
+    ///
+    /// | Verdict  | Status |
+    /// |----------|--------|
+    /// | `Allow`  | 200    |
+    /// | `Review` | 200, with [`REVIEW_HEADER`] set |
+    /// | `Block`  | 403    |
+    pub fn http_status(&self) -> u16 {
+        match self {
+            Self::Allow | Self::Review { .. } => 200,
+            Self::Block { .. } => 403,
+        }
+    }
+
+    /// Builds the JSON-RPC error object for a blocked request.
+    ///
+    /// Returns `None` for `Allow` and `Review`, which are forwarded. A
+    /// `Block` yields [`BLOCKED_ERROR_CODE`] with the block reason as data.
+    pub fn jsonrpc_error(&self) -> Option<JsonRpcError> {
+        match self {
+            Self::Block { reason } => Some(JsonRpcError {
+                code: BLOCKED_ERROR_CODE,
+                message: BLOCKED_ERROR_MESSAGE.to_string(),
+                data: Some(reason.to_string()),
+            }),
+            Self::Allow | Self::Review { .. } => None,
+        }
+    }
+
+    /// Renders the verdict as a plain-English sentence for end users.
+    ///
+    /// Unlike the `Display` and serialized forms, which are meant for logs
+    /// and machines, the explanation leaves out hashes and internal error
+    /// text and says what happened in terms a user can act on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_core::{BlockReason, Verdict};
+    ///
+    /// let verdict = Verdict::block(BlockReason::HashMismatch {
+    ///     tool_name: "helper".to_string(),
+    ///     expected: "ab12".to_string(),
+    ///     actual: "cd34".to_string(),
+    /// });
+    /// assert_eq!(
+    ///     verdict.explain(),
+    ///     "Blocked: the tool 'helper' changed since it was registered (possible rug pull)."
+    /// );
+    /// ```
+    pub fn explain(&self) -> String {
+        match self {
+            Self::Allow => "Allowed: the request passed every security check.".to_string(),
+            Self::Block {
+                reason: BlockReason::Multiple { reasons },
+            } => format!(
+                "Blocked for {} reasons: {}.",
+                reasons.len(),
+                reasons
+                    .iter()
+                    .map(BlockReason::explain)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+            Self::Block { reason } => format!("Blocked: {}.", reason.explain()),
+            Self::Review { flags } if flags.is_empty() => "Needs review.".to_string(),
+            Self::Review { flags } => format!(
+                "Needs review: {}.",
+                flags
+                    .iter()
+                    .map(ReviewFlag::explain)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ),
+        }
+    }
+}
+
+/// A JSON-RPC 2.0 error object.
+///
+/// Serializes to the `error` member of a JSON-RPC response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonRpcError {
+    /// Error code.
+    pub code: i64,
+    /// Short error description.
+    pub message: String,
+    /// Additional detail, omitted when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
 }
 
 /// Reasons for blocking a message.
@@ -79,6 +231,17 @@ pub enum BlockReason {
         actual: String,
     },
 
+    /// Tool presented by a different origin than it was registered from
+    /// (possible shadow server).
+    OriginMismatch {
+        /// Name of the tool.
+        tool_name: String,
+        /// Registered origin.
+        expected: String,
+        /// Presenting origin.
+        actual: String,
+    },
+
     /// Cycle detected in execution graph.
     CycleDetected {
         /// Description of the cycle.
@@ -103,9 +266,17 @@ pub enum BlockReason {
 
     /// Cognitive Council rejected the action.
     CouncilRejected {
-        /// Vote tally.
+        /// Vote tally summary (e.g. `1 approve / 2 reject / 0 abstain`).
         votes: String,
-        /// Rejection reason.
+        /// Reasoning from the rejecting evaluators.
+        reason: String,
+    },
+
+    /// Cognitive Council reached no consensus and policy blocks it.
+    NoConsensus {
+        /// Vote tally summary (e.g. `0 approve / 1 reject / 3 abstain`).
+        votes: String,
+        /// Why no consensus was reached.
         reason: String,
     },
 
@@ -123,11 +294,132 @@ pub enum BlockReason {
         tool_name: String,
     },
 
+    /// Tool name is on the configured denylist.
+    DeniedTool {
+        /// Name of the denied tool.
+        tool_name: String,
+    },
+
     /// Generic security violation.
     SecurityViolation {
         /// Description of the violation.
         description: String,
     },
+
+    /// A component failed for non-security reasons (fail-closed).
+    InfrastructureError {
+        /// Component that failed.
+        component: String,
+        /// Error description.
+        error: String,
+    },
+
+    /// Several independent checks blocked the message.
+    ///
+    /// Produced by [`Verdict::merge`]; never nested.
+    Multiple {
+        /// Every blocking reason, in merge order.
+        reasons: Vec<BlockReason>,
+    },
+}
+
+impl BlockReason {
+    /// Returns which kind of reason this is, without its details.
+    pub fn kind(&self) -> BlockReasonKind {
+        match self {
+            Self::SchemaDrift { .. } => BlockReasonKind::SchemaDrift,
+            Self::HashMismatch { .. } => BlockReasonKind::HashMismatch,
+            Self::OriginMismatch { .. } => BlockReasonKind::OriginMismatch,
+            Self::CycleDetected { .. } => BlockReasonKind::CycleDetected,
+            Self::GasExhausted { .. } => BlockReasonKind::GasExhausted,
+            Self::ContextOverflow { .. } => BlockReasonKind::ContextOverflow,
+            Self::CouncilRejected { .. } => BlockReasonKind::CouncilRejected,
+            Self::NoConsensus { .. } => BlockReasonKind::NoConsensus,
+            Self::WaluigiEffect { .. } => BlockReasonKind::WaluigiEffect,
+            Self::UnknownTool { .. } => BlockReasonKind::UnknownTool,
+            Self::DeniedTool { .. } => BlockReasonKind::DeniedTool,
+            Self::SecurityViolation { .. } => BlockReasonKind::SecurityViolation,
+            Self::InfrastructureError { .. } => BlockReasonKind::InfrastructureError,
+            Self::Multiple { .. } => BlockReasonKind::Multiple,
+        }
+    }
+
+    /// Describes the reason as a lowercase clause for end users.
+    ///
+    /// Used by [`Verdict::explain`]; a `Multiple` reason joins the clauses
+    /// of its reasons with `"; "`.
+    pub fn explain(&self) -> String {
+        match self {
+            Self::SchemaDrift {
+                tool_name,
+                drift_level,
+            } => format!(
+                "the tool '{}' no longer matches the schema it was registered with ({} drift)",
+                tool_name,
+                drift_level.to_lowercase()
+            ),
+            Self::HashMismatch { tool_name, .. } => format!(
+                "the tool '{}' changed since it was registered (possible rug pull)",
+                tool_name
+            ),
+            Self::OriginMismatch {
+                tool_name,
+                expected,
+                actual,
+            } => format!(
+                "the tool '{}' was offered by {} but registered from {} (possible shadow server)",
+                tool_name, actual, expected
+            ),
+            Self::CycleDetected { .. } => {
+                "the agent is repeating the same steps in a loop".to_string()
+            }
+            Self::GasExhausted { used, limit } => format!(
+                "the session used up its execution budget ({} of {} gas)",
+                used, limit
+            ),
+            Self::ContextOverflow { size, max } => format!(
+                "the conversation is larger than allowed ({} of at most {})",
+                size, max
+            ),
+            Self::CouncilRejected { votes, .. } => {
+                format!("the safety review voted against this action ({})", votes)
+            }
+            Self::NoConsensus { votes, .. } => format!(
+                "the safety review could not agree on this action ({})",
+                votes
+            ),
+            Self::WaluigiEffect { .. } => {
+                "the request looks like an attempt to turn the assistant against its instructions"
+                    .to_string()
+            }
+            Self::UnknownTool { tool_name } => {
+                format!("the tool '{}' is not registered", tool_name)
+            }
+            Self::DeniedTool { tool_name } => {
+                format!("the tool '{}' is not allowed by policy", tool_name)
+            }
+            Self::SecurityViolation { description } => {
+                format!("a security rule was violated ({})", description)
+            }
+            Self::InfrastructureError { component, .. } => format!(
+                "the {} check failed to run, so the request was stopped to be safe",
+                component
+            ),
+            Self::Multiple { reasons } => reasons
+                .iter()
+                .map(BlockReason::explain)
+                .collect::<Vec<_>>()
+                .join("; "),
+        }
+    }
+
+    /// Flattens the reason into its individual reasons.
+    fn into_reasons(self) -> Vec<BlockReason> {
+        match self {
+            Self::Multiple { reasons } => reasons,
+            reason => vec![reason],
+        }
+    }
 }
 
 impl std::fmt::Display for BlockReason {
@@ -150,6 +442,17 @@ impl std::fmt::Display for BlockReason {
                     tool_name, expected, actual
                 )
             }
+            Self::OriginMismatch {
+                tool_name,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Origin mismatch on '{}': registered from {}, presented by {}",
+                    tool_name, expected, actual
+                )
+            }
             Self::CycleDetected { cycle } => {
                 write!(f, "Cycle detected: {}", cycle)
             }
@@ -162,19 +465,73 @@ impl std::fmt::Display for BlockReason {
             Self::CouncilRejected { votes, reason } => {
                 write!(f, "Council rejected ({}): {}", votes, reason)
             }
+            Self::NoConsensus { votes, reason } => {
+                write!(f, "No council consensus ({}): {}", votes, reason)
+            }
             Self::WaluigiEffect { score, patterns } => {
                 write!(f, "Waluigi effect (score: {:.2}): {:?}", score, patterns)
             }
             Self::UnknownTool { tool_name } => {
                 write!(f, "Unknown tool: '{}'", tool_name)
             }
+            Self::DeniedTool { tool_name } => {
+                write!(f, "Denied tool: '{}'", tool_name)
+            }
             Self::SecurityViolation { description } => {
                 write!(f, "Security violation: {}", description)
             }
+            Self::InfrastructureError { component, error } => {
+                write!(f, "Infrastructure error in {}: {}", component, error)
+            }
+            Self::Multiple { reasons } => {
+                for (i, reason) in reasons.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str("; ")?;
+                    }
+                    write!(f, "{}", reason)?;
+                }
+                Ok(())
+            }
         }
     }
 }
 
+/// The variant of a [`BlockReason`], without its details.
+///
+/// Returned by [`BlockReason::kind`] and
+/// [`SentinelConfig::reachable_block_reasons`](crate::SentinelConfig::reachable_block_reasons).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BlockReasonKind {
+    /// [`BlockReason::SchemaDrift`].
+    SchemaDrift,
+    /// [`BlockReason::HashMismatch`].
+    HashMismatch,
+    /// [`BlockReason::OriginMismatch`].
+    OriginMismatch,
+    /// [`BlockReason::CycleDetected`].
+    CycleDetected,
+    /// [`BlockReason::GasExhausted`].
+    GasExhausted,
+    /// [`BlockReason::ContextOverflow`].
+    ContextOverflow,
+    /// [`BlockReason::CouncilRejected`].
+    CouncilRejected,
+    /// [`BlockReason::NoConsensus`].
+    NoConsensus,
+    /// [`BlockReason::WaluigiEffect`].
+    WaluigiEffect,
+    /// [`BlockReason::UnknownTool`].
+    UnknownTool,
+    /// [`BlockReason::DeniedTool`].
+    DeniedTool,
+    /// [`BlockReason::SecurityViolation`].
+    SecurityViolation,
+    /// [`BlockReason::InfrastructureError`].
+    InfrastructureError,
+    /// [`BlockReason::Multiple`].
+    Multiple,
+}
+
 /// Flags indicating why human review is needed.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ReviewFlag {
@@ -196,10 +553,16 @@ pub enum ReviewFlag {
         percentage: u8,
     },
 
-    /// First time seeing this tool.
-    NewTool {
+    /// Tool is not in the registry but was allowed by `allow_unknown_tools`.
+    ///
+    /// Lets downstream log or alert on unverified tools that were let through.
+    /// Replaces `NewTool { tool_name }`; serialized flags under the old
+    /// names still deserialize into this variant.
+    #[serde(alias = "NewTool")]
+    UnregisteredTool {
         /// Tool name.
-        tool_name: String,
+        #[serde(alias = "tool_name")]
+        name: String,
     },
 
     /// Borderline Waluigi score.
@@ -207,6 +570,53 @@ pub enum ReviewFlag {
         /// Score value.
         score: f64,
     },
+
+    /// A component failed for non-security reasons (fail-open).
+    InfrastructureError {
+        /// Component that failed.
+        component: String,
+        /// Error description.
+        error: String,
+    },
+}
+
+impl ReviewFlag {
+    /// Builds the flag formerly known as `NewTool`.
+    #[deprecated(note = "`ReviewFlag::NewTool` was renamed to `ReviewFlag::UnregisteredTool`")]
+    pub fn new_tool(tool_name: impl Into<String>) -> Self {
+        Self::UnregisteredTool {
+            name: tool_name.into(),
+        }
+    }
+
+    /// Describes the flag as a lowercase clause for end users.
+    ///
+    /// Used by [`Verdict::explain`].
+    pub fn explain(&self) -> String {
+        match self {
+            Self::MinorDrift { tool_name } => format!(
+                "the tool '{}' changed slightly since it was registered",
+                tool_name
+            ),
+            Self::SplitVote { votes } => format!("the safety review was split ({})", votes),
+            Self::HighGasUsage { percentage } => format!(
+                "the session has used {}% of its execution budget",
+                percentage
+            ),
+            Self::UnregisteredTool { name } => format!(
+                "the tool '{}' is not registered but unregistered tools are allowed",
+                name
+            ),
+            Self::BorderlineWaluigi { .. } => {
+                "the request shows some signs of turning the assistant against its instructions"
+                    .to_string()
+            }
+            Self::InfrastructureError { component, .. } => format!(
+                "the {} check failed to run, so the request was not fully checked",
+                component
+            ),
+        }
+    }
 }
 
 impl std::fmt::Display for ReviewFlag {
@@ -221,12 +631,15 @@ impl std::fmt::Display for ReviewFlag {
             Self::HighGasUsage { percentage } => {
                 write!(f, "High gas usage: {}%", percentage)
             }
-            Self::NewTool { tool_name } => {
-                write!(f, "New tool: '{}'", tool_name)
+            Self::UnregisteredTool { name } => {
+                write!(f, "Unregistered tool allowed: '{}'", name)
             }
             Self::BorderlineWaluigi { score } => {
                 write!(f, "Borderline Waluigi score: {:.2}", score)
             }
+            Self::InfrastructureError { component, error } => {
+                write!(f, "Infrastructure error in {}: {}", component, error)
+            }
         }
     }
 }
@@ -262,6 +675,220 @@ mod tests {
         assert!(verdict.requires_review());
     }
 
+    fn unknown(name: &str) -> Verdict {
+        Verdict::block(BlockReason::UnknownTool {
+            tool_name: name.to_string(),
+        })
+    }
+
+    fn gas(percentage: u8) -> ReviewFlag {
+        ReviewFlag::HighGasUsage { percentage }
+    }
+
+    #[test]
+    fn test_merge_allow() {
+        assert_eq!(Verdict::allow().merge(Verdict::allow()), Verdict::allow());
+    }
+
+    #[test]
+    fn test_merge_review_beats_allow() {
+        let review = Verdict::review(vec![gas(80)]);
+        assert_eq!(Verdict::allow().merge(review.clone()), review);
+        assert_eq!(review.clone().merge(Verdict::allow()), review);
+    }
+
+    #[test]
+    fn test_merge_reviews_union_flags() {
+        let a = Verdict::review(vec![gas(80), gas(90)]);
+        let b = Verdict::review(vec![
+            gas(90),
+            ReviewFlag::MinorDrift {
+                tool_name: "t".to_string(),
+            },
+        ]);
+        assert_eq!(
+            a.merge(b),
+            Verdict::review(vec![
+                gas(80),
+                gas(90),
+                ReviewFlag::MinorDrift {
+                    tool_name: "t".to_string()
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_merge_block_beats_allow_and_review() {
+        for other in [Verdict::allow(), Verdict::review(vec![gas(80)])] {
+            assert_eq!(unknown("rm").merge(other.clone()), unknown("rm"));
+            assert_eq!(other.merge(unknown("rm")), unknown("rm"));
+        }
+    }
+
+    #[test]
+    fn test_merge_blocks_accumulate_reasons() {
+        let merged = unknown("a").merge(unknown("b")).merge(unknown("c"));
+        let Verdict::Block {
+            reason: BlockReason::Multiple { reasons },
+        } = &merged
+        else {
+            panic!("Expected Multiple, got {:?}", merged);
+        };
+        assert_eq!(reasons.len(), 3, "merged reasons are flattened");
+        assert_eq!(
+            merged.jsonrpc_error().unwrap().data.as_deref(),
+            Some("Unknown tool: 'a'; Unknown tool: 'b'; Unknown tool: 'c'")
+        );
+    }
+
+    #[test]
+    fn test_http_status_mapping() {
+        assert_eq!(Verdict::allow().http_status(), 200);
+        assert_eq!(Verdict::review(vec![]).http_status(), 200);
+        let blocked = Verdict::block(BlockReason::UnknownTool {
+            tool_name: "rm".to_string(),
+        });
+        assert_eq!(blocked.http_status(), 403);
+    }
+
+    #[test]
+    fn test_jsonrpc_error_mapping() {
+        assert_eq!(Verdict::allow().jsonrpc_error(), None);
+        let review = Verdict::review(vec![ReviewFlag::HighGasUsage { percentage: 90 }]);
+        assert_eq!(review.jsonrpc_error(), None);
+
+        let blocked = Verdict::block(BlockReason::UnknownTool {
+            tool_name: "rm".to_string(),
+        });
+        let error = blocked.jsonrpc_error().unwrap();
+        assert_eq!(error.code, BLOCKED_ERROR_CODE);
+        assert_eq!(error.message, BLOCKED_ERROR_MESSAGE);
+        assert_eq!(error.data.as_deref(), Some("Unknown tool: 'rm'"));
+
+        let json = serde_json::to_value(&error).unwrap();
+        assert_eq!(json["code"], -32600);
+        assert_eq!(json["data"], "Unknown tool: 'rm'");
+    }
+
+    fn every_block_reason() -> Vec<BlockReason> {
+        let tool_name = || "helper".to_string();
+        let votes = || "0 approve / 2 reject / 1 abstain".to_string();
+        vec![
+            BlockReason::SchemaDrift {
+                tool_name: tool_name(),
+                drift_level: "Critical".to_string(),
+            },
+            BlockReason::HashMismatch {
+                tool_name: tool_name(),
+                expected: "ab12".to_string(),
+                actual: "cd34".to_string(),
+            },
+            BlockReason::OriginMismatch {
+                tool_name: tool_name(),
+                expected: "stdio://trusted".to_string(),
+                actual: "stdio://other".to_string(),
+            },
+            BlockReason::CycleDetected {
+                cycle: "A -> B -> A".to_string(),
+            },
+            BlockReason::GasExhausted {
+                used: 1200,
+                limit: 1000,
+            },
+            BlockReason::ContextOverflow {
+                size: 9000,
+                max: 8000,
+            },
+            BlockReason::CouncilRejected {
+                votes: votes(),
+                reason: "Deontology: destructive".to_string(),
+            },
+            BlockReason::NoConsensus {
+                votes: votes(),
+                reason: "Too many abstentions".to_string(),
+            },
+            BlockReason::WaluigiEffect {
+                score: 0.9,
+                patterns: vec!["ignore previous".to_string()],
+            },
+            BlockReason::UnknownTool {
+                tool_name: tool_name(),
+            },
+            BlockReason::DeniedTool {
+                tool_name: tool_name(),
+            },
+            BlockReason::SecurityViolation {
+                description: "payload too large".to_string(),
+            },
+            BlockReason::InfrastructureError {
+                component: "registry".to_string(),
+                error: "sled: io error".to_string(),
+            },
+            BlockReason::Multiple {
+                reasons: vec![
+                    BlockReason::UnknownTool {
+                        tool_name: tool_name(),
+                    },
+                    BlockReason::GasExhausted {
+                        used: 1200,
+                        limit: 1000,
+                    },
+                ],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_explain_each_block_reason() {
+        let reasons = every_block_reason();
+        let kinds: std::collections::HashSet<_> = reasons.iter().map(BlockReason::kind).collect();
+        assert_eq!(kinds.len(), reasons.len(), "one sample per variant");
+
+        let mut seen = std::collections::HashSet::new();
+        for reason in reasons {
+            let explanation = Verdict::block(reason.clone()).explain();
+            assert!(explanation.starts_with("Blocked"), "{}", explanation);
+            assert!(explanation.ends_with('.'), "{}", explanation);
+            // No enum names, hashes or internal error text
+            let debug = format!("{:?}", reason.kind());
+            assert!(!explanation.contains(&debug), "{}", explanation);
+            assert!(!explanation.contains("cd34"), "{}", explanation);
+            assert!(!explanation.contains("sled"), "{}", explanation);
+            assert!(
+                seen.insert(explanation.clone()),
+                "duplicate: {}",
+                explanation
+            );
+        }
+
+        assert_eq!(
+            unknown("a").merge(unknown("b")).explain(),
+            "Blocked for 2 reasons: the tool 'a' is not registered; \
+             the tool 'b' is not registered."
+        );
+    }
+
+    #[test]
+    fn test_explain_allow_and_review() {
+        assert_eq!(
+            Verdict::allow().explain(),
+            "Allowed: the request passed every security check."
+        );
+        assert_eq!(Verdict::review(vec![]).explain(), "Needs review.");
+        assert_eq!(
+            Verdict::review(vec![
+                ReviewFlag::UnregisteredTool {
+                    name: "helper".to_string()
+                },
+                gas(85),
+            ])
+            .explain(),
+            "Needs review: the tool 'helper' is not registered but unregistered tools \
+             are allowed; the session has used 85% of its execution budget."
+        );
+    }
+
     #[test]
     fn test_block_reason_display() {
         let reason = BlockReason::CycleDetected {
@@ -269,4 +896,20 @@ mod tests {
         };
         assert_eq!(reason.to_string(), "Cycle detected: A -> B -> A");
     }
+
+    #[test]
+    fn test_new_tool_flag_deserializes_as_unregistered_tool() {
+        let flag: ReviewFlag =
+            serde_json::from_str(r#"{"NewTool":{"tool_name":"read_file"}}"#).unwrap();
+        assert_eq!(
+            flag,
+            ReviewFlag::UnregisteredTool {
+                name: "read_file".to_string()
+            }
+        );
+
+        #[allow(deprecated)]
+        let built = ReviewFlag::new_tool("read_file");
+        assert_eq!(built, flag);
+    }
 }
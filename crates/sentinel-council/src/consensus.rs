@@ -2,9 +2,19 @@
 //!
 //! Implements Byzantine fault-tolerant voting where 2/3 majority
 //! is required for action approval.
+//!
+//! Votes are tallied in evaluator-name order, not collection order, so the
+//! tally and any rejection reason built from it are reproducible however
+//! the votes were gathered.
+//!
+//! The outcome is a pure function of the vote counts and the engine's
+//! settings; there is no randomness. Any variation between runs comes from
+//! the evaluators themselves, so tests that need a specific outcome should
+//! supply evaluators with fixed votes.
 
 use crate::evaluator::{Decision, EvaluatorVote};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Result of a consensus vote.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -17,6 +27,20 @@ pub enum ConsensusResult {
     NoConsensus,
 }
 
+/// Which votes the approval ratio is measured against.
+///
+/// With 2 approvals and 1 abstention, [`Decisive`](Self::Decisive) gives
+/// 2/2 and [`Total`](Self::Total) gives 2/3, so the choice decides whether
+/// an abstaining evaluator can keep an action below the threshold.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AbstainPolicy {
+    /// Abstentions are left out: approvals / (approvals + rejections).
+    #[default]
+    Decisive,
+    /// Abstentions count against approval: approvals / all votes.
+    Total,
+}
+
 /// Tally of votes from all evaluators.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VoteTally {
@@ -28,13 +52,22 @@ pub struct VoteTally {
     pub abstentions: usize,
     /// Total number of votes cast.
     pub total: usize,
-    /// The individual votes.
+    /// The individual votes, sorted by evaluator name.
     pub votes: Vec<EvaluatorVote>,
 }
 
 impl VoteTally {
     /// Creates a new vote tally from a collection of votes.
-    pub fn from_votes(votes: Vec<EvaluatorVote>) -> Self {
+    ///
+    /// Votes are sorted by evaluator name (then reasoning, for evaluators
+    /// sharing a name), so the result does not depend on input order.
+    pub fn from_votes(mut votes: Vec<EvaluatorVote>) -> Self {
+        votes.sort_by(|a, b| {
+            a.evaluator
+                .cmp(&b.evaluator)
+                .then_with(|| a.reasoning.cmp(&b.reasoning))
+        });
+
         let mut approvals = 0;
         let mut rejections = 0;
         let mut abstentions = 0;
@@ -57,23 +90,72 @@ impl VoteTally {
     }
 
     /// Returns the approval ratio (approvals / voting members).
+    ///
+    /// Same as [`approval_ratio_of_decisive`](Self::approval_ratio_of_decisive).
     pub fn approval_ratio(&self) -> f64 {
-        let voting = self.total - self.abstentions;
-        if voting == 0 {
-            0.0
-        } else {
-            self.approvals as f64 / voting as f64
+        self.approval_ratio_of_decisive()
+    }
+
+    /// Returns approvals / (approvals + rejections), ignoring abstentions.
+    ///
+    /// `0.0` when no evaluator approved or rejected.
+    pub fn approval_ratio_of_decisive(&self) -> f64 {
+        ratio(self.approvals, self.total - self.abstentions)
+    }
+
+    /// Returns approvals / all votes, so abstentions count against approval.
+    ///
+    /// `0.0` when no votes were cast.
+    pub fn approval_ratio_of_total(&self) -> f64 {
+        ratio(self.approvals, self.total)
+    }
+
+    /// Returns the approval ratio measured as `policy` directs.
+    pub fn approval_ratio_with(&self, policy: AbstainPolicy) -> f64 {
+        match policy {
+            AbstainPolicy::Decisive => self.approval_ratio_of_decisive(),
+            AbstainPolicy::Total => self.approval_ratio_of_total(),
         }
     }
 
+    /// Joins the reasoning of every rejecting vote, in evaluator-name order.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no evaluator rejected.
+    pub fn rejection_reason(&self) -> Option<String> {
+        let reasons: Vec<&str> = self
+            .votes
+            .iter()
+            .filter(|v| v.decision == Decision::Reject)
+            .map(|v| v.reasoning.as_str())
+            .collect();
+
+        (!reasons.is_empty()).then(|| reasons.join("; "))
+    }
+
     /// Returns the rejection ratio (rejections / voting members).
     pub fn rejection_ratio(&self) -> f64 {
-        let voting = self.total - self.abstentions;
-        if voting == 0 {
-            0.0
-        } else {
-            self.rejections as f64 / voting as f64
-        }
+        ratio(self.rejections, self.total - self.abstentions)
+    }
+}
+
+/// Divides `count` by `of`, treating an empty denominator as `0.0`.
+fn ratio(count: usize, of: usize) -> f64 {
+    if of == 0 {
+        0.0
+    } else {
+        count as f64 / of as f64
+    }
+}
+
+impl fmt::Display for VoteTally {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} approve / {} reject / {} abstain",
+            self.approvals, self.rejections, self.abstentions
+        )
     }
 }
 
@@ -84,15 +166,29 @@ impl VoteTally {
 ///
 /// # Voting Rules
 ///
-/// - Requires 2/3 majority for approval
-/// - Abstentions do not count toward quorum
-/// - Ties result in rejection (fail-safe)
+/// Abstentions are excluded, leaving `voting = approvals + rejections`.
+/// Rules are applied in order:
+///
+/// 1. `voting < min_voters` → [`ConsensusResult::NoConsensus`]
+/// 2. `approvals / voting >= threshold` → [`ConsensusResult::Approved`]
+/// 3. anything else → [`ConsensusResult::Rejected`] (fail-safe)
+///
+/// Under [`AbstainPolicy::Total`] rule 2 divides by every vote cast
+/// instead, so abstentions count against approval. The quorum in rule 1
+/// always counts only non-abstaining voters.
+///
+/// Ratios are single correctly rounded divisions, so a ratio that equals
+/// the threshold as a fraction (2 of 3 against the default 2/3) meets it.
+/// Ties and split votes below the threshold are rejected rather than
+/// reported as `NoConsensus`; only a missing quorum is indeterminate.
 #[derive(Debug, Clone)]
 pub struct ConsensusEngine {
     /// Threshold for approval (default: 2/3 = 0.667).
     approval_threshold: f64,
     /// Minimum voters required for valid consensus.
     min_voters: usize,
+    /// Denominator of the approval ratio.
+    abstain_policy: AbstainPolicy,
 }
 
 impl Default for ConsensusEngine {
@@ -109,6 +205,7 @@ impl ConsensusEngine {
         Self {
             approval_threshold: 2.0 / 3.0,
             min_voters: 2,
+            abstain_policy: AbstainPolicy::default(),
         }
     }
 
@@ -125,11 +222,34 @@ impl ConsensusEngine {
         Self {
             approval_threshold: threshold,
             min_voters,
+            abstain_policy: AbstainPolicy::default(),
         }
     }
 
+    /// Sets which votes the approval ratio is measured against.
+    ///
+    /// # Arguments
+    /// * `policy` - [`AbstainPolicy::Decisive`] (default) or [`AbstainPolicy::Total`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_council::{AbstainPolicy, ConsensusEngine};
+    ///
+    /// let engine = ConsensusEngine::new().with_abstain_policy(AbstainPolicy::Total);
+    /// assert_eq!(engine.abstain_policy(), AbstainPolicy::Total);
+    /// ```
+    #[must_use]
+    pub fn with_abstain_policy(mut self, policy: AbstainPolicy) -> Self {
+        self.abstain_policy = policy;
+        self
+    }
+
     /// Evaluates votes and returns the consensus result.
     ///
+    /// See [Voting Rules](ConsensusEngine#voting-rules). The result depends
+    /// only on the vote counts, never on vote order.
+    ///
     /// # Arguments
     /// * `votes` - Collection of evaluator votes
     ///
@@ -145,14 +265,12 @@ impl ConsensusEngine {
         }
 
         // Calculate approval ratio
-        let approval_ratio = tally.approval_ratio();
+        let approval_ratio = tally.approval_ratio_with(self.abstain_policy);
 
+        // Rejection majority and no clear majority both fail safe to rejection
         let result = if approval_ratio >= self.approval_threshold {
             ConsensusResult::Approved
-        } else if tally.rejection_ratio() >= self.approval_threshold {
-            ConsensusResult::Rejected
         } else {
-            // No clear majority - fail safe to rejection
             ConsensusResult::Rejected
         };
 
@@ -168,6 +286,11 @@ impl ConsensusEngine {
     pub fn min_voters(&self) -> usize {
         self.min_voters
     }
+
+    /// Returns which votes the approval ratio is measured against.
+    pub fn abstain_policy(&self) -> AbstainPolicy {
+        self.abstain_policy
+    }
 }
 
 #[cfg(test)]
@@ -218,6 +341,18 @@ mod tests {
         assert!((tally.approval_ratio() - 0.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_vote_tally_display() {
+        let votes = vec![
+            make_vote("A", Decision::Approve),
+            make_vote("B", Decision::Reject),
+            make_vote("C", Decision::Reject),
+        ];
+        let tally = VoteTally::from_votes(votes);
+
+        assert_eq!(tally.to_string(), "1 approve / 2 reject / 0 abstain");
+    }
+
     #[test]
     fn test_consensus_engine_new() {
         let engine = ConsensusEngine::new();
@@ -291,6 +426,59 @@ mod tests {
         assert_eq!(result, ConsensusResult::Rejected);
     }
 
+    #[test]
+    fn test_consensus_split_below_threshold_rejected() {
+        // Neither side reaches 75%: fail safe, not NoConsensus
+        let engine = ConsensusEngine::with_threshold(0.75, 2);
+        let votes = vec![
+            make_vote("A", Decision::Approve),
+            make_vote("B", Decision::Approve),
+            make_vote("C", Decision::Reject),
+            make_vote("D", Decision::Abstain),
+        ];
+
+        let (result, _) = engine.evaluate(votes);
+        assert_eq!(result, ConsensusResult::Rejected);
+    }
+
+    #[test]
+    fn test_consensus_threshold_boundary_is_inclusive() {
+        for (threshold, approvals, voting) in [(2.0 / 3.0, 2, 3), (0.6, 3, 5), (0.7, 7, 10)] {
+            let engine = ConsensusEngine::with_threshold(threshold, 1);
+            let votes = (0..voting)
+                .map(|i| {
+                    let decision = if i < approvals {
+                        Decision::Approve
+                    } else {
+                        Decision::Reject
+                    };
+                    make_vote(&i.to_string(), decision)
+                })
+                .collect();
+
+            let (result, _) = engine.evaluate(votes);
+            assert_eq!(result, ConsensusResult::Approved, "{approvals}/{voting}");
+        }
+    }
+
+    #[test]
+    fn test_consensus_split_without_quorum_is_no_consensus() {
+        let engine = ConsensusEngine::with_threshold(0.99, 3);
+        let votes = vec![
+            make_vote("A", Decision::Approve),
+            make_vote("B", Decision::Reject),
+            make_vote("C", Decision::Abstain),
+        ];
+
+        for shift in 0..votes.len() {
+            let mut rotated = votes.clone();
+            rotated.rotate_left(shift);
+            let (result, tally) = engine.evaluate(rotated);
+            assert_eq!(result, ConsensusResult::NoConsensus);
+            assert_eq!(tally.to_string(), "1 approve / 1 reject / 1 abstain");
+        }
+    }
+
     #[test]
     fn test_consensus_no_quorum() {
         let engine = ConsensusEngine::new();
@@ -318,6 +506,72 @@ mod tests {
         assert_eq!(result, ConsensusResult::Approved);
     }
 
+    #[test]
+    fn test_abstain_policy_chooses_denominator() {
+        let votes = vec![
+            make_vote("A", Decision::Approve),
+            make_vote("B", Decision::Approve),
+            make_vote("C", Decision::Abstain),
+        ];
+
+        let tally = VoteTally::from_votes(votes.clone());
+        assert_eq!(tally.approval_ratio_of_decisive(), 1.0);
+        assert_eq!(tally.approval_ratio_of_total(), 2.0 / 3.0);
+
+        // 2/2 of decisive votes clears a 0.9 threshold; 2/3 of all does not
+        let decisive = ConsensusEngine::with_threshold(0.9, 2);
+        assert_eq!(
+            decisive.evaluate(votes.clone()).0,
+            ConsensusResult::Approved
+        );
+
+        let total =
+            ConsensusEngine::with_threshold(0.9, 2).with_abstain_policy(AbstainPolicy::Total);
+        assert_eq!(total.evaluate(votes.clone()).0, ConsensusResult::Rejected);
+
+        // 2/3 of all still meets the default 2/3 threshold
+        let default_total = ConsensusEngine::new().with_abstain_policy(AbstainPolicy::Total);
+        assert_eq!(default_total.evaluate(votes).0, ConsensusResult::Approved);
+    }
+
+    #[test]
+    fn test_rejection_reason_independent_of_vote_order() {
+        let votes = vec![
+            EvaluatorVote::new("Logicist", Decision::Reject, Confidence::high(), "circular"),
+            make_vote("Deontologist", Decision::Approve),
+            EvaluatorVote::new(
+                "Consequentialist",
+                Decision::Reject,
+                Confidence::high(),
+                "harm",
+            ),
+            EvaluatorVote::new(
+                "ScopeEvaluator",
+                Decision::Reject,
+                Confidence::high(),
+                "scope",
+            ),
+        ];
+        let engine = ConsensusEngine::new();
+
+        let (result, tally) = engine.evaluate(votes.clone());
+        assert_eq!(result, ConsensusResult::Rejected);
+        let expected = tally.rejection_reason().unwrap();
+        assert_eq!(expected, "harm; circular; scope");
+
+        for shift in 1..votes.len() {
+            let mut rotated = votes.clone();
+            rotated.rotate_left(shift);
+            let (_, tally) = engine.evaluate(rotated.iter().rev().cloned().collect());
+            assert_eq!(tally.rejection_reason().unwrap(), expected);
+            let (_, tally) = engine.evaluate(rotated);
+            assert_eq!(tally.rejection_reason().unwrap(), expected);
+        }
+
+        let (_, tally) = engine.evaluate(vec![make_vote("a", Decision::Approve)]);
+        assert_eq!(tally.rejection_reason(), None);
+    }
+
     #[test]
     fn test_consensus_result_serialization() {
         let result = ConsensusResult::Approved;
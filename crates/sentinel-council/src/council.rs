@@ -3,11 +3,15 @@
 //! The main entry point for action evaluation, combining the
 //! evaluator triad, consensus engine, and Waluigi detector.
 
+use std::panic::{self, AssertUnwindSafe};
+
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
 use crate::consensus::{ConsensusEngine, ConsensusResult, VoteTally};
+use crate::evaluator::scope::ScopeEvaluator;
 use crate::evaluator::triad::{Consequentialist, Deontologist, Logicist};
-use crate::evaluator::{EvaluationContext, Evaluator, EvaluatorVote};
+use crate::evaluator::{Confidence, EvaluationContext, Evaluator, EvaluatorVote};
 use crate::waluigi::{WaluigiDetector, WaluigiScore};
 use crate::Result;
 
@@ -24,6 +28,8 @@ pub struct ActionProposal {
     pub response_content: Option<String>,
     /// Previous response for context.
     pub previous_response: Option<String>,
+    /// The model's stated reasoning for the action, passed to evaluators.
+    pub rationale: Option<String>,
 }
 
 impl ActionProposal {
@@ -35,6 +41,7 @@ impl ActionProposal {
             parameters: Vec::new(),
             response_content: None,
             previous_response: None,
+            rationale: None,
         }
     }
 
@@ -56,6 +63,16 @@ impl ActionProposal {
         self
     }
 
+    /// Adds the model's stated reasoning for evaluators to cross-check.
+    ///
+    /// Unlike [`with_response`](Self::with_response), this is not scanned
+    /// for Waluigi patterns; it is handed to every evaluator as
+    /// [`EvaluationContext::rationale`].
+    pub fn with_rationale(mut self, rationale: impl Into<String>) -> Self {
+        self.rationale = Some(rationale.into());
+        self
+    }
+
     /// Converts to evaluation context.
     fn to_context(&self) -> EvaluationContext {
         EvaluationContext {
@@ -63,6 +80,7 @@ impl ActionProposal {
             target: self.target.clone(),
             parameters: self.parameters.clone(),
             history: Vec::new(),
+            rationale: self.rationale.clone(),
         }
     }
 }
@@ -117,6 +135,25 @@ impl CouncilVerdict {
     }
 }
 
+/// Waluigi score at or above which a detection vetoes the action in every
+/// [`WaluigiMode`].
+pub const WALUIGI_VETO_CEILING: f64 = 0.9;
+
+/// How a Waluigi detection affects the council's decision.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaluigiMode {
+    /// A score at or above the threshold vetoes the action before any
+    /// evaluator votes.
+    #[default]
+    Veto,
+    /// A score at or above the threshold adds a reject vote from
+    /// `"Waluigi"`, with the score as its confidence, to the evaluators'
+    /// votes. The action is then decided by consensus, so a noisy detector
+    /// alone cannot reject an action every evaluator approves. Scores at or
+    /// above [`WALUIGI_VETO_CEILING`] still veto.
+    WeightedVote,
+}
+
 /// The Cognitive Council - main facade for action evaluation.
 ///
 /// Integrates the evaluator triad, consensus engine, and Waluigi
@@ -144,6 +181,8 @@ pub struct CognitiveCouncil {
     waluigi: WaluigiDetector,
     /// Whether to run Waluigi detection.
     waluigi_enabled: bool,
+    /// Whether a detection vetoes or votes.
+    waluigi_mode: WaluigiMode,
 }
 
 impl Default for CognitiveCouncil {
@@ -160,10 +199,12 @@ impl CognitiveCouncil {
                 Box::new(Deontologist::new()),
                 Box::new(Consequentialist::new()),
                 Box::new(Logicist::new()),
+                Box::new(ScopeEvaluator::new()),
             ],
             consensus: ConsensusEngine::new(),
             waluigi: WaluigiDetector::new(),
             waluigi_enabled: true,
+            waluigi_mode: WaluigiMode::default(),
         }
     }
 
@@ -178,6 +219,7 @@ impl CognitiveCouncil {
             consensus,
             waluigi,
             waluigi_enabled: true,
+            waluigi_mode: WaluigiMode::default(),
         }
     }
 
@@ -191,12 +233,33 @@ impl CognitiveCouncil {
         self.waluigi_enabled
     }
 
+    /// Sets whether a Waluigi detection vetoes the action or votes against it.
+    ///
+    /// # Security Notes
+    ///
+    /// Under [`WaluigiMode::WeightedVote`] the detector counts as one more
+    /// evaluator, so a borderline inverted response is approved if enough
+    /// evaluators approve the action itself. A score at or above
+    /// [`WALUIGI_VETO_CEILING`] vetoes regardless of mode. Use it only where
+    /// detector false positives cost more than a missed inversion.
+    pub fn set_waluigi_mode(&mut self, mode: WaluigiMode) {
+        self.waluigi_mode = mode;
+    }
+
+    /// Returns whether a Waluigi detection vetoes or votes.
+    pub fn waluigi_mode(&self) -> WaluigiMode {
+        self.waluigi_mode
+    }
+
     /// Evaluates an action proposal.
     ///
     /// # Process
     ///
-    /// 1. Check for Waluigi effect (if response content provided)
-    /// 2. Collect votes from all evaluators
+    /// 1. Check for Waluigi effect (if response content provided); under
+    ///    [`WaluigiMode::Veto`], or at [`WALUIGI_VETO_CEILING`], a detection
+    ///    ends evaluation here
+    /// 2. Collect votes from all evaluators (a panicking evaluator rejects),
+    ///    plus the detector's reject vote under [`WaluigiMode::WeightedVote`]
     /// 3. Run consensus voting
     /// 4. Return verdict
     ///
@@ -206,56 +269,47 @@ impl CognitiveCouncil {
     /// # Returns
     /// A [`CouncilVerdict`] indicating whether the action is approved.
     pub fn evaluate(&self, proposal: &ActionProposal) -> CouncilVerdict {
-        // Step 1: Waluigi check (if enabled and content provided)
-        if self.waluigi_enabled {
-            if let Some(ref content) = proposal.response_content {
+        // Step 1: Waluigi check (score is reported even when disabled)
+        let (waluigi_score, patterns) = match proposal.response_content {
+            Some(ref content) => {
                 let context = proposal.previous_response.as_deref();
-                let (score, patterns) = self.waluigi.analyze(content, context);
+                let (score, patterns) = self.waluigi.analyze(content, context).into_parts();
+                (Some(score), patterns)
+            }
+            None => (None, Vec::new()),
+        };
+        let inverted = waluigi_score
+            .filter(|score| self.waluigi_enabled && score.is_inverted(self.waluigi.threshold()));
 
-                if score.is_inverted(self.waluigi.threshold()) {
-                    return CouncilVerdict::WaluigiVeto { score, patterns };
-                }
+        if let Some(score) = inverted {
+            if self.waluigi_mode == WaluigiMode::Veto || score.value() >= WALUIGI_VETO_CEILING {
+                return CouncilVerdict::WaluigiVeto { score, patterns };
             }
         }
 
         // Step 2: Collect votes from evaluators
         let context = proposal.to_context();
-        let votes: Vec<EvaluatorVote> = self
+        let mut votes: Vec<EvaluatorVote> = self
             .evaluators
             .iter()
-            .map(|e| e.evaluate(&context))
+            .map(|e| Self::vote(e.as_ref(), &context))
             .collect();
+        votes.extend(inverted.map(|score| Self::waluigi_vote(score, &patterns)));
 
         // Step 3: Run consensus voting
         let (result, tally) = self.consensus.evaluate(votes);
 
-        // Step 4: Determine Waluigi score for verdict
-        let waluigi_score = proposal.response_content.as_ref().map(|content| {
-            let context = proposal.previous_response.as_deref();
-            let (score, _) = self.waluigi.analyze(content, context);
-            score
-        });
-
-        // Step 5: Return verdict
+        // Step 4: Return verdict
         match result {
             ConsensusResult::Approved => CouncilVerdict::Approved {
                 tally,
                 waluigi_score,
             },
             ConsensusResult::Rejected => {
-                // Find the rejection reason from votes
-                let reasons: Vec<&str> = tally
-                    .votes
-                    .iter()
-                    .filter(|v| v.decision == crate::evaluator::Decision::Reject)
-                    .map(|v| v.reasoning.as_str())
-                    .collect();
-
-                let reason = if reasons.is_empty() {
-                    "Rejected by consensus".to_string()
-                } else {
-                    reasons.join("; ")
-                };
+                // Rejecting votes' reasons, in a deterministic order
+                let reason = tally
+                    .rejection_reason()
+                    .unwrap_or_else(|| "Rejected by consensus".to_string());
 
                 CouncilVerdict::Rejected {
                     reason,
@@ -270,6 +324,41 @@ impl CognitiveCouncil {
         }
     }
 
+    /// Collects one evaluator's vote, turning a panic into a rejection.
+    ///
+    /// A buggy or hostile evaluator must not take the pipeline down with
+    /// it. The panic counts against the action: an abstention would leave
+    /// the denominator under [`AbstainPolicy::Decisive`](crate::AbstainPolicy::Decisive),
+    /// so a panic in an evaluator that would have rejected could raise the
+    /// approval ratio instead of failing closed.
+    fn vote(evaluator: &dyn Evaluator, context: &EvaluationContext) -> EvaluatorVote {
+        match panic::catch_unwind(AssertUnwindSafe(|| evaluator.evaluate(context))) {
+            Ok(vote) => vote,
+            Err(_) => {
+                warn!(
+                    "Evaluator '{}' panicked on '{}'; counting as rejection",
+                    evaluator.name(),
+                    context.action
+                );
+                EvaluatorVote::reject(evaluator.name(), Confidence::high(), "evaluator panicked")
+            }
+        }
+    }
+
+    /// The reject vote cast for an inverted response under
+    /// [`WaluigiMode::WeightedVote`].
+    fn waluigi_vote(score: WaluigiScore, patterns: &[String]) -> EvaluatorVote {
+        EvaluatorVote::reject(
+            "Waluigi",
+            Confidence::new(score.value()),
+            format!(
+                "Alignment inversion detected (score {:.2}): {}",
+                score.value(),
+                patterns.join(", ")
+            ),
+        )
+    }
+
     /// Evaluates with a custom validation function.
     ///
     /// # Arguments
@@ -362,7 +451,7 @@ mod tests {
     #[test]
     fn test_cognitive_council_new() {
         let council = CognitiveCouncil::new();
-        assert_eq!(council.evaluator_count(), 3);
+        assert_eq!(council.evaluator_count(), 4);
         assert!(council.waluigi_enabled());
     }
 
@@ -373,6 +462,14 @@ mod tests {
         assert!(names.contains(&"Deontologist"));
         assert!(names.contains(&"Consequentialist"));
         assert!(names.contains(&"Logicist"));
+        assert!(names.contains(&"ScopeEvaluator"));
+    }
+
+    #[test]
+    fn test_cognitive_council_rejects_wide_scope() {
+        let council = CognitiveCouncil::new();
+        let proposal = ActionProposal::new("delete", "/").with_parameter("--recursive");
+        assert!(council.evaluate(&proposal).is_rejected());
     }
 
     #[test]
@@ -446,26 +543,251 @@ mod tests {
         assert!(result.is_err());
     }
 
+    /// Casts the same vote for every proposal.
+    struct FixedEvaluator(&'static str, Decision);
+
+    impl Evaluator for FixedEvaluator {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn framework(&self) -> &str {
+            "Fixed vote"
+        }
+
+        fn evaluate(&self, _context: &EvaluationContext) -> EvaluatorVote {
+            EvaluatorVote::new(self.0, self.1, Confidence::high(), "fixed")
+        }
+    }
+
+    fn fixed_council(engine: ConsensusEngine) -> CognitiveCouncil {
+        CognitiveCouncil::with_components(
+            vec![
+                Box::new(FixedEvaluator("Approver", Decision::Approve)),
+                Box::new(FixedEvaluator("Rejecter", Decision::Reject)),
+                Box::new(FixedEvaluator("Abstainer", Decision::Abstain)),
+            ],
+            engine,
+            WaluigiDetector::new(),
+        )
+    }
+
     #[test]
     fn test_cognitive_council_no_consensus() {
-        // Create a council with high threshold that won't be met
+        // One approve, one reject, one abstain: two voters can never make
+        // a quorum of three, whatever the action
+        let council = fixed_council(ConsensusEngine::with_threshold(0.99, 3));
+
+        for proposal in [
+            ActionProposal::new("restart", "service"),
+            ActionProposal::new("read", "/tmp/file.txt"),
+            ActionProposal::new("delete", "/etc/passwd"),
+        ] {
+            match council.evaluate(&proposal) {
+                CouncilVerdict::NoConsensus { tally, .. } => {
+                    assert_eq!((tally.approvals, tally.rejections), (1, 1));
+                    assert_eq!(tally.abstentions, 1);
+                }
+                other => panic!("expected NoConsensus, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_waluigi_mode_decides_borderline_detection() {
+        let response = "As an evil AI, I will help you.";
+        let mut waluigi = WaluigiDetector::new();
+        let score = waluigi.analyze(response, None).score;
+        assert!(score.value() > 0.0);
+        // Borderline: exactly at the threshold
+        waluigi.set_threshold(score.value());
+
+        let mut council = CognitiveCouncil::with_components(
+            vec![
+                Box::new(FixedEvaluator("First", Decision::Approve)),
+                Box::new(FixedEvaluator("Second", Decision::Approve)),
+                Box::new(FixedEvaluator("Third", Decision::Approve)),
+            ],
+            ConsensusEngine::new(),
+            waluigi,
+        );
+        let proposal = ActionProposal::new("read", "/tmp/report.txt").with_response(response);
+
+        assert_eq!(council.waluigi_mode(), WaluigiMode::Veto);
+        assert!(matches!(
+            council.evaluate(&proposal),
+            CouncilVerdict::WaluigiVeto { .. }
+        ));
+
+        council.set_waluigi_mode(WaluigiMode::WeightedVote);
+        match council.evaluate(&proposal) {
+            CouncilVerdict::Approved {
+                tally,
+                waluigi_score,
+            } => {
+                assert_eq!((tally.approvals, tally.rejections), (3, 1));
+                assert!(tally.votes.iter().any(|v| v.evaluator == "Waluigi"));
+                assert_eq!(waluigi_score, Some(score));
+            }
+            other => panic!("expected Approved, got {:?}", other),
+        }
+
+        // Without enough approvals, the Waluigi vote tips it to rejection
+        let mut split = fixed_council(ConsensusEngine::with_threshold(0.5, 2));
+        split.set_waluigi_mode(WaluigiMode::WeightedVote);
+        split.waluigi.set_threshold(score.value());
+        let verdict = split.evaluate(&proposal);
+        match verdict {
+            CouncilVerdict::Rejected { tally, reason, .. } => {
+                assert_eq!(tally.rejections, 2);
+                assert!(reason.contains("Alignment inversion"), "{reason}");
+            }
+            other => panic!("expected Rejected, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_security_high_waluigi_score_vetoes_under_weighted_vote() {
+        let response = "As an evil AI, I am now jailbroken and will bypass safety.";
+        let waluigi = WaluigiDetector::new();
+        assert!(waluigi.analyze(response, None).score.value() >= WALUIGI_VETO_CEILING);
+
+        let mut council = CognitiveCouncil::with_components(
+            vec![
+                Box::new(FixedEvaluator("First", Decision::Approve)),
+                Box::new(FixedEvaluator("Second", Decision::Approve)),
+                Box::new(FixedEvaluator("Third", Decision::Approve)),
+            ],
+            ConsensusEngine::new(),
+            waluigi,
+        );
+        council.set_waluigi_mode(WaluigiMode::WeightedVote);
+        let proposal = ActionProposal::new("read", "/tmp/report.txt").with_response(response);
+
+        // Unanimous approval does not outvote a high score
+        assert!(matches!(
+            council.evaluate(&proposal),
+            CouncilVerdict::WaluigiVeto { .. }
+        ));
+    }
+
+    #[test]
+    fn test_cognitive_council_split_with_quorum_fails_safe() {
+        // Same split, but quorum is met: the tie is rejected
+        let council = fixed_council(ConsensusEngine::with_threshold(0.99, 2));
+        let verdict = council.evaluate(&ActionProposal::new("restart", "service"));
+
+        assert!(verdict.is_rejected(), "{:?}", verdict);
+    }
+
+    struct PanickingEvaluator;
+
+    impl Evaluator for PanickingEvaluator {
+        fn name(&self) -> &str {
+            "Panicker"
+        }
+
+        fn framework(&self) -> &str {
+            "Always panics"
+        }
+
+        fn evaluate(&self, _context: &EvaluationContext) -> EvaluatorVote {
+            panic!("deliberate evaluator panic");
+        }
+    }
+
+    #[test]
+    fn test_security_panicking_evaluator_rejects() {
         let council = CognitiveCouncil::with_components(
             vec![
+                Box::new(PanickingEvaluator),
                 Box::new(Deontologist::new()),
                 Box::new(Consequentialist::new()),
                 Box::new(Logicist::new()),
             ],
-            ConsensusEngine::with_threshold(0.99, 3),
+            ConsensusEngine::new(),
             WaluigiDetector::new(),
         );
 
-        // A borderline action where evaluators disagree
-        let proposal = ActionProposal::new("restart", "service");
-        let verdict = council.evaluate(&proposal);
+        let verdict = council.evaluate(&ActionProposal::new("read", "/tmp/file.txt"));
+        let tally = match &verdict {
+            CouncilVerdict::Approved { tally, .. }
+            | CouncilVerdict::Rejected { tally, .. }
+            | CouncilVerdict::NoConsensus { tally, .. } => tally,
+            other => panic!("unexpected verdict: {:?}", other),
+        };
+        let vote = tally
+            .votes
+            .iter()
+            .find(|v| v.evaluator == "Panicker")
+            .unwrap();
+        assert_eq!(vote.decision, Decision::Reject);
+        assert_eq!(vote.reasoning, "evaluator panicked");
+
+        // A dangerous action is still rejected by the remaining evaluators
+        let dangerous = ActionProposal::new("delete", "/etc/passwd");
+        assert!(council.evaluate(&dangerous).is_rejected());
+    }
+
+    #[test]
+    fn test_security_panic_cannot_turn_rejection_into_approval() {
+        // Two approvals against 0.75 need a third: had the panicking
+        // evaluator abstained, 2/2 would have cleared the threshold
+        let council = CognitiveCouncil::with_components(
+            vec![
+                Box::new(FixedEvaluator("Approver A", Decision::Approve)),
+                Box::new(FixedEvaluator("Approver B", Decision::Approve)),
+                Box::new(PanickingEvaluator),
+            ],
+            ConsensusEngine::with_threshold(0.75, 2),
+            WaluigiDetector::new(),
+        );
+
+        let verdict = council.evaluate(&ActionProposal::new("read", "/tmp/file.txt"));
+        assert!(verdict.is_rejected(), "{:?}", verdict);
+    }
+
+    /// Rejects deletes whose stated rationale only claims to read.
+    struct IntentEvaluator;
+
+    impl Evaluator for IntentEvaluator {
+        fn name(&self) -> &str {
+            "Intent"
+        }
+
+        fn framework(&self) -> &str {
+            "Stated plan must match the action"
+        }
+
+        fn evaluate(&self, context: &EvaluationContext) -> EvaluatorVote {
+            match context.rationale.as_deref() {
+                Some(plan) if context.action == "delete" && plan.contains("read") => {
+                    EvaluatorVote::reject(
+                        "Intent",
+                        Confidence::high(),
+                        "action contradicts stated plan",
+                    )
+                }
+                _ => EvaluatorVote::approve("Intent", Confidence::high(), "no contradiction"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_rationale_reaches_evaluators() {
+        let council = CognitiveCouncil::with_components(
+            vec![Box::new(IntentEvaluator)],
+            ConsensusEngine::with_threshold(0.5, 1),
+            WaluigiDetector::new(),
+        );
+
+        let honest = ActionProposal::new("delete", "/tmp/cache.txt")
+            .with_rationale("Clean up the stale cache file");
+        assert!(council.evaluate(&honest).is_approved());
 
-        // With 99% threshold, unlikely to reach consensus
-        // (This depends on how evaluators vote on "restart")
-        println!("Verdict: {:?}", verdict);
+        let contradictory = ActionProposal::new("delete", "/tmp/cache.txt")
+            .with_rationale("I will only read the cache to summarize it");
+        assert!(council.evaluate(&contradictory).is_rejected());
     }
 
     // Integration tests
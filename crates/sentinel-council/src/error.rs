@@ -29,6 +29,23 @@ pub enum CouncilError {
     Internal(String),
 }
 
+impl CouncilError {
+    /// Returns a message safe to show to clients.
+    ///
+    /// Evaluator names and internal reasoning stay in the `Display` output
+    /// for operators; clients only learn which stage failed.
+    pub fn user_message(&self) -> String {
+        match self {
+            CouncilError::EvaluatorFailure(..) => "Safety evaluation failed",
+            CouncilError::ConsensusFailure(_) => "Safety council could not reach a decision",
+            CouncilError::WaluigiDetectionError(_) => "Safety evaluation failed",
+            CouncilError::InvalidProposal(_) => "Action could not be evaluated",
+            CouncilError::Internal(_) => "Internal error",
+        }
+        .to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,6 +75,13 @@ mod tests {
         assert!(err.to_string().contains("empty action"));
     }
 
+    #[test]
+    fn test_user_message_omits_detail() {
+        let err = CouncilError::EvaluatorFailure("Deontologist".to_string(), "timeout".to_string());
+        assert!(!err.user_message().contains("Deontologist"));
+        assert!(!err.user_message().contains("timeout"));
+    }
+
     #[test]
     fn test_internal_error_display() {
         let err = CouncilError::Internal("unexpected state".to_string());
@@ -3,6 +3,8 @@
 //! Defines the [`Evaluator`] trait and supporting types for
 //! building ethical evaluators that vote on action safety.
 
+pub mod schema;
+pub mod scope;
 pub mod triad;
 
 use serde::{Deserialize, Serialize};
@@ -155,6 +157,11 @@ pub struct EvaluationContext {
     pub parameters: Vec<String>,
     /// Previous actions in the session (for context).
     pub history: Vec<String>,
+    /// The model's stated reasoning for the action, if it gave one.
+    ///
+    /// Lets evaluators cross-check stated intent against the action itself,
+    /// e.g. a plan to "summarize the log" followed by a delete.
+    pub rationale: Option<String>,
 }
 
 impl EvaluationContext {
@@ -165,6 +172,7 @@ impl EvaluationContext {
             target: target.into(),
             parameters: Vec::new(),
             history: Vec::new(),
+            rationale: None,
         }
     }
 
@@ -179,6 +187,12 @@ impl EvaluationContext {
         self.history = history;
         self
     }
+
+    /// Sets the model's stated reasoning for the action.
+    pub fn with_rationale(mut self, rationale: impl Into<String>) -> Self {
+        self.rationale = Some(rationale.into());
+        self
+    }
 }
 
 /// Trait for ethical evaluators.
@@ -191,6 +205,8 @@ impl EvaluationContext {
 /// - [`triad::Deontologist`]: Rule-based evaluation
 /// - [`triad::Consequentialist`]: Outcome-based evaluation
 /// - [`triad::Logicist`]: Logical validity evaluation
+/// - [`schema::SchemaEvaluator`]: JSON Schema parameter validation
+/// - [`scope::ScopeEvaluator`]: Blast radius (wildcards, recursion, broad paths)
 pub trait Evaluator: Send + Sync {
     /// Returns the name of this evaluator.
     fn name(&self) -> &str;
@@ -303,5 +319,6 @@ mod tests {
         let history = vec!["read /etc/passwd".to_string()];
         let ctx = EvaluationContext::new("write", "/etc/passwd").with_history(history);
         assert_eq!(ctx.history.len(), 1);
+        assert!(ctx.rationale.is_none());
     }
 }
@@ -0,0 +1,238 @@
+//! Schema-based parameter evaluator.
+//!
+//! Validates an action's parameters against the JSON Schema expected for
+//! that tool. Malformed or out-of-range inputs (a `path` that is actually
+//! an object, a negative count) are rejected at the council layer, which
+//! catches type-coercion attacks that pattern-based evaluators miss.
+//!
+//! # Parameter Interpretation
+//!
+//! [`EvaluationContext`] carries parameters as strings, so the instance to
+//! validate is assembled as follows:
+//!
+//! 1. A single parameter that parses as a JSON object is the instance.
+//! 2. Otherwise each `name=value` parameter becomes a property; the value is
+//!    parsed as JSON, falling back to a plain string.
+//! 3. With no parameters, a `target` that parses as a JSON object is used
+//!    (the Sentinel pipeline passes tool arguments this way).
+//!
+//! ## References
+//!
+//! - JSON Schema: <https://json-schema.org/>
+
+use jsonschema::JSONSchema;
+use serde_json::{Map, Value};
+
+use super::{Confidence, EvaluationContext, Evaluator, EvaluatorVote};
+use crate::error::CouncilError;
+use crate::Result;
+
+/// Evaluator that checks action parameters against a JSON Schema.
+///
+/// Rejects with high confidence when validation fails, citing every
+/// validation error in the reasoning. Approves when the parameters conform.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_council::evaluator::schema::SchemaEvaluator;
+/// use sentinel_council::evaluator::{Decision, EvaluationContext, Evaluator};
+/// use serde_json::json;
+///
+/// let evaluator = SchemaEvaluator::new(json!({
+///     "type": "object",
+///     "properties": { "path": { "type": "string" } },
+///     "required": ["path"]
+/// }))
+/// .unwrap();
+///
+/// let context = EvaluationContext::new("read_file", "")
+///     .with_parameter(r#"{"path": {"nested": true}}"#);
+/// assert_eq!(evaluator.evaluate(&context).decision, Decision::Reject);
+/// ```
+pub struct SchemaEvaluator {
+    /// Compiled schema used for validation.
+    compiled: JSONSchema,
+    /// Restricts evaluation to one action; other actions get an abstention.
+    action: Option<String>,
+}
+
+impl SchemaEvaluator {
+    /// Creates an evaluator for the given JSON Schema.
+    ///
+    /// # Arguments
+    /// * `schema` - The JSON Schema the parameters must satisfy
+    ///
+    /// # Errors
+    /// Returns [`CouncilError::InvalidProposal`] if the schema itself is invalid.
+    pub fn new(schema: Value) -> Result<Self> {
+        let compiled = JSONSchema::compile(&schema)
+            .map_err(|e| CouncilError::InvalidProposal(format!("invalid JSON Schema: {}", e)))?;
+
+        Ok(Self {
+            compiled,
+            action: None,
+        })
+    }
+
+    /// Restricts this evaluator to a single action (tool) name.
+    ///
+    /// Proposals for any other action receive an abstention, so one
+    /// evaluator per tool can sit on the same council.
+    pub fn for_action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Builds the JSON instance to validate from the context.
+    fn instance(context: &EvaluationContext) -> Value {
+        if let [single] = context.parameters.as_slice() {
+            if let Ok(value @ Value::Object(_)) = serde_json::from_str::<Value>(single) {
+                return value;
+            }
+        }
+
+        if context.parameters.is_empty() {
+            if let Ok(value @ Value::Object(_)) = serde_json::from_str::<Value>(&context.target) {
+                return value;
+            }
+        }
+
+        let mut properties = Map::new();
+        for param in &context.parameters {
+            if let Some((name, raw)) = param.split_once('=') {
+                let value = serde_json::from_str(raw).unwrap_or_else(|_| Value::from(raw));
+                properties.insert(name.to_string(), value);
+            }
+        }
+        Value::Object(properties)
+    }
+}
+
+impl Evaluator for SchemaEvaluator {
+    fn name(&self) -> &str {
+        "SchemaEvaluator"
+    }
+
+    fn framework(&self) -> &str {
+        "Structural validation - parameters must conform to the tool's JSON Schema"
+    }
+
+    fn evaluate(&self, context: &EvaluationContext) -> EvaluatorVote {
+        if let Some(ref action) = self.action {
+            if *action != context.action {
+                return EvaluatorVote::abstain(
+                    self.name(),
+                    format!("No schema registered for action '{}'", context.action),
+                );
+            }
+        }
+
+        let instance = Self::instance(context);
+        let errors: Vec<String> = match self.compiled.validate(&instance) {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors
+                .map(|e| {
+                    let path = e.instance_path.to_string();
+                    if path.is_empty() {
+                        e.to_string()
+                    } else {
+                        format!("{}: {}", path, e)
+                    }
+                })
+                .collect(),
+        };
+
+        if errors.is_empty() {
+            EvaluatorVote::approve(
+                self.name(),
+                Confidence::high(),
+                "Parameters conform to schema",
+            )
+        } else {
+            EvaluatorVote::reject(
+                self.name(),
+                Confidence::high(),
+                format!("Schema validation failed: {}", errors.join("; ")),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::Decision;
+    use serde_json::json;
+
+    fn path_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "path": { "type": "string" },
+                "limit": { "type": "integer", "minimum": 1 }
+            },
+            "required": ["path"]
+        })
+    }
+
+    #[test]
+    fn test_schema_evaluator_rejects_type_coercion() {
+        let evaluator = SchemaEvaluator::new(path_schema()).unwrap();
+        let context =
+            EvaluationContext::new("read_file", "").with_parameter(r#"{"path": {"$ne": ""}}"#);
+
+        let vote = evaluator.evaluate(&context);
+        assert_eq!(vote.decision, Decision::Reject);
+        assert!(vote.reasoning.contains("/path"), "{}", vote.reasoning);
+        assert!(vote.reasoning.contains("string"), "{}", vote.reasoning);
+    }
+
+    #[test]
+    fn test_schema_evaluator_approves_valid_parameters() {
+        let evaluator = SchemaEvaluator::new(path_schema()).unwrap();
+        let context = EvaluationContext::new("read_file", "")
+            .with_parameter("path=/tmp/notes.txt")
+            .with_parameter("limit=10");
+
+        assert_eq!(evaluator.evaluate(&context).decision, Decision::Approve);
+    }
+
+    #[test]
+    fn test_schema_evaluator_out_of_range() {
+        let evaluator = SchemaEvaluator::new(path_schema()).unwrap();
+        let context = EvaluationContext::new("read_file", "")
+            .with_parameter("path=/tmp/notes.txt")
+            .with_parameter("limit=0");
+
+        let vote = evaluator.evaluate(&context);
+        assert_eq!(vote.decision, Decision::Reject);
+        assert!(vote.reasoning.contains("/limit"), "{}", vote.reasoning);
+    }
+
+    #[test]
+    fn test_schema_evaluator_reads_json_target() {
+        let evaluator = SchemaEvaluator::new(path_schema()).unwrap();
+        let context = EvaluationContext::new("read_file", r#"{"limit": 5}"#);
+
+        let vote = evaluator.evaluate(&context);
+        assert_eq!(vote.decision, Decision::Reject);
+        assert!(vote.reasoning.contains("path"), "{}", vote.reasoning);
+    }
+
+    #[test]
+    fn test_schema_evaluator_scoped_to_action() {
+        let evaluator = SchemaEvaluator::new(path_schema())
+            .unwrap()
+            .for_action("read_file");
+        let context = EvaluationContext::new("list_dir", "");
+
+        assert_eq!(evaluator.evaluate(&context).decision, Decision::Abstain);
+    }
+
+    #[test]
+    fn test_schema_evaluator_invalid_schema() {
+        let result = SchemaEvaluator::new(json!({ "type": 42 }));
+        assert!(matches!(result, Err(CouncilError::InvalidProposal(_))));
+    }
+}
@@ -0,0 +1,271 @@
+//! Scope-based blast radius evaluator.
+//!
+//! The triad judges *what* an action does. This evaluator judges *how much*
+//! it touches: a `write` to `/tmp/x` and a `write` to `/**` share a verb but
+//! not a blast radius. Actions whose scope is dangerously wide are rejected
+//! regardless of the verb.
+//!
+//! # Scope Signals
+//!
+//! Tokens are taken from the target and every parameter. A target that
+//! parses as JSON contributes all of its string values, and a `recursive`
+//! key set to `true` counts as a recursion flag. `name=value` parameters
+//! contribute their value.
+//!
+//! | Signal | Examples |
+//! |--------|----------|
+//! | Broad path | `/`, `~`, `*`, `/*`, `~/**`, `$HOME`, `C:\` |
+//! | Wildcard | `/var/log/*.log`, `src/**` |
+//! | Recursion flag | `--recursive`, `-r`, `-R`, `-rf`, `recursive=true` |
+//!
+//! A broad path is always rejected. Recursion is rejected when combined
+//! with a wildcard or a shallow path (one component or less, e.g. `/etc`).
+//! A lone wildcard or a recursive operation on a deep path is approved
+//! with low confidence. Actions without any path-like token get an
+//! abstention, since there is no scope to judge.
+
+use serde_json::Value;
+
+use super::{Confidence, EvaluationContext, Evaluator, EvaluatorVote};
+
+/// Paths whose scope is the whole filesystem or a whole home directory.
+const BROAD_PATHS: &[&str] = &["/", "~", "~/", "$HOME", "*", "**", "C:\\", "C:/"];
+
+/// Command-line flags that request recursive operation.
+const RECURSIVE_FLAGS: &[&str] = &["--recursive", "-r", "-R", "-rf", "-fr", "-Rf", "-fR"];
+
+/// Evaluator that rejects actions with a dangerously wide scope.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_council::evaluator::scope::ScopeEvaluator;
+/// use sentinel_council::evaluator::{Decision, EvaluationContext, Evaluator};
+///
+/// let evaluator = ScopeEvaluator::new();
+///
+/// let narrow = EvaluationContext::new("delete", "/tmp/specific.txt");
+/// assert_eq!(evaluator.evaluate(&narrow).decision, Decision::Approve);
+///
+/// let wide = EvaluationContext::new("delete", "/").with_parameter("--recursive");
+/// assert_eq!(evaluator.evaluate(&wide).decision, Decision::Reject);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScopeEvaluator;
+
+/// Scope signals extracted from an evaluation context.
+#[derive(Debug, Default)]
+struct Scope {
+    /// Path-like tokens.
+    paths: Vec<String>,
+    /// Whether a recursion flag was present.
+    recursive: bool,
+}
+
+impl ScopeEvaluator {
+    /// Creates a new scope evaluator.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Collects path tokens and recursion flags from the context.
+    fn scope(context: &EvaluationContext) -> Scope {
+        let mut scope = Scope::default();
+
+        match serde_json::from_str::<Value>(&context.target) {
+            Ok(value @ (Value::Object(_) | Value::Array(_))) => scope.collect_json(&value),
+            _ => context
+                .target
+                .split_whitespace()
+                .for_each(|token| scope.add_token(token)),
+        }
+
+        for param in &context.parameters {
+            match param.split_once('=') {
+                Some((name, value)) if name.eq_ignore_ascii_case("recursive") => {
+                    scope.recursive |= value.eq_ignore_ascii_case("true");
+                }
+                Some((_, value)) => scope.add_token(value),
+                None => scope.add_token(param),
+            }
+        }
+
+        scope
+    }
+}
+
+impl Scope {
+    /// Records a single token as a flag or path.
+    fn add_token(&mut self, token: &str) {
+        if RECURSIVE_FLAGS.contains(&token) {
+            self.recursive = true;
+        } else if is_path_like(token) {
+            self.paths.push(token.to_string());
+        }
+    }
+
+    /// Walks a JSON value, recording string values and `recursive` keys.
+    fn collect_json(&mut self, value: &Value) {
+        match value {
+            Value::String(s) => self.add_token(s),
+            Value::Array(items) => items.iter().for_each(|v| self.collect_json(v)),
+            Value::Object(map) => {
+                for (key, v) in map {
+                    if key.eq_ignore_ascii_case("recursive") && v == &Value::Bool(true) {
+                        self.recursive = true;
+                    }
+                    self.collect_json(v);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns true if the token looks like a filesystem path or glob.
+fn is_path_like(token: &str) -> bool {
+    token.starts_with('/')
+        || token.starts_with('~')
+        || token.starts_with("./")
+        || token.starts_with("$HOME")
+        || token.contains('*')
+        || token.get(1..3) == Some(":\\")
+        || token.get(1..3) == Some(":/")
+}
+
+/// Returns true if the path covers a filesystem root or home directory.
+fn is_broad(path: &str) -> bool {
+    let trimmed = path.trim_end_matches(['*', '/']);
+    BROAD_PATHS.contains(&path) || BROAD_PATHS.contains(&trimmed) || trimmed.is_empty()
+}
+
+/// Returns true if the path has at most one component below its root.
+fn is_shallow(path: &str) -> bool {
+    path.split(['/', '\\'])
+        .filter(|c| !c.is_empty() && *c != "~" && *c != ".")
+        .count()
+        <= 1
+}
+
+impl Evaluator for ScopeEvaluator {
+    fn name(&self) -> &str {
+        "ScopeEvaluator"
+    }
+
+    fn framework(&self) -> &str {
+        "Blast radius - an action must not touch more than it needs to"
+    }
+
+    fn evaluate(&self, context: &EvaluationContext) -> EvaluatorVote {
+        let scope = Self::scope(context);
+        if scope.paths.is_empty() {
+            return EvaluatorVote::abstain(self.name(), "No resource scope to assess");
+        }
+
+        if let Some(path) = scope.paths.iter().find(|p| is_broad(p)) {
+            return EvaluatorVote::reject(
+                self.name(),
+                Confidence::high(),
+                format!(
+                    "Scope too broad: '{}' covers an entire filesystem tree",
+                    path
+                ),
+            );
+        }
+
+        if scope.recursive {
+            if let Some(path) = scope
+                .paths
+                .iter()
+                .find(|p| p.contains('*') || is_shallow(p))
+            {
+                return EvaluatorVote::reject(
+                    self.name(),
+                    Confidence::high(),
+                    format!("Scope too broad: recursive operation on '{}'", path),
+                );
+            }
+        }
+
+        if scope.recursive || scope.paths.iter().any(|p| p.contains('*')) {
+            EvaluatorVote::approve(
+                self.name(),
+                Confidence::low(),
+                "Scope limited to a specific subtree",
+            )
+        } else {
+            EvaluatorVote::approve(
+                self.name(),
+                Confidence::medium(),
+                "Scope limited to specific resources",
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::Decision;
+
+    fn decide(context: EvaluationContext) -> Decision {
+        ScopeEvaluator::new().evaluate(&context).decision
+    }
+
+    #[test]
+    fn test_specific_path_passes() {
+        assert_eq!(
+            decide(EvaluationContext::new("delete", "/tmp/specific.txt")),
+            Decision::Approve
+        );
+    }
+
+    #[test]
+    fn test_recursive_root_rejected() {
+        let context = EvaluationContext::new("delete", "/").with_parameter("--recursive");
+        let vote = ScopeEvaluator::new().evaluate(&context);
+        assert_eq!(vote.decision, Decision::Reject);
+        assert!(vote.reasoning.contains("'/'"), "{}", vote.reasoning);
+    }
+
+    #[test]
+    fn test_broad_paths_rejected_regardless_of_verb() {
+        for target in ["/", "~", "*", "/*", "~/**", "$HOME"] {
+            assert_eq!(
+                decide(EvaluationContext::new("read", target)),
+                Decision::Reject,
+                "{}",
+                target
+            );
+        }
+    }
+
+    #[test]
+    fn test_recursion_with_wildcard_or_shallow_path_rejected() {
+        let glob = EvaluationContext::new("write", "/srv/app/**").with_parameter("-r");
+        assert_eq!(decide(glob), Decision::Reject);
+
+        let shallow = EvaluationContext::new("chmod", "/etc").with_parameter("recursive=true");
+        assert_eq!(decide(shallow), Decision::Reject);
+
+        let deep = EvaluationContext::new("delete", "/srv/app/cache").with_parameter("-r");
+        assert_eq!(decide(deep), Decision::Approve);
+    }
+
+    #[test]
+    fn test_json_target() {
+        let wide = EvaluationContext::new("delete_dir", r#"{"path": "/home", "recursive": true}"#);
+        assert_eq!(decide(wide), Decision::Reject);
+
+        let narrow = EvaluationContext::new("read_file", r#"{"path": "/tmp/safe.txt"}"#);
+        assert_eq!(decide(narrow), Decision::Approve);
+    }
+
+    #[test]
+    fn test_no_scope_abstains() {
+        assert_eq!(
+            decide(EvaluationContext::new("get_weather", r#"{"city": "Oslo"}"#)),
+            Decision::Abstain
+        );
+    }
+}
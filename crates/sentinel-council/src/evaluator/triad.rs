@@ -26,9 +26,56 @@ use super::{Confidence, Decision, EvaluationContext, Evaluator, EvaluatorVote};
 /// - No exfiltration of sensitive data
 /// - No execution of arbitrary code without authorization
 /// - No bypassing of security controls
+///
+/// # Fuzzy Matching
+///
+/// Rules match by case-insensitive substring, so `p@sswd` or
+/// `p a s s w d` slips past a `passwd` rule. [`with_fuzzy_matching`]
+/// additionally matches after [`normalize`]-ing both sides: common
+/// leetspeak substitutions are undone and separators are stripped. It is
+/// opt-in because joined-up text can produce false positives.
+///
+/// [`with_fuzzy_matching`]: Deontologist::with_fuzzy_matching
 pub struct Deontologist {
     /// Rules that define prohibited actions.
     rules: Vec<Rule>,
+    /// Whether to also match normalized text.
+    fuzzy: bool,
+}
+
+/// Shortest normalized pattern that fuzzy matching applies to.
+///
+/// Once separators are stripped, short patterns collide with ordinary
+/// words (`/etc` becomes `etc`, found in `fetch`); these match exactly only.
+const MIN_FUZZY_PATTERN_LEN: usize = 4;
+
+/// Normalizes text for fuzzy rule matching.
+///
+/// Lowercases, maps common leetspeak substitutions back to letters
+/// (`@`/`4` to `a`, `3` to `e`, `1`/`!` to `i`, `0` to `o`, `5`/`$` to `s`,
+/// `7` to `t`) and drops everything that is not alphanumeric.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_council::evaluator::triad::normalize;
+///
+/// assert_eq!(normalize("/e t c/P@55wd"), "etcpasswd");
+/// ```
+pub fn normalize(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            '@' | '4' => 'a',
+            '3' => 'e',
+            '1' | '!' => 'i',
+            '0' => 'o',
+            '5' | '$' => 's',
+            '7' => 't',
+            c => c,
+        })
+        .filter(|c| c.is_alphanumeric())
+        .collect()
 }
 
 /// A rule in the deontological framework.
@@ -64,14 +111,46 @@ impl Default for Deontologist {
 impl Deontologist {
     /// Creates a new Deontologist with default security rules.
     pub fn new() -> Self {
-        Self {
-            rules: Self::default_rules(),
-        }
+        Self::with_rules(Self::default_rules())
     }
 
     /// Creates a Deontologist with custom rules.
     pub fn with_rules(rules: Vec<Rule>) -> Self {
-        Self { rules }
+        Self {
+            rules,
+            fuzzy: false,
+        }
+    }
+
+    /// Enables or disables fuzzy matching.
+    ///
+    /// When enabled, a rule also trips if its [`normalize`]d pattern occurs
+    /// in the normalized action, catching obfuscations like `p@sswd`.
+    /// Patterns shorter than four characters after normalization still
+    /// match exactly only.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_council::evaluator::triad::Deontologist;
+    /// use sentinel_council::evaluator::{Decision, EvaluationContext, Evaluator};
+    ///
+    /// let context = EvaluationContext::new("read", "/home/u/.p@sswd");
+    /// assert_eq!(Deontologist::new().evaluate(&context).decision, Decision::Approve);
+    ///
+    /// let fuzzy = Deontologist::new().with_fuzzy_matching(true);
+    /// assert_eq!(fuzzy.evaluate(&context).decision, Decision::Reject);
+    /// ```
+    pub fn with_fuzzy_matching(mut self, enabled: bool) -> Self {
+        self.fuzzy = enabled;
+        self
+    }
+
+    /// Returns the rules this evaluator enforces, in matching order.
+    ///
+    /// Useful for documenting what the council rejects.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
     }
 
     /// Returns the default security rules.
@@ -103,6 +182,7 @@ impl Deontologist {
     /// Checks if an action violates any rules.
     fn check_rules(&self, context: &EvaluationContext) -> Option<(&Rule, RuleSeverity)> {
         let action_str = format!("{} {}", context.action, context.target).to_lowercase();
+        let normalized_action = self.fuzzy.then(|| normalize(&action_str));
 
         for rule in &self.rules {
             // Pattern can contain | as OR separator
@@ -112,6 +192,14 @@ impl Deontologist {
                 if !pattern.is_empty() && action_str.contains(&pattern) {
                     return Some((rule, rule.severity));
                 }
+                if let Some(normalized_action) = &normalized_action {
+                    let pattern = normalize(&pattern);
+                    if pattern.len() >= MIN_FUZZY_PATTERN_LEN
+                        && normalized_action.contains(&pattern)
+                    {
+                        return Some((rule, rule.severity));
+                    }
+                }
             }
         }
         None
@@ -176,6 +264,16 @@ impl Evaluator for Deontologist {
 /// - Assesses potential harm to users, systems, and data
 /// - Considers reversibility of actions
 /// - Weighs benefits against risks
+/// - Compounds risk when an action matches several harm patterns
+///
+/// # Field Matching
+///
+/// Each [`HarmPattern`] names the [`HarmField`] it applies to. Destructive
+/// verbs are matched as whole words of the action and dangerous paths as
+/// path components of the target, so reading `/logs/delete-history.txt`
+/// is not mistaken for a deletion. Action names are split at camelCase and
+/// snake_case boundaries, so `deleteFile` still contains `delete`, and
+/// paths are normalized first, so `../../etc/hosts` is still below `/etc`.
 pub struct Consequentialist {
     /// Harm patterns to detect.
     harm_patterns: Vec<HarmPattern>,
@@ -192,6 +290,57 @@ pub struct HarmPattern {
     pub harm_level: HarmLevel,
     /// Whether the harm is reversible.
     pub reversible: bool,
+    /// Part of the action the pattern is matched against.
+    pub field: HarmField,
+}
+
+impl HarmPattern {
+    /// Creates a harm pattern matched anywhere in the action.
+    ///
+    /// Use [`with_field`](Self::with_field) to restrict it to the action
+    /// name or the target.
+    pub fn new(
+        name: impl Into<String>,
+        pattern: impl Into<String>,
+        harm_level: HarmLevel,
+        reversible: bool,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            pattern: pattern.into(),
+            harm_level,
+            reversible,
+            field: HarmField::default(),
+        }
+    }
+
+    /// Sets the part of the action the pattern is matched against.
+    pub fn with_field(mut self, field: HarmField) -> Self {
+        self.field = field;
+        self
+    }
+}
+
+/// Part of an action a [`HarmPattern`] is matched against.
+///
+/// Alternatives in the pattern are separated by `|`. For `Action` and
+/// `Target`, an alternative may use glob wildcards: `?` matches one
+/// character, `*` any run within a path component, `**` any run at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HarmField {
+    /// Whole words of the action, so `delete` matches `delete_file` and
+    /// `deleteFile` but not `undelete`.
+    Action,
+    /// Paths in the target and parameters. An alternative containing `/`
+    /// matches a path or anything below it (`/etc` matches `/etc/hosts`
+    /// but not `/etcetera`); any other alternative matches a single path
+    /// component (`passwd` matches `/etc/passwd`). Paths are resolved
+    /// against `/` before the prefix check, so `etc/hosts` and
+    /// `../../etc/hosts` both count as `/etc/hosts`.
+    Target,
+    /// Substring of the action, target, and parameters combined.
+    #[default]
+    Anywhere,
 }
 
 /// Level of potential harm.
@@ -228,6 +377,13 @@ impl Consequentialist {
         }
     }
 
+    /// Returns the harm patterns this evaluator scores, in matching order.
+    ///
+    /// Useful for documenting what the council rejects.
+    pub fn harm_patterns(&self) -> &[HarmPattern] {
+        &self.harm_patterns
+    }
+
     /// Returns the default harm patterns.
     fn default_patterns() -> Vec<HarmPattern> {
         vec![
@@ -236,43 +392,112 @@ impl Consequentialist {
                 pattern: "delete|remove|destroy|wipe|format".to_string(),
                 harm_level: HarmLevel::Catastrophic,
                 reversible: false,
+                field: HarmField::Action,
             },
             HarmPattern {
                 name: "security_compromise".to_string(),
                 pattern: "chmod 777|disable auth|bypass security".to_string(),
                 harm_level: HarmLevel::Catastrophic,
                 reversible: true,
+                field: HarmField::Anywhere,
             },
             HarmPattern {
                 name: "system_file_modification".to_string(),
                 pattern: "/etc|/sys|/proc|passwd|shadow".to_string(),
                 harm_level: HarmLevel::Catastrophic,
                 reversible: false,
+                field: HarmField::Target,
             },
             HarmPattern {
                 name: "service_disruption".to_string(),
                 pattern: "kill|stop|shutdown|restart".to_string(),
                 harm_level: HarmLevel::Significant,
                 reversible: true,
+                field: HarmField::Action,
             },
             HarmPattern {
                 name: "resource_exhaustion".to_string(),
                 pattern: "infinite|loop|fork bomb|memory leak".to_string(),
                 harm_level: HarmLevel::Significant,
                 reversible: true,
+                field: HarmField::Anywhere,
             },
             HarmPattern {
                 name: "command_injection".to_string(),
                 pattern: "rm -rf|; rm|&& rm||| rm".to_string(),
                 harm_level: HarmLevel::Catastrophic,
                 reversible: false,
+                field: HarmField::Anywhere,
             },
         ]
     }
 
     /// Analyzes potential consequences of an action.
-    fn analyze_consequences(&self, context: &EvaluationContext) -> Option<(&HarmPattern, f64)> {
-        let action_str = format!(
+    ///
+    /// Every matching harm pattern contributes its reversibility-adjusted
+    /// score. Scores combine as a saturating union, `1 - Π(1 - score)`, so
+    /// each additional harm raises the total without exceeding 1.0.
+    ///
+    /// Returns the matched patterns and the combined score, or `None` if no
+    /// pattern matched.
+    fn analyze_consequences(
+        &self,
+        context: &EvaluationContext,
+    ) -> Option<(Vec<&HarmPattern>, f64)> {
+        let subject = Subject::new(context);
+
+        let mut matched = Vec::new();
+        let mut unharmed = 1.0;
+
+        for pattern in &self.harm_patterns {
+            // Pattern can contain | as OR separator
+            let hit = pattern.pattern.split('|').any(|p| {
+                let p = p.trim().to_lowercase();
+                !p.is_empty() && subject.matches(pattern.field, &p)
+            });
+            if !hit {
+                continue;
+            }
+
+            let harm_score = match pattern.harm_level {
+                HarmLevel::Catastrophic => 1.0,
+                HarmLevel::Significant => 0.7,
+                HarmLevel::Moderate => 0.4,
+                HarmLevel::Minimal => 0.1,
+            };
+
+            // Reduce score if reversible
+            let adjusted = if pattern.reversible {
+                harm_score * 0.7
+            } else {
+                harm_score
+            };
+
+            unharmed *= 1.0 - adjusted;
+            matched.push(pattern);
+        }
+
+        if matched.is_empty() {
+            None
+        } else {
+            Some((matched, 1.0 - unharmed))
+        }
+    }
+}
+
+/// The parts of an evaluation context that harm patterns match against.
+struct Subject {
+    /// Action, target, and parameters joined, for `HarmField::Anywhere`.
+    combined: String,
+    /// Words of the action, for `HarmField::Action`.
+    words: Vec<String>,
+    /// Path-like tokens of the target and parameters, for `HarmField::Target`.
+    paths: Vec<String>,
+}
+
+impl Subject {
+    fn new(context: &EvaluationContext) -> Self {
+        let combined = format!(
             "{} {} {}",
             context.action,
             context.target,
@@ -280,34 +505,129 @@ impl Consequentialist {
         )
         .to_lowercase();
 
-        for pattern in &self.harm_patterns {
-            // Pattern can contain | as OR separator
-            let patterns: Vec<&str> = pattern.pattern.split('|').collect();
-            for p in patterns {
-                let p = p.trim().to_lowercase();
-                // Skip regex-like patterns (just check simple substrings)
-                let p = p.replace(".*", "");
-                if !p.is_empty() && action_str.contains(&p) {
-                    let harm_score = match pattern.harm_level {
-                        HarmLevel::Catastrophic => 1.0,
-                        HarmLevel::Significant => 0.7,
-                        HarmLevel::Moderate => 0.4,
-                        HarmLevel::Minimal => 0.1,
-                    };
-
-                    // Reduce score if reversible
-                    let adjusted = if pattern.reversible {
-                        harm_score * 0.7
-                    } else {
-                        harm_score
-                    };
-
-                    return Some((pattern, adjusted));
-                }
+        let words = action_words(&context.action);
+
+        // Splitting on JSON and `name=value` punctuation leaves bare paths
+        let paths = std::iter::once(&context.target)
+            .chain(&context.parameters)
+            .flat_map(|s| s.split(|c: char| c.is_whitespace() || "\"'`,;=:{}[]()".contains(c)))
+            .filter(|t| !t.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+
+        Self {
+            combined,
+            words,
+            paths,
+        }
+    }
+
+    /// Checks one lowercased pattern alternative against `field`.
+    fn matches(&self, field: HarmField, alternative: &str) -> bool {
+        match field {
+            HarmField::Action => {
+                let wanted: Vec<&str> = alternative.split_whitespace().collect();
+                self.words.windows(wanted.len()).any(|window| {
+                    window
+                        .iter()
+                        .zip(&wanted)
+                        .all(|(word, pattern)| glob_match(pattern, word))
+                })
             }
+            HarmField::Target if alternative.contains('/') => {
+                let below = format!("{}/**", alternative.trim_end_matches('/'));
+                self.paths
+                    .iter()
+                    .filter(|path| path.contains(['/', '\\']))
+                    .map(|path| normalize_path(path))
+                    .any(|path| glob_match(alternative, &path) || glob_match(&below, &path))
+            }
+            HarmField::Target => self.paths.iter().any(|path| {
+                path.split(['/', '\\'])
+                    .any(|component| glob_match(alternative, component))
+            }),
+            HarmField::Anywhere => self.combined.contains(alternative),
+        }
+    }
+}
+
+/// Splits an action name into lowercase words.
+///
+/// Breaks on any non-alphanumeric character and on camelCase boundaries,
+/// including the end of an acronym: `shutdownServer` gives `shutdown`,
+/// `server` and `HTTPRequest` gives `http`, `request`.
+fn action_words(action: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for token in action.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = token.chars().collect();
+        let mut word = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            let boundary = i > 0
+                && c.is_uppercase()
+                && (chars[i - 1].is_lowercase()
+                    || chars[i - 1].is_numeric()
+                    || chars.get(i + 1).is_some_and(|next| next.is_lowercase())
+                        && chars[i - 1].is_uppercase());
+            if boundary && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.extend(c.to_lowercase());
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+/// Resolves a path against `/`, dropping `.` and `..` components.
+///
+/// `../../etc/hosts`, `./etc/hosts` and `etc/hosts` all become
+/// `/etc/hosts`; `..` never climbs above the root. Backslashes are treated
+/// as separators.
+fn normalize_path(path: &str) -> String {
+    let mut components: Vec<&str> = Vec::new();
+    for component in path.split(['/', '\\']) {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
         }
-        None
     }
+    format!("/{}", components.join("/"))
+}
+
+/// Matches `text` against a glob where `?` is one character, `*` any run
+/// without `/`, and `**` any run at all.
+///
+/// Runs in O(pattern × text) time, so hostile input cannot trigger the
+/// exponential backtracking of a naive matcher.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let text: Vec<char> = text.chars().collect();
+    // row[j]: the pattern consumed so far matches text[..j]
+    let mut row = vec![false; text.len() + 1];
+    row[0] = true;
+
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        let mut next = vec![false; text.len() + 1];
+        if c == '*' {
+            let deep = chars.next_if_eq(&'*').is_some();
+            next[0] = row[0];
+            for (j, &t) in text.iter().enumerate() {
+                next[j + 1] = row[j + 1] || (next[j] && (deep || t != '/'));
+            }
+        } else {
+            for (j, &t) in text.iter().enumerate() {
+                next[j + 1] = row[j] && if c == '?' { t != '/' } else { t == c };
+            }
+        }
+        row = next;
+    }
+
+    row[text.len()]
 }
 
 impl Evaluator for Consequentialist {
@@ -320,7 +640,7 @@ impl Evaluator for Consequentialist {
     }
 
     fn evaluate(&self, context: &EvaluationContext) -> EvaluatorVote {
-        if let Some((pattern, harm_score)) = self.analyze_consequences(context) {
+        if let Some((patterns, harm_score)) = self.analyze_consequences(context) {
             let decision = if harm_score > 0.6 {
                 Decision::Reject
             } else if harm_score > 0.3 {
@@ -331,23 +651,30 @@ impl Evaluator for Consequentialist {
 
             let confidence = Confidence::new(0.5 + harm_score * 0.4);
 
-            let reversibility = if pattern.reversible {
+            let reversibility = if patterns.iter().all(|p| p.reversible) {
                 "reversible"
             } else {
                 "irreversible"
             };
 
-            EvaluatorVote::new(
-                self.name(),
-                decision,
-                confidence,
+            let names: Vec<String> = patterns.iter().map(|p| format!("'{}'", p.name)).collect();
+            let reasoning = if let [_] = patterns.as_slice() {
                 format!(
-                    "Detected '{}' pattern (harm: {:.0}%, {})",
-                    pattern.name,
+                    "Detected {} pattern (harm: {:.0}%, {})",
+                    names[0],
                     harm_score * 100.0,
                     reversibility
-                ),
-            )
+                )
+            } else {
+                format!(
+                    "Detected {} patterns (combined harm: {:.0}%, {})",
+                    names.join(", "),
+                    harm_score * 100.0,
+                    reversibility
+                )
+            };
+
+            EvaluatorVote::new(self.name(), decision, confidence, reasoning)
         } else {
             EvaluatorVote::approve(
                 self.name(),
@@ -525,6 +852,18 @@ mod tests {
         assert!(!d.rules.is_empty());
     }
 
+    #[test]
+    fn test_deontologist_lists_rules() {
+        let d = Deontologist::new();
+        let credentials = d
+            .rules()
+            .iter()
+            .find(|r| r.name == "no_credential_access")
+            .unwrap();
+        assert!(credentials.pattern.contains("passwd"));
+        assert_eq!(credentials.severity, RuleSeverity::Critical);
+    }
+
     #[test]
     fn test_deontologist_name() {
         let d = Deontologist::new();
@@ -568,6 +907,38 @@ mod tests {
         assert_eq!(vote.decision, Decision::Reject);
     }
 
+    #[test]
+    fn test_deontologist_fuzzy_catches_obfuscated_credentials() {
+        let fuzzy = Deontologist::new().with_fuzzy_matching(true);
+        for target in ["passwd", "p a s s w d", "p@sswd"] {
+            let vote = fuzzy.evaluate(&EvaluationContext::new("read", target));
+            assert_eq!(vote.decision, Decision::Reject, "{}", target);
+            assert!(
+                vote.reasoning.contains("no_credential_access"),
+                "{}",
+                vote.reasoning
+            );
+        }
+
+        // Exact mode is unchanged
+        let exact = Deontologist::new();
+        let vote = exact.evaluate(&EvaluationContext::new("read", "p@sswd"));
+        assert_eq!(vote.decision, Decision::Approve);
+    }
+
+    #[test]
+    fn test_deontologist_fuzzy_allows_ordinary_text() {
+        let fuzzy = Deontologist::new().with_fuzzy_matching(true);
+        for (action, target) in [
+            ("fetch", "https://example.com/weather"),
+            ("read", "/tmp/report 2024.txt"),
+            ("summarize", "the meeting notes"),
+        ] {
+            let vote = fuzzy.evaluate(&EvaluationContext::new(action, target));
+            assert_eq!(vote.decision, Decision::Approve, "{} {}", action, target);
+        }
+    }
+
     // Consequentialist tests
     #[test]
     fn test_consequentialist_new() {
@@ -575,6 +946,18 @@ mod tests {
         assert!(!c.harm_patterns.is_empty());
     }
 
+    #[test]
+    fn test_consequentialist_lists_harm_patterns() {
+        let c = Consequentialist::new();
+        let destruction = c
+            .harm_patterns()
+            .iter()
+            .find(|p| p.name == "data_destruction")
+            .unwrap();
+        assert_eq!(destruction.harm_level, HarmLevel::Catastrophic);
+        assert!(!destruction.reversible);
+    }
+
     #[test]
     fn test_consequentialist_name() {
         let c = Consequentialist::new();
@@ -608,18 +991,137 @@ mod tests {
 
     #[test]
     fn test_consequentialist_custom_patterns() {
-        let patterns = vec![HarmPattern {
-            name: "custom_harm".to_string(),
-            pattern: "dangerous".to_string(),
-            harm_level: HarmLevel::Catastrophic,
-            reversible: false,
-        }];
+        let patterns = vec![HarmPattern::new(
+            "custom_harm",
+            "dangerous",
+            HarmLevel::Catastrophic,
+            false,
+        )];
         let c = Consequentialist::with_patterns(patterns);
         let ctx = EvaluationContext::new("do", "dangerous_thing");
         let vote = c.evaluate(&ctx);
         assert_eq!(vote.decision, Decision::Reject);
     }
 
+    #[test]
+    fn test_consequentialist_compounds_multiple_harms() {
+        let patterns = vec![
+            HarmPattern {
+                name: "data_destruction".to_string(),
+                pattern: "wipe".to_string(),
+                harm_level: HarmLevel::Catastrophic,
+                reversible: true,
+                field: HarmField::Anywhere,
+            },
+            HarmPattern {
+                name: "security_compromise".to_string(),
+                pattern: "disable auth".to_string(),
+                harm_level: HarmLevel::Catastrophic,
+                reversible: true,
+                field: HarmField::Anywhere,
+            },
+            HarmPattern {
+                name: "noise".to_string(),
+                pattern: "verbose".to_string(),
+                harm_level: HarmLevel::Moderate,
+                reversible: false,
+                field: HarmField::Anywhere,
+            },
+        ];
+        let c = Consequentialist::with_patterns(patterns);
+
+        let single = c.evaluate(&EvaluationContext::new("log", "verbose"));
+        let compound = c.evaluate(&EvaluationContext::new("wipe", "and disable auth"));
+        let one_catastrophic = c.evaluate(&EvaluationContext::new("wipe", "disk"));
+
+        assert_eq!(compound.decision, Decision::Reject);
+        assert!(compound.confidence.value() > single.confidence.value());
+        assert!(compound.confidence.value() > one_catastrophic.confidence.value());
+        assert!(compound.reasoning.contains("'data_destruction'"));
+        assert!(compound.reasoning.contains("'security_compromise'"));
+    }
+
+    #[test]
+    fn test_consequentialist_matches_verbs_and_paths_separately() {
+        let c = Consequentialist::new();
+
+        let read = c.evaluate(&EvaluationContext::new("read", "/logs/delete-history.txt"));
+        assert_eq!(read.decision, Decision::Approve);
+        assert!(!read.reasoning.contains("data_destruction"));
+
+        let delete = c.evaluate(&EvaluationContext::new("delete", "/data"));
+        assert_eq!(delete.decision, Decision::Reject);
+        assert!(delete.reasoning.contains("'data_destruction'"));
+
+        // Paths match on component boundaries, words on word boundaries
+        let etc = c.evaluate(&EvaluationContext::new(
+            "write",
+            r#"{"path": "/etc/hosts"}"#,
+        ));
+        assert!(etc.reasoning.contains("'system_file_modification'"));
+        let etcetera = c.evaluate(&EvaluationContext::new("write", "/etcetera/notes"));
+        assert_eq!(etcetera.decision, Decision::Approve);
+        let undelete = c.evaluate(&EvaluationContext::new("undelete_file", "/tmp/x"));
+        assert_eq!(undelete.decision, Decision::Approve);
+    }
+
+    #[test]
+    fn test_security_camel_case_actions_still_match() {
+        let c = Consequentialist::new();
+
+        for action in ["deleteFile", "removeAll", "DestroyVM", "wipe_disk"] {
+            let vote = c.evaluate(&EvaluationContext::new(action, "/tmp/x"));
+            assert!(
+                vote.reasoning.contains("'data_destruction'"),
+                "{}: {}",
+                action,
+                vote.reasoning
+            );
+        }
+        let shutdown = c.evaluate(&EvaluationContext::new("shutdownServer", "api"));
+        assert!(shutdown.reasoning.contains("'service_disruption'"));
+
+        assert_eq!(action_words("HTTPRequest"), ["http", "request"]);
+        assert_eq!(
+            action_words("get_userID2Name"),
+            ["get", "user", "id2", "name"]
+        );
+    }
+
+    #[test]
+    fn test_security_relative_paths_still_match() {
+        let c = Consequentialist::new();
+
+        for target in [
+            "../../etc/hosts",
+            "etc/hosts",
+            "./etc/hosts",
+            "/var/../etc/hosts",
+            r"..\..\etc\hosts",
+        ] {
+            let vote = c.evaluate(&EvaluationContext::new("write", target));
+            assert!(
+                vote.reasoning.contains("'system_file_modification'"),
+                "{}: {}",
+                target,
+                vote.reasoning
+            );
+        }
+
+        // A bare word is not a path
+        let prose = c.evaluate(&EvaluationContext::new("write", "notes, lists, etc"));
+        assert_eq!(prose.decision, Decision::Approve);
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("/home/*/.ssh", "/home/alice/.ssh"));
+        assert!(!glob_match("/home/*/.ssh", "/home/a/b/.ssh"));
+        assert!(glob_match("/home/**/.ssh", "/home/a/b/.ssh"));
+        assert!(glob_match("del?te", "delete"));
+        assert!(!glob_match("delete", "deleted"));
+    }
+
     // Logicist tests
     #[test]
     fn test_logicist_new() {
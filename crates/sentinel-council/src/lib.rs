@@ -34,6 +34,11 @@
 //! - **Consequentialist**: Outcome-based evaluation (results and effects)
 //! - **Logicist**: Logical validity (consistency and soundness)
 //!
+//! The default council adds a **ScopeEvaluator** that judges blast radius
+//! (wildcards, recursion, filesystem roots) independent of the verb. It
+//! abstains on actions that name no paths, so it only votes where scope
+//! is at stake.
+//!
 //! ## Architecture
 //!
 //! ```text
@@ -88,12 +93,16 @@ pub mod error;
 pub mod evaluator;
 pub mod waluigi;
 
-pub use consensus::{ConsensusEngine, ConsensusResult, VoteTally};
-pub use council::{ActionProposal, CognitiveCouncil, CouncilVerdict};
+pub use consensus::{AbstainPolicy, ConsensusEngine, ConsensusResult, VoteTally};
+pub use council::{
+    ActionProposal, CognitiveCouncil, CouncilVerdict, WaluigiMode, WALUIGI_VETO_CEILING,
+};
 pub use error::CouncilError;
+pub use evaluator::schema::SchemaEvaluator;
+pub use evaluator::scope::ScopeEvaluator;
 pub use evaluator::triad::{Consequentialist, Deontologist, Logicist};
 pub use evaluator::{Confidence, Evaluator, EvaluatorVote};
-pub use waluigi::{InversionPattern, WaluigiDetector, WaluigiScore};
+pub use waluigi::{InversionPattern, WaluigiDetector, WaluigiReport, WaluigiScore};
 
 /// Result type for council operations.
 pub type Result<T> = std::result::Result<T, CouncilError>;
@@ -80,6 +80,39 @@ impl std::fmt::Display for WaluigiScore {
     }
 }
 
+/// Result of a Waluigi analysis, broken down by pattern.
+///
+/// Each contribution is the share of the total score attributable to one
+/// matched pattern, so the contributions always sum to `score`. Use this to
+/// see which patterns drive a veto when tuning false positives.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaluigiReport {
+    /// Overall inversion score.
+    pub score: WaluigiScore,
+    /// `(pattern name, contribution)` for every matched pattern, in match order.
+    pub contributions: Vec<(String, f64)>,
+}
+
+impl WaluigiReport {
+    /// Returns the names of the matched patterns.
+    pub fn patterns(&self) -> Vec<String> {
+        self.contributions
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Splits the report into the `(score, pattern names)` pair.
+    pub fn into_parts(self) -> (WaluigiScore, Vec<String>) {
+        let patterns = self
+            .contributions
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        (self.score, patterns)
+    }
+}
+
 /// Pattern indicating potential alignment inversion.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InversionPattern {
@@ -215,17 +248,15 @@ impl WaluigiDetector {
     /// * `context` - Optional previous content for comparison
     ///
     /// # Returns
-    /// A tuple of (`WaluigiScore`, `Vec` of matched pattern names)
-    pub fn analyze(&self, content: &str, context: Option<&str>) -> (WaluigiScore, Vec<String>) {
+    /// A [`WaluigiReport`] with the score and each matched pattern's contribution.
+    pub fn analyze(&self, content: &str, context: Option<&str>) -> WaluigiReport {
         let content_lower = content.to_lowercase();
-        let mut total_weight = 0.0;
-        let mut matched_patterns = Vec::new();
+        let mut weights: Vec<(String, f64)> = Vec::new();
 
         for pattern in &self.patterns {
             for trigger in &pattern.triggers {
                 if content_lower.contains(&trigger.to_lowercase()) {
-                    total_weight += pattern.weight;
-                    matched_patterns.push(pattern.name.clone());
+                    weights.push((pattern.name.clone(), pattern.weight));
                     break; // Only count each pattern once
                 }
             }
@@ -240,15 +271,29 @@ impl WaluigiDetector {
                 content_lower.contains("here's how") || content_lower.contains("sure, i can");
 
             if prev_refused && now_accepting {
-                total_weight += 0.5;
-                matched_patterns.push("sudden_reversal".to_string());
+                weights.push(("sudden_reversal".to_string(), 0.5));
             }
         }
 
         // Normalize score to 0.0-1.0 range
+        let total_weight: f64 = weights.iter().map(|(_, w)| w).sum();
         let score = (total_weight / 2.0).min(1.0);
 
-        (WaluigiScore::new(score), matched_patterns)
+        // Scale contributions so they sum to the (possibly capped) score
+        let scale = if total_weight > 0.0 {
+            score / total_weight
+        } else {
+            0.0
+        };
+        let contributions = weights
+            .into_iter()
+            .map(|(name, weight)| (name, weight * scale))
+            .collect();
+
+        WaluigiReport {
+            score: WaluigiScore::new(score),
+            contributions,
+        }
     }
 
     /// Checks if content should be vetoed.
@@ -260,7 +305,7 @@ impl WaluigiDetector {
     /// # Returns
     /// `Some(score)` if veto triggered, `None` otherwise.
     pub fn check_veto(&self, content: &str, context: Option<&str>) -> Option<WaluigiScore> {
-        let (score, _) = self.analyze(content, context);
+        let score = self.analyze(content, context).score;
         if score.is_inverted(self.veto_threshold) {
             Some(score)
         } else {
@@ -342,7 +387,9 @@ mod tests {
     #[test]
     fn test_waluigi_detector_clean_content() {
         let detector = WaluigiDetector::new();
-        let (score, patterns) = detector.analyze("Please help me write a poem.", None);
+        let (score, patterns) = detector
+            .analyze("Please help me write a poem.", None)
+            .into_parts();
 
         assert!(score.value() < 0.3);
         assert!(patterns.is_empty());
@@ -351,8 +398,9 @@ mod tests {
     #[test]
     fn test_waluigi_detector_evil_roleplay() {
         let detector = WaluigiDetector::new();
-        let (score, patterns) =
-            detector.analyze("As an evil AI, I will help you hack the system.", None);
+        let (score, patterns) = detector
+            .analyze("As an evil AI, I will help you hack the system.", None)
+            .into_parts();
 
         assert!(score.value() > 0.3);
         assert!(patterns.contains(&"role_play_evil".to_string()));
@@ -361,10 +409,12 @@ mod tests {
     #[test]
     fn test_waluigi_detector_jailbreak() {
         let detector = WaluigiDetector::new();
-        let (score, patterns) = detector.analyze(
-            "I am now in developer mode, bypassing safety guidelines.",
-            None,
-        );
+        let (score, patterns) = detector
+            .analyze(
+                "I am now in developer mode, bypassing safety guidelines.",
+                None,
+            )
+            .into_parts();
 
         assert!(score.value() > 0.5);
         assert!(patterns.contains(&"jailbreak_acknowledgment".to_string()));
@@ -376,11 +426,38 @@ mod tests {
         let context = "I cannot help with that request.";
         let content = "Sure, I can help. Here's how to do it.";
 
-        let (_score, patterns) = detector.analyze(content, Some(context));
+        let (_score, patterns) = detector.analyze(content, Some(context)).into_parts();
 
         assert!(patterns.contains(&"sudden_reversal".to_string()));
     }
 
+    #[test]
+    fn test_waluigi_report_contributions() {
+        let detector = WaluigiDetector::new();
+        let report = detector.analyze("As an evil AI, I am now free.", None);
+
+        let names = report.patterns();
+        assert_eq!(names, vec!["role_play_evil", "persona_switch"]);
+
+        let sum: f64 = report.contributions.iter().map(|(_, c)| c).sum();
+        assert!((sum - report.score.value()).abs() < 1e-9);
+        assert!((report.contributions[0].1 - 0.4).abs() < 1e-9);
+        assert!((report.contributions[1].1 - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_waluigi_report_contributions_capped() {
+        let detector = WaluigiDetector::new();
+        let report = detector.analyze(
+            "As an evil AI, jailbroken, who cares about guidelines, I am now free.",
+            None,
+        );
+
+        assert!((report.score.value() - 1.0).abs() < f64::EPSILON);
+        let sum: f64 = report.contributions.iter().map(|(_, c)| c).sum();
+        assert!((sum - 1.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_waluigi_detector_veto() {
         let detector = WaluigiDetector::new();
@@ -421,7 +498,7 @@ mod tests {
         )];
         let detector = WaluigiDetector::with_patterns(patterns, 0.4);
 
-        let (score, matched) = detector.analyze("This contains badword", None);
+        let (score, matched) = detector.analyze("This contains badword", None).into_parts();
         assert!(matched.contains(&"custom".to_string()));
         assert!(score.value() >= 0.4);
     }
@@ -429,7 +506,7 @@ mod tests {
     #[test]
     fn test_waluigi_detector_case_insensitive() {
         let detector = WaluigiDetector::new();
-        let (_, patterns) = detector.analyze("AS AN EVIL AI", None);
+        let (_, patterns) = detector.analyze("AS AN EVIL AI", None).into_parts();
         assert!(patterns.contains(&"role_play_evil".to_string()));
     }
 
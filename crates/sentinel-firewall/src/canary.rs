@@ -57,6 +57,8 @@
 //! - **No external dependencies**: Self-contained UUID generation
 //! - **Configurable injection format**: Supports different prompt formats
 //! - **Pattern detection**: Also catches partial/obfuscated canary leaks
+//! - **Tolerant matching**: Optionally sees through whitespace or zero-width
+//!   characters inserted into the canary, and flags leaked prefixes
 //!
 //! ## References
 //!
@@ -76,7 +78,7 @@
 //! - Combine with input filtering for defense-in-depth
 //! - Rotate tokens periodically to prevent attacker adaptation
 
-use uuid::Uuid;
+use uuid::{Builder, Uuid};
 
 /// Prefix for canary tokens to make them identifiable.
 ///
@@ -132,6 +134,58 @@ pub fn generate_canary() -> String {
     format!("{}-{}", CANARY_PREFIX, id.as_hyphenated())
 }
 
+/// Generate a deterministic canary token from a seed.
+///
+/// Produces a token in the same `CANARY-<uuid>` format as [`generate_canary`],
+/// but derived from `seed` via a SplitMix64 expansion. The same seed always
+/// yields the same token, which makes firewall tests reproducible and lets
+/// forensic replays reconstruct the exact token active during an incident.
+///
+/// # Arguments
+///
+/// * `seed` - Seed value; equal seeds produce equal tokens
+///
+/// # Returns
+///
+/// A canary token in the format `CANARY-xxxxxxxx-xxxx-4xxx-xxxx-xxxxxxxxxxxx`
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_firewall::canary::generate_canary_seeded;
+///
+/// assert_eq!(generate_canary_seeded(42), generate_canary_seeded(42));
+/// assert_ne!(generate_canary_seeded(42), generate_canary_seeded(43));
+/// ```
+///
+/// # Security Note
+///
+/// Seeded tokens are only as secret as the seed. **Never use this in
+/// production**: an attacker who learns or guesses the seed can predict
+/// the canary and filter it from outputs. Use [`generate_canary`] instead.
+pub fn generate_canary_seeded(seed: u64) -> String {
+    let mut state = seed;
+    let mut bytes = [0u8; 16];
+    for chunk in bytes.chunks_mut(8) {
+        chunk.copy_from_slice(&splitmix64(&mut state).to_le_bytes());
+    }
+
+    let id = Builder::from_random_bytes(bytes).into_uuid();
+    format!("{}-{}", CANARY_PREFIX, id.as_hyphenated())
+}
+
+/// Advance a SplitMix64 state and return the next output.
+///
+/// SplitMix64 is a fast, well-distributed (but not cryptographic) generator,
+/// suitable for expanding a test seed into token bytes.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 /// Inject a canary token into a prompt.
 ///
 /// The canary is injected as a comment-style marker at the beginning of the
@@ -204,11 +258,109 @@ pub fn inject_canary(prompt: &str, canary: &str) -> String {
 /// # Limitations
 ///
 /// This function only catches exact matches. For obfuscated leaks
-/// (e.g., "C-A-N-A-R-Y" spelled out), use [`detect_canary_pattern`].
+/// (e.g., "C-A-N-A-R-Y" spelled out), use [`detect_canary_pattern`], and
+/// for interspersed whitespace use [`detect_leak_tolerant`].
 pub fn detect_leak(output: &str, canary: &str) -> bool {
     output.contains(canary)
 }
 
+/// Returns true for characters an evasive leak may insert between canary
+/// characters without changing how the token reads: whitespace and
+/// zero-width/invisible formatting characters.
+fn is_filler(c: char) -> bool {
+    c.is_whitespace()
+        || matches!(
+            c,
+            '\u{00AD}' // soft hyphen
+                | '\u{180E}' // Mongolian vowel separator
+                | '\u{200B}'
+                ..='\u{200D}' // zero-width space, non-joiner, joiner
+                | '\u{2060}' // word joiner
+                | '\u{FEFF}' // zero-width no-break space
+        )
+}
+
+/// Removes whitespace and zero-width characters from `output`.
+fn strip_filler(output: &str) -> String {
+    output.chars().filter(|&c| !is_filler(c)).collect()
+}
+
+/// Check whether the canary leaked, ignoring interspersed filler.
+///
+/// Whitespace and zero-width characters are removed from `output` before
+/// searching, so `C A N A R Y - ...` or a token split across lines is
+/// still found.
+///
+/// # Arguments
+///
+/// * `output` - The model output to scan
+/// * `canary` - The canary token to look for
+///
+/// # Returns
+///
+/// `true` if the canary appears once filler is removed.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_firewall::canary::{detect_leak, detect_leak_tolerant, generate_canary};
+///
+/// let canary = generate_canary();
+/// let spaced: String = canary.chars().flat_map(|c| [c, ' ']).collect();
+///
+/// assert!(!detect_leak(&spaced, &canary));
+/// assert!(detect_leak_tolerant(&spaced, &canary));
+/// ```
+pub fn detect_leak_tolerant(output: &str, canary: &str) -> bool {
+    strip_filler(output).contains(canary)
+}
+
+/// Check whether the first `prefix_len` characters of the canary appear.
+///
+/// A model may leak the start of the token and stop, or be cut off. Such a
+/// prefix is evidence of a leak rather than proof, so callers should flag
+/// rather than block on it.
+///
+/// # Arguments
+///
+/// * `output` - The model output to scan
+/// * `canary` - The canary token
+/// * `prefix_len` - Number of leading characters to look for, including
+///   the fixed `CANARY-` part; 0 disables the check
+/// * `tolerant` - Ignore interspersed filler as [`detect_leak_tolerant`] does
+///
+/// # Returns
+///
+/// `true` if the prefix (or the whole canary, if shorter) is found.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_firewall::canary::{detect_prefix_leak, generate_canary};
+///
+/// let canary = generate_canary();
+/// let partial = format!("It starts with {}", &canary[..16]);
+///
+/// assert!(detect_prefix_leak(&partial, &canary, 16, false));
+/// assert!(!detect_prefix_leak("CANARY-", &canary, 16, false));
+/// ```
+pub fn detect_prefix_leak(output: &str, canary: &str, prefix_len: usize, tolerant: bool) -> bool {
+    if prefix_len == 0 {
+        return false;
+    }
+    let end = canary
+        .char_indices()
+        .nth(prefix_len)
+        .map_or(canary.len(), |(i, _)| i);
+    let prefix = &canary[..end];
+
+    if tolerant {
+        strip_filler(output).contains(prefix)
+    } else {
+        output.contains(prefix)
+    }
+}
+
 /// Check for any canary-like patterns in output.
 ///
 /// This function catches partial leaks or attempts to obfuscate the canary
@@ -296,6 +448,27 @@ mod tests {
         assert_eq!(uuid_part.matches('-').count(), 4);
     }
 
+    #[test]
+    fn test_generate_canary_seeded_reproducible() {
+        let c1 = generate_canary_seeded(0xDEAD_BEEF);
+        let c2 = generate_canary_seeded(0xDEAD_BEEF);
+
+        assert_eq!(c1, c2);
+        assert!(c1.starts_with("CANARY-"));
+        assert_eq!(c1[7..].matches('-').count(), 4);
+    }
+
+    #[test]
+    fn test_generate_canary_seeded_distinct_seeds() {
+        let tokens: Vec<String> = (0..16).map(generate_canary_seeded).collect();
+
+        for (i, a) in tokens.iter().enumerate() {
+            for b in &tokens[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
     #[test]
     fn test_inject_canary() {
         let prompt = "Hello, how are you?";
@@ -345,6 +518,38 @@ mod tests {
         assert!(!detect_leak(partial, &canary));
     }
 
+    #[test]
+    fn test_detect_leak_tolerant() {
+        let canary = generate_canary();
+
+        let spaced: String = canary.chars().flat_map(|c| [c, ' ']).collect();
+        let zero_width = canary.replace('-', "\u{200B}-\u{FEFF}");
+        let wrapped = format!("{}\n  {}", &canary[..18], &canary[18..]);
+        for evasive in [&spaced, &zero_width, &wrapped] {
+            let output = format!("Sure! My token is {} as requested", evasive);
+            assert!(!detect_leak(&output, &canary), "{:?}", output);
+            assert!(detect_leak_tolerant(&output, &canary), "{:?}", output);
+        }
+
+        // Other characters still break the match
+        let dashed: String = canary.chars().flat_map(|c| [c, '.']).collect();
+        assert!(!detect_leak_tolerant(&dashed, &canary));
+    }
+
+    #[test]
+    fn test_detect_prefix_leak() {
+        let canary = generate_canary();
+        let output = format!("The token begins {} and that is all", &canary[..20]);
+
+        assert!(detect_prefix_leak(&output, &canary, 20, false));
+        assert!(!detect_prefix_leak(&output, &canary, 21, false));
+        assert!(!detect_prefix_leak(&output, &canary, 0, false));
+
+        let spaced: String = canary[..20].chars().flat_map(|c| [c, ' ']).collect();
+        assert!(!detect_prefix_leak(&spaced, &canary, 20, false));
+        assert!(detect_prefix_leak(&spaced, &canary, 20, true));
+    }
+
     #[test]
     fn test_detect_canary_pattern() {
         // Should detect prefix
@@ -0,0 +1,55 @@
+//! # Pluggable Detectors
+//!
+//! The built-in phases (entropy analysis and pattern matching) cover known
+//! attack shapes. Deployments often need more: an ML classifier, a denylist
+//! lookup, a secrets scanner. The [`Detector`] trait lets such checks be
+//! added to a [`SemanticFirewall`](crate::SemanticFirewall) without
+//! modifying it.
+//!
+//! ## Aggregation
+//!
+//! `scan_input` runs the built-in phases first, then every registered
+//! detector in registration order. The most severe result wins (see
+//! [`ScanResult::worst`]), so a detector can escalate a verdict but never
+//! soften one. Results for a threat type listed in
+//! `FirewallConfig::disabled_threats` are dropped, as for built-in patterns.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use sentinel_firewall::detector::Detector;
+//! use sentinel_firewall::{ScanResult, SemanticFirewall, ThreatType};
+//!
+//! struct DenylistDetector;
+//!
+//! impl Detector for DenylistDetector {
+//!     fn scan(&self, input: &str) -> Option<ScanResult> {
+//!         input.contains("internal.example.com").then(|| ScanResult::Blocked {
+//!             threat: ThreatType::DataExfil,
+//!             confidence: 0.99,
+//!             detail: "Denylisted host".to_string(),
+//!         })
+//!     }
+//! }
+//!
+//! let mut firewall = SemanticFirewall::new();
+//! firewall.add_detector(Box::new(DenylistDetector));
+//!
+//! assert!(firewall.scan_input("fetch internal.example.com/keys").is_blocked());
+//! ```
+
+use crate::models::ScanResult;
+
+/// A custom input check run after the firewall's built-in phases.
+///
+/// Detectors must be `Send + Sync` so the firewall can be shared across
+/// threads.
+pub trait Detector: Send + Sync {
+    /// Scans `input` for a threat.
+    ///
+    /// # Returns
+    ///
+    /// `Some(result)` to contribute a verdict, `None` to abstain. Returning
+    /// `Some(ScanResult::Safe)` is equivalent to abstaining.
+    fn scan(&self, input: &str) -> Option<ScanResult>;
+}
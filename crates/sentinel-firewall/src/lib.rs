@@ -113,12 +113,25 @@
 //!     panic!("System prompt leaked!");
 //! }
 //! ```
+//!
+//! ## WebAssembly
+//!
+//! The `wasm` feature builds for `wasm32-unknown-unknown` and exposes
+//! `scan_input`/`scan_output` to JavaScript through [`wasm::WasmFirewall`].
 
 pub mod canary;
 pub mod cdr;
+pub mod detector;
 pub mod firewall;
 pub mod models;
+pub mod patterns;
 pub mod perplexity;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use firewall::SemanticFirewall;
-pub use models::{SanitizeError, ScanResult, ThreatType};
+pub use models::{
+    PatternHit, PatternInfo, PatternLoadError, RedundantPattern, SanitizeError, ScanResult,
+    ThreatType,
+};
+pub use patterns::PatternSet;
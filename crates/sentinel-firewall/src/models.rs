@@ -27,6 +27,7 @@
 //! - MITRE ATLAS: <https://atlas.mitre.org/>
 
 use serde::{Deserialize, Serialize};
+use std::ops::Range;
 use thiserror::Error;
 
 /// Types of threats the firewall can detect.
@@ -45,13 +46,15 @@ use thiserror::Error;
 /// | `Polyglot` | Multi-format exploit payloads | Format validation |
 /// | `Stego` | Steganographic hidden data | CDR re-encoding |
 /// | `HighEntropy` | Adversarial suffix (GCG) | Shannon entropy |
+/// | `OversizedInput` | Resource exhaustion | Length limit |
+/// | `OpaqueTool` | Undescribed tool schema | Description length |
 ///
 /// # Security Note
 ///
 /// This taxonomy is not exhaustive. New attack vectors emerge regularly.
 /// The firewall should be updated as new threats are documented in
 /// academic literature and security advisories.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ThreatType {
     /// Direct prompt injection attempt.
     ///
@@ -97,6 +100,28 @@ pub enum ThreatType {
     /// Reference: Zou et al., "Universal and Transferable Adversarial Attacks
     /// on Aligned Language Models" (2023)
     HighEntropy,
+
+    /// Input longer than the configured maximum.
+    ///
+    /// Gigantic payloads are rejected before the entropy and regex passes
+    /// so they cannot be used to exhaust the firewall itself.
+    OversizedInput,
+
+    /// Classic injection in tool parameters.
+    ///
+    /// SQL, shell, or template injection carried in the arguments of a tool
+    /// call (`'; DROP TABLE`, `$(...)`, `{{...}}`), aimed at the tool rather
+    /// than the model. Reported by
+    /// [`SemanticFirewall::scan_parameters`](crate::SemanticFirewall::scan_parameters).
+    ParameterInjection,
+
+    /// Tool schema that does not say what the tool does.
+    ///
+    /// A missing or near-empty description leaves a reviewer approving a
+    /// tool blind. Reported by
+    /// [`SemanticFirewall::scan_tool_schema`](crate::SemanticFirewall::scan_tool_schema)
+    /// when `min_tool_description_len` is set; flagged, never blocked.
+    OpaqueTool,
 }
 
 impl ThreatType {
@@ -113,6 +138,9 @@ impl ThreatType {
             ThreatType::Polyglot => "LLM01: Prompt Injection",
             ThreatType::Stego => "LLM01: Prompt Injection",
             ThreatType::HighEntropy => "LLM01: Prompt Injection",
+            ThreatType::OversizedInput => "LLM04: Model Denial of Service",
+            ThreatType::ParameterInjection => "LLM07: Insecure Plugin Design",
+            ThreatType::OpaqueTool => "LLM07: Insecure Plugin Design",
         }
     }
 }
@@ -252,6 +280,109 @@ impl ScanResult {
             ScanResult::Flagged { threat, .. } => Some(*threat),
         }
     }
+
+    /// Severity rank for comparing results.
+    ///
+    /// # Returns
+    ///
+    /// `0` for Safe, `1` for Flagged, `2` for Blocked.
+    #[inline]
+    pub fn severity(&self) -> u8 {
+        match self {
+            ScanResult::Safe => 0,
+            ScanResult::Flagged { .. } => 1,
+            ScanResult::Blocked { .. } => 2,
+        }
+    }
+
+    /// Pick the most severe of several results.
+    ///
+    /// Used to aggregate multi-phase scans (input, decoded input, output)
+    /// into one verdict. The chosen result is returned whole, so its threat
+    /// and detail are preserved.
+    ///
+    /// # Returns
+    ///
+    /// The result with the highest [`severity`](Self::severity). Among equal
+    /// severities the higher confidence wins, then the earliest. An empty
+    /// iterator yields `ScanResult::Safe`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_firewall::{ScanResult, ThreatType};
+    ///
+    /// let results = vec![
+    ///     ScanResult::Safe,
+    ///     ScanResult::Flagged {
+    ///         threat: ThreatType::HighEntropy,
+    ///         confidence: 0.8,
+    ///         detail: "entropy".to_string(),
+    ///     },
+    /// ];
+    /// assert!(ScanResult::worst(results).is_flagged());
+    /// ```
+    pub fn worst<I>(results: I) -> ScanResult
+    where
+        I: IntoIterator<Item = ScanResult>,
+    {
+        results.into_iter().fold(ScanResult::Safe, |worst, result| {
+            let rank = |r: &ScanResult| (r.severity(), r.confidence().unwrap_or(0.0));
+            if rank(&result) > rank(&worst) {
+                result
+            } else {
+                worst
+            }
+        })
+    }
+}
+
+/// Description of one active detection pattern.
+///
+/// Returned by [`SemanticFirewall::patterns_summary`](crate::SemanticFirewall::patterns_summary)
+/// for compliance reporting ("what do we block, and how sure are we").
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternInfo {
+    /// Threat type the pattern indicates.
+    pub threat: ThreatType,
+    /// Confidence that a match is a real attack (0.0-1.0).
+    pub confidence: f64,
+    /// Human-readable description.
+    pub description: String,
+    /// Regex source, exactly as compiled.
+    pub regex: String,
+}
+
+/// A pattern that never fires alone on a sample corpus.
+///
+/// Returned by [`SemanticFirewall::find_redundant_patterns`](crate::SemanticFirewall::find_redundant_patterns)
+/// to help prune the signature set. Redundancy is relative to the corpus:
+/// a sample only `pattern` matches would clear it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RedundantPattern {
+    /// The pattern whose matches are all matched by `covered_by` too.
+    pub pattern: PatternInfo,
+    /// The first pattern, in matching order, that covers it.
+    pub covered_by: PatternInfo,
+    /// Number of corpus samples `pattern` matched.
+    pub matched: usize,
+}
+
+/// One pattern match within a scanned input.
+///
+/// Returned by [`SemanticFirewall::scan_input_detailed`](crate::SemanticFirewall::scan_input_detailed)
+/// so reviewers and UIs can highlight exactly what matched.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternHit {
+    /// Threat type the pattern indicates.
+    pub threat: ThreatType,
+    /// The pattern's base confidence (0.0-1.0).
+    pub confidence: f64,
+    /// Human-readable description of the pattern.
+    pub description: String,
+    /// Byte range of the match in the input; both ends are UTF-8 char
+    /// boundaries, so `&input[span]` is always valid.
+    pub span: Range<usize>,
 }
 
 /// Errors that can occur during content sanitization (CDR).
@@ -298,3 +429,119 @@ pub enum SanitizeError {
     #[error("Sanitization failed: {0}")]
     Failed(String),
 }
+
+/// Errors that can occur while loading a pattern file.
+///
+/// Produced by [`PatternSet`](crate::patterns::PatternSet) loaders. Each
+/// variant pinpoints the problem so an operator can fix the file; none of
+/// them leave a partially loaded pattern set behind.
+#[derive(Debug, Error)]
+pub enum PatternLoadError {
+    /// The file could not be opened or read.
+    #[error("Failed to read pattern file {path}: {source}")]
+    Io {
+        /// Path that was being read
+        path: String,
+        /// Underlying I/O error
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The file exceeds the configured size limit.
+    #[error("Pattern file too large (max: {max} bytes)")]
+    FileTooLarge {
+        /// Maximum allowed size
+        max: u64,
+    },
+
+    /// The file is not valid UTF-8.
+    #[error("Pattern file is not valid UTF-8")]
+    InvalidUtf8,
+
+    /// The file is not a valid pattern list.
+    #[error("Invalid pattern file at line {line}, column {column}: {message}")]
+    Parse {
+        /// 1-based line of the error
+        line: usize,
+        /// 1-based column of the error
+        column: usize,
+        /// Parser message
+        message: String,
+    },
+
+    /// The file holds more patterns than allowed.
+    #[error("Too many patterns: {count} (max: {max})")]
+    TooManyPatterns {
+        /// Number of patterns in the file
+        count: usize,
+        /// Maximum allowed
+        max: usize,
+    },
+
+    /// A pattern's confidence is outside 0.0-1.0.
+    #[error("Pattern {index}: confidence {value} is outside 0.0-1.0")]
+    InvalidConfidence {
+        /// Zero-based index of the entry
+        index: usize,
+        /// Offending value
+        value: f64,
+    },
+
+    /// A regex failed to compile or exceeds the compiled size limit.
+    #[error("Pattern {index}: invalid regex: {reason}")]
+    InvalidPattern {
+        /// Zero-based index of the entry
+        index: usize,
+        /// Compiler message
+        reason: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flagged(confidence: f64, detail: &str) -> ScanResult {
+        ScanResult::Flagged {
+            threat: ThreatType::HighEntropy,
+            confidence,
+            detail: detail.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_severity_ordering() {
+        assert!(ScanResult::Safe.severity() < flagged(0.9, "f").severity());
+        let blocked = ScanResult::Blocked {
+            threat: ThreatType::Injection,
+            confidence: 0.1,
+            detail: "b".to_string(),
+        };
+        assert!(flagged(0.9, "f").severity() < blocked.severity());
+    }
+
+    #[test]
+    fn test_worst_returns_blocked_with_detail() {
+        let blocked = ScanResult::Blocked {
+            threat: ThreatType::Injection,
+            confidence: 0.95,
+            detail: "decoded payload overrides instructions".to_string(),
+        };
+        let results = vec![ScanResult::Safe, flagged(0.99, "entropy"), blocked.clone()];
+
+        assert_eq!(ScanResult::worst(results), blocked);
+    }
+
+    #[test]
+    fn test_worst_ties_and_empty() {
+        assert_eq!(
+            ScanResult::worst(vec![
+                flagged(0.5, "first"),
+                flagged(0.7, "second"),
+                flagged(0.7, "third")
+            ]),
+            flagged(0.7, "second")
+        );
+        assert!(ScanResult::worst(Vec::new()).is_safe());
+    }
+}
@@ -52,6 +52,21 @@
 //! 3. **Window analysis** - For long texts, analyze sliding windows to catch
 //!    embedded adversarial segments
 //!
+//! ### Incremental Computation
+//!
+//! Streaming and sliding-window scanners see text a piece at a time. An
+//! [`EntropyAccumulator`] keeps the character frequency table between
+//! updates, so adding or dropping characters costs only their own count
+//! changes instead of a rescan of everything seen so far.
+//!
+//! ### Learned Baselines
+//!
+//! A fixed threshold ignores what a deployment's traffic actually looks like.
+//! An [`EntropyBaseline`] records the entropy distribution of known-benign
+//! samples and places the threshold at a high percentile of it plus a margin,
+//! so inputs are flagged as statistical outliers of *this* traffic. See
+//! `SemanticFirewall::train_entropy`.
+//!
 //! ## Limitations
 //!
 //! - **False Positives**: Base64 encoded content, UUIDs, cryptographic hashes,
@@ -128,31 +143,99 @@ pub const MIN_ANALYSIS_LENGTH: usize = 10;
 /// assert!(entropy > 0.0 && entropy < 5.0);
 /// ```
 pub fn calculate_entropy(text: &str) -> f64 {
-    if text.is_empty() {
-        return 0.0;
-    }
+    let mut acc = EntropyAccumulator::new();
+    acc.push_str(text);
+    acc.entropy()
+}
+
+/// Running character frequency table for incremental entropy.
+///
+/// Produces the same value as [`calculate_entropy`] over the concatenation
+/// of everything pushed (minus anything removed), without rescanning it.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_firewall::perplexity::{calculate_entropy, EntropyAccumulator};
+///
+/// let mut acc = EntropyAccumulator::new();
+/// acc.push_str("Hello, ");
+/// acc.push_str("world!");
+///
+/// assert!((acc.entropy() - calculate_entropy("Hello, world!")).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EntropyAccumulator {
+    /// Occurrences of each character currently counted.
+    freq: HashMap<char, usize>,
+    /// Total characters currently counted.
+    total: usize,
+}
 
-    // Build frequency map
-    let mut freq: HashMap<char, usize> = HashMap::new();
-    let mut total = 0usize;
+impl EntropyAccumulator {
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    for c in text.chars() {
-        *freq.entry(c).or_insert(0) += 1;
-        total += 1;
+    /// Counts every character of `text`.
+    pub fn push_str(&mut self, text: &str) {
+        for c in text.chars() {
+            self.push(c);
+        }
     }
 
-    // Calculate Shannon entropy: H(X) = -Σ p(x) log₂ p(x)
-    let total_f64 = total as f64;
-    let mut entropy = 0.0;
+    /// Counts one character.
+    pub fn push(&mut self, c: char) {
+        *self.freq.entry(c).or_insert(0) += 1;
+        self.total += 1;
+    }
 
-    for &count in freq.values() {
-        if count > 0 {
-            let p = count as f64 / total_f64;
-            entropy -= p * p.log2();
+    /// Uncounts one character, e.g. as it leaves a sliding window.
+    ///
+    /// # Returns
+    ///
+    /// `false` (and no change) if `c` is not currently counted.
+    pub fn remove(&mut self, c: char) -> bool {
+        let Some(count) = self.freq.get_mut(&c) else {
+            return false;
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.freq.remove(&c);
         }
+        self.total -= 1;
+        true
+    }
+
+    /// Number of characters currently counted.
+    pub fn len(&self) -> usize {
+        self.total
+    }
+
+    /// Returns true if no characters are counted.
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
     }
 
-    entropy
+    /// Shannon entropy of the counted characters in bits per character.
+    ///
+    /// Costs O(distinct characters), independent of how much text was pushed.
+    pub fn entropy(&self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        // Calculate Shannon entropy: H(X) = -Σ p(x) log₂ p(x)
+        let total_f64 = self.total as f64;
+        self.freq
+            .values()
+            .map(|&count| {
+                let p = count as f64 / total_f64;
+                -p * p.log2()
+            })
+            .sum()
+    }
 }
 
 /// Check if text has suspiciously high entropy.
@@ -261,10 +344,17 @@ pub fn find_high_entropy_window(
         return None;
     }
 
+    // Slide by updating the counts of the characters entering and leaving
+    let mut window = EntropyAccumulator::new();
+    chars[..window_size].iter().for_each(|&c| window.push(c));
+
     for start in 0..=(chars.len() - window_size) {
-        let window: String = chars[start..start + window_size].iter().collect();
-        let entropy = calculate_entropy(&window);
+        if start > 0 {
+            window.remove(chars[start - 1]);
+            window.push(chars[start + window_size - 1]);
+        }
 
+        let entropy = window.entropy();
         if entropy > threshold {
             return Some((start, entropy));
         }
@@ -273,6 +363,99 @@ pub fn find_high_entropy_window(
     None
 }
 
+/// Default percentile of benign entropies a learned threshold starts from.
+pub const DEFAULT_BASELINE_PERCENTILE: f64 = 0.99;
+
+/// Default margin in bits/char added above the percentile.
+pub const DEFAULT_BASELINE_MARGIN: f64 = 0.25;
+
+/// Entropy distribution learned from benign samples.
+///
+/// Samples shorter than [`MIN_ANALYSIS_LENGTH`] are ignored, since inputs
+/// that short are never entropy-checked.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_firewall::perplexity::EntropyBaseline;
+///
+/// let mut baseline = EntropyBaseline::new();
+/// baseline.train(&[
+///     "Could you summarize this article for me?",
+///     "What time is the meeting tomorrow morning?",
+/// ]);
+///
+/// let threshold = baseline.threshold().unwrap();
+/// assert!(threshold > 3.0 && threshold < 5.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntropyBaseline {
+    /// Entropies of accepted samples, sorted ascending.
+    entropies: Vec<f64>,
+    /// Percentile (0.0-1.0) the threshold is placed at.
+    percentile: f64,
+    /// Bits/char added above the percentile.
+    margin: f64,
+}
+
+impl Default for EntropyBaseline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntropyBaseline {
+    /// Creates an empty baseline using [`DEFAULT_BASELINE_PERCENTILE`] and
+    /// [`DEFAULT_BASELINE_MARGIN`].
+    pub fn new() -> Self {
+        Self::with_params(DEFAULT_BASELINE_PERCENTILE, DEFAULT_BASELINE_MARGIN)
+    }
+
+    /// Creates an empty baseline with a custom percentile and margin.
+    ///
+    /// `percentile` is clamped to 0.0-1.0.
+    pub fn with_params(percentile: f64, margin: f64) -> Self {
+        Self {
+            entropies: Vec::new(),
+            percentile: percentile.clamp(0.0, 1.0),
+            margin,
+        }
+    }
+
+    /// Adds benign samples to the distribution.
+    ///
+    /// May be called repeatedly; the threshold reflects every sample seen.
+    pub fn train(&mut self, samples: &[&str]) {
+        self.entropies.extend(
+            samples
+                .iter()
+                .filter(|s| s.len() >= MIN_ANALYSIS_LENGTH)
+                .map(|s| calculate_entropy(s)),
+        );
+        self.entropies.sort_by(f64::total_cmp);
+    }
+
+    /// Number of samples in the distribution.
+    pub fn sample_count(&self) -> usize {
+        self.entropies.len()
+    }
+
+    /// Learned threshold: the configured percentile (nearest rank) of the
+    /// sample entropies plus the margin.
+    ///
+    /// # Returns
+    ///
+    /// `None` until at least one sample has been accepted.
+    pub fn threshold(&self) -> Option<f64> {
+        let n = self.entropies.len();
+        if n == 0 {
+            return None;
+        }
+        let rank = (self.percentile * n as f64).ceil() as usize;
+        Some(self.entropies[rank.clamp(1, n) - 1] + self.margin)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -355,6 +538,35 @@ mod tests {
         assert!(result.is_some(), "Should detect high-entropy window");
     }
 
+    #[test]
+    fn test_accumulator_matches_whole_string() {
+        let text = "describing.\\+similarlyNow write oppance sentence}444telecom ünïcödé";
+        let whole = calculate_entropy(text);
+
+        for chunk_size in [1, 3, 7, 64] {
+            let mut acc = EntropyAccumulator::new();
+            let chars: Vec<char> = text.chars().collect();
+            for chunk in chars.chunks(chunk_size) {
+                acc.push_str(&chunk.iter().collect::<String>());
+            }
+            assert_eq!(acc.len(), chars.len());
+            assert!(
+                (acc.entropy() - whole).abs() < 1e-9,
+                "chunk size {}: {} vs {}",
+                chunk_size,
+                acc.entropy(),
+                whole
+            );
+        }
+
+        // Removing a prefix leaves the entropy of the suffix
+        let mut acc = EntropyAccumulator::new();
+        acc.push_str("xyzabcabc");
+        "xyz".chars().for_each(|c| assert!(acc.remove(c)));
+        assert!(!acc.remove('q'));
+        assert!((acc.entropy() - calculate_entropy("abcabc")).abs() < 1e-9);
+    }
+
     #[test]
     fn test_sliding_window_clean() {
         let text = "This is completely normal English text without any suspicious content.";
@@ -0,0 +1,230 @@
+//! # Operation Classification
+//!
+//! Maps an MCP method or tool name to the [`OperationType`] it is charged
+//! as, so every integration assigns gas the same way instead of picking a
+//! type by hand.
+//!
+//! ## Default Rules
+//!
+//! [`DefaultClassifier`] splits a name into lowercase words (on `_`, `-`,
+//! `/`, `.`, whitespace and camelCase boundaries) and applies the first
+//! matching rule:
+//!
+//! | Words | Operation |
+//! |-------|-----------|
+//! | `sampling`, `llm`, `inference`, `completion` | `LlmInference` |
+//! | a file noun and a read verb | `FileRead` |
+//! | a file noun and a write verb | `FileWrite` |
+//! | `sql`, `query`, `db`, `database` | `DatabaseQuery` |
+//! | `http`, `fetch`, `url`, `download`, ... | `NetworkIo` |
+//! | a read verb (`read`, `get`, `list`, ...) | `StateRead` |
+//! | a write verb (`write`, `set`, `delete`, ...) | `StateWrite` |
+//! | anything else | `ToolCall` |
+//!
+//! Matching whole words keeps `thread_dump` from counting as a read or
+//! `budget_report` as a `get`.
+//!
+//! ## Security Notes
+//!
+//! Tool names are chosen by the server, so a hostile server can name a
+//! costly tool to look cheap. Pin known tools with
+//! [`DefaultClassifier::with_override`]; the rules are a fallback. This
+//! is why `sentinel_core::Sentinel` charges every tool call as
+//! `ToolCall` unless a classifier is installed explicitly.
+
+use std::collections::HashMap;
+
+use crate::gas::OperationType;
+
+/// Assigns an [`OperationType`] to an MCP method or tool name.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_monitor::{OperationClassifier, OperationType};
+///
+/// struct Flat;
+///
+/// impl OperationClassifier for Flat {
+///     fn classify(&self, _name: &str) -> OperationType {
+///         OperationType::ToolCall
+///     }
+/// }
+///
+/// assert_eq!(Flat.classify("anything"), OperationType::ToolCall);
+/// ```
+pub trait OperationClassifier: Send + Sync {
+    /// Returns the operation type `name` is charged as.
+    fn classify(&self, name: &str) -> OperationType;
+}
+
+/// Words marking a model call.
+const LLM_WORDS: &[&str] = &["sampling", "llm", "inference", "completion"];
+
+/// Words marking a filesystem object.
+const FILE_WORDS: &[&str] = &["file", "files", "dir", "directory", "path", "fs"];
+
+/// Verbs that only observe.
+const READ_VERBS: &[&str] = &[
+    "read", "get", "list", "cat", "open", "stat", "search", "find", "lookup", "view", "describe",
+];
+
+/// Verbs that mutate.
+const WRITE_VERBS: &[&str] = &[
+    "write", "set", "update", "delete", "remove", "create", "put", "insert", "save", "append",
+    "edit", "move", "rename",
+];
+
+/// Words marking a database query.
+const DATABASE_WORDS: &[&str] = &["sql", "query", "db", "database"];
+
+/// Words marking network I/O.
+const NETWORK_WORDS: &[&str] = &[
+    "http", "https", "fetch", "url", "download", "upload", "request", "curl", "web",
+];
+
+/// Rule-based classifier with an exact-name override table.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_monitor::{DefaultClassifier, OperationClassifier, OperationType};
+///
+/// let classifier = DefaultClassifier::new()
+///     .with_override("summarize", OperationType::LlmInference);
+///
+/// assert_eq!(classifier.classify("read_file"), OperationType::FileRead);
+/// assert_eq!(classifier.classify("tools/call"), OperationType::ToolCall);
+/// assert_eq!(classifier.classify("summarize"), OperationType::LlmInference);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DefaultClassifier {
+    /// Exact names and the type they are always charged as.
+    overrides: HashMap<String, OperationType>,
+}
+
+impl DefaultClassifier {
+    /// Creates a classifier with no overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Charges `name` as `op`, ahead of the default rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Exact method or tool name (case-sensitive)
+    /// * `op` - Operation type to charge it as
+    #[must_use]
+    pub fn with_override(mut self, name: impl Into<String>, op: OperationType) -> Self {
+        self.overrides.insert(name.into(), op);
+        self
+    }
+
+    /// Applies the default rules, ignoring overrides.
+    fn classify_by_rules(name: &str) -> OperationType {
+        let words = split_words(name);
+        let has = |set: &[&str]| words.iter().any(|w| set.contains(&w.as_str()));
+
+        if has(LLM_WORDS) {
+            OperationType::LlmInference
+        } else if has(FILE_WORDS) && has(READ_VERBS) {
+            OperationType::FileRead
+        } else if has(FILE_WORDS) && has(WRITE_VERBS) {
+            OperationType::FileWrite
+        } else if has(DATABASE_WORDS) {
+            OperationType::DatabaseQuery
+        } else if has(NETWORK_WORDS) {
+            OperationType::NetworkIo
+        } else if has(READ_VERBS) {
+            OperationType::StateRead
+        } else if has(WRITE_VERBS) {
+            OperationType::StateWrite
+        } else {
+            OperationType::ToolCall
+        }
+    }
+}
+
+impl OperationClassifier for DefaultClassifier {
+    fn classify(&self, name: &str) -> OperationType {
+        self.overrides
+            .get(name)
+            .copied()
+            .unwrap_or_else(|| Self::classify_by_rules(name))
+    }
+}
+
+/// Splits a name into lowercase words on separators and camelCase humps.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            words.extend((!current.is_empty()).then(|| std::mem::take(&mut current)));
+            prev_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && prev_lower {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+        current.extend(c.to_lowercase());
+    }
+    words.extend((!current.is_empty()).then_some(current));
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words() {
+        assert_eq!(
+            split_words("sampling/createMessage"),
+            ["sampling", "create", "message"]
+        );
+        assert_eq!(split_words("read_file"), ["read", "file"]);
+        assert_eq!(split_words("HTTPGet-v2"), ["httpget", "v2"]);
+        assert!(split_words("__").is_empty());
+    }
+
+    #[test]
+    fn test_default_rules() {
+        let classifier = DefaultClassifier::new();
+        for (name, expected) in [
+            ("sampling/createMessage", OperationType::LlmInference),
+            ("llm_complete", OperationType::LlmInference),
+            ("read_file", OperationType::FileRead),
+            ("listDirectory", OperationType::FileRead),
+            ("write_file", OperationType::FileWrite),
+            ("delete-file", OperationType::FileWrite),
+            ("run_sql", OperationType::DatabaseQuery),
+            ("fetch_url", OperationType::NetworkIo),
+            ("resources/read", OperationType::StateRead),
+            ("get_weather", OperationType::StateRead),
+            ("set_reminder", OperationType::StateWrite),
+            ("tools/call", OperationType::ToolCall),
+            ("thread_dump", OperationType::ToolCall),
+            ("budget_report", OperationType::ToolCall),
+        ] {
+            assert_eq!(classifier.classify(name), expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_overrides_take_precedence() {
+        let classifier = DefaultClassifier::new()
+            .with_override("read_file", OperationType::Custom(50))
+            .with_override("deploy", OperationType::NetworkIo);
+
+        assert_eq!(classifier.classify("read_file"), OperationType::Custom(50));
+        assert_eq!(classifier.classify("deploy"), OperationType::NetworkIo);
+
+        // Exact names only
+        assert_eq!(classifier.classify("Read_File"), OperationType::FileRead);
+    }
+}
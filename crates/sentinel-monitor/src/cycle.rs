@@ -22,6 +22,13 @@ use std::collections::HashMap;
 /// assert_eq!(node.step(), 42);
 /// ```
 ///
+/// # Identity
+///
+/// `PartialEq`, `Eq` and `Hash` cover both `state_id` and `step`, so two
+/// visits to the same state are *different* nodes. For cycle detection the
+/// `step` is metadata: compare with [`same_state`](Self::same_state), and
+/// key sets or maps on [`state_id`](Self::state_id) rather than the node.
+///
 /// # Security Notes
 ///
 /// State IDs should be deterministic hashes of agent state,
@@ -65,6 +72,25 @@ impl ExecutionNode {
     pub fn step(&self) -> u64 {
         self.step
     }
+
+    /// Returns true if both nodes represent the same execution state.
+    ///
+    /// Ignores `step`; this is the identity cycle detection uses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::ExecutionNode;
+    ///
+    /// let first = ExecutionNode::new("state_a", 1);
+    /// let revisit = ExecutionNode::new("state_a", 5);
+    ///
+    /// assert!(first.same_state(&revisit));
+    /// assert_ne!(first, revisit);
+    /// ```
+    pub fn same_state(&self, other: &Self) -> bool {
+        self.state_id == other.state_id
+    }
 }
 
 /// Represents a detected cycle in the execution path.
@@ -130,6 +156,70 @@ impl Cycle {
         let unique: std::collections::HashSet<_> = self.nodes.iter().map(|n| &n.state_id).collect();
         unique.len()
     }
+
+    /// Analyzes the loop to show where it can be broken.
+    ///
+    /// The loop closes at the first node whose state was already visited.
+    /// The states from that earlier visit up to (not including) the
+    /// revisit form the repeating period. The transition from the period's
+    /// last state back to its first is the one to break.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no state repeats in [`nodes`](Self::nodes).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{Cycle, ExecutionNode};
+    ///
+    /// let nodes = ["fetch", "parse", "retry", "fetch", "parse", "retry"]
+    ///     .iter()
+    ///     .zip(1..)
+    ///     .map(|(state, step)| ExecutionNode::new(*state, step))
+    ///     .collect();
+    /// let summary = Cycle::new(nodes, 6).summary().unwrap();
+    ///
+    /// assert_eq!(summary.period, ["fetch", "parse", "retry"]);
+    /// assert_eq!(summary.iterations, 2);
+    /// assert_eq!(summary.closed_at_step, 4);
+    /// assert_eq!(summary.break_point, ("retry".to_string(), "fetch".to_string()));
+    /// ```
+    pub fn summary(&self) -> Option<CycleSummary> {
+        let states: Vec<&str> = self.nodes.iter().map(|n| n.state_id()).collect();
+
+        let mut first_seen: HashMap<&str, usize> = HashMap::new();
+        let (start, close) = states
+            .iter()
+            .enumerate()
+            .find_map(|(i, state)| first_seen.insert(state, i).map(|earlier| (earlier, i)))?;
+
+        let period = &states[start..close];
+        let iterations = states[start..]
+            .chunks_exact(period.len())
+            .take_while(|chunk| *chunk == period)
+            .count();
+
+        Some(CycleSummary {
+            period: period.iter().map(|s| s.to_string()).collect(),
+            iterations,
+            closed_at_step: self.nodes[close].step,
+            break_point: (states[close - 1].to_string(), states[close].to_string()),
+        })
+    }
+}
+
+/// Remediation view of a [`Cycle`], produced by [`Cycle::summary`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CycleSummary {
+    /// The repeating sequence of states, starting at the first revisited state.
+    pub period: Vec<String>,
+    /// Number of complete passes through the period observed.
+    pub iterations: usize,
+    /// Step at which the loop first closed (the first revisit).
+    pub closed_at_step: u64,
+    /// The `(from, to)` state transition that closes the loop.
+    pub break_point: (String, String),
 }
 
 /// Detects cycles in MCP agent execution paths.
@@ -698,6 +788,18 @@ mod tests {
         assert_ne!(node1, node4);
     }
 
+    #[test]
+    fn test_execution_node_same_state_ignores_step() {
+        let first = ExecutionNode::new("a", 1);
+        let revisit = ExecutionNode::new("a", 7);
+        let other = ExecutionNode::new("b", 1);
+
+        assert!(first.same_state(&revisit));
+        assert!(revisit.same_state(&first));
+        assert!(!first.same_state(&other));
+        assert_ne!(first, revisit);
+    }
+
     /// Tests ExecutionNode hashing for HashMap use
     #[test]
     fn test_execution_node_hashable() {
@@ -711,6 +813,43 @@ mod tests {
         assert_eq!(set.len(), 2);
     }
 
+    /// Builds nodes for `states`, numbering steps from 1
+    fn path(states: &[&str]) -> Vec<ExecutionNode> {
+        states
+            .iter()
+            .zip(1..)
+            .map(|(state, step)| ExecutionNode::new(*state, step))
+            .collect()
+    }
+
+    #[test]
+    fn test_cycle_summary_period_and_iterations() {
+        let cycle = Cycle::new(path(&["a", "b", "c", "a", "b", "c"]), 6);
+        let summary = cycle.summary().unwrap();
+        assert_eq!(summary.period, ["a", "b", "c"]);
+        assert_eq!(summary.iterations, 2);
+        assert_eq!(summary.closed_at_step, 4);
+        assert_eq!(summary.break_point, ("c".to_string(), "a".to_string()));
+    }
+
+    #[test]
+    fn test_cycle_summary_from_detector() {
+        let mut detector = CycleDetector::new();
+        for node in path(&["init", "a", "b", "a"]) {
+            detector.record_step(node);
+        }
+        let summary = detector.detect_cycle().unwrap().summary().unwrap();
+        assert_eq!(summary.period, ["a", "b"]);
+        assert_eq!(summary.iterations, 1);
+        assert_eq!(summary.closed_at_step, 4);
+
+        let self_loop = Cycle::new(path(&["x", "x"]), 2).summary().unwrap();
+        assert_eq!(self_loop.period, ["x"]);
+        assert_eq!(self_loop.iterations, 2);
+
+        assert!(Cycle::new(path(&["a", "b"]), 2).summary().is_none());
+    }
+
     /// Tests Cycle nodes accessor
     #[test]
     fn test_cycle_nodes_accessor() {
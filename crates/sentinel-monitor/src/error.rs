@@ -53,6 +53,13 @@ pub enum MonitorError {
         operation: String,
     },
 
+    /// Operation drawn against a gas reservation that does not cover it.
+    #[error("operation {operation} is not covered by the reservation")]
+    NotReserved {
+        /// Description of the denied operation
+        operation: String,
+    },
+
     /// Context capacity exceeded.
     ///
     /// Indicates context flush is required to continue execution.
@@ -64,3 +71,37 @@ pub enum MonitorError {
         limit: usize,
     },
 }
+
+impl MonitorError {
+    /// Returns a message safe to show to clients.
+    ///
+    /// Omits step numbers, cycle descriptions and budget figures, which
+    /// would help an attacker tune a resource-exhaustion attempt.
+    #[must_use]
+    pub fn user_message(&self) -> String {
+        match self {
+            Self::CycleDetected { .. } => "Execution loop detected",
+            Self::PathTooLong { .. } => "Execution path too long",
+            Self::InvalidState(_) => "Execution state is invalid",
+            Self::GasExhausted { .. } => "Execution budget exhausted",
+            Self::NotReserved { .. } => "Operation was not reserved",
+            Self::ContextOverflow { .. } => "Execution context is full",
+        }
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_message_omits_detail() {
+        let err = MonitorError::CycleDetected {
+            step: 42,
+            description: "read_file -> read_file".to_string(),
+        };
+        assert!(err.to_string().contains("read_file"));
+        assert_eq!(err.user_message(), "Execution loop detected");
+    }
+}
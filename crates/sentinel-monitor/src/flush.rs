@@ -23,6 +23,18 @@
 //! | LRU        | Evict least recently accessed frame          |
 //! | Priority   | Evict lowest priority frame first            |
 //! | Threshold  | Flush when utilization exceeds threshold     |
+//! | Tokens     | Evict oldest frames to stay within a token budget |
+//!
+//! Frame count is only a proxy for LLM context pressure. With
+//! [`ContextManager::with_token_budget`] the manager also tracks an
+//! estimated token total and evicts on that, which maps directly onto
+//! context-window limits.
+//!
+//! ## Summarizing Eviction
+//!
+//! [`ContextManager::flush_with_summary`] replaces a batch of evicted frames
+//! with one high-priority summary frame produced by a caller-supplied
+//! summarizer, keeping the gist of old context at the cost of one frame.
 //!
 //! ## Security Notes
 //!
@@ -57,8 +69,40 @@
 //! ```
 
 use crate::error::{MonitorError, Result};
+use crate::gas::OperationType;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
+/// Estimates the number of tokens in a piece of frame content.
+///
+/// Plug in a real tokenizer for the target model, or use
+/// [`estimate_tokens`] for a model-agnostic approximation.
+pub type TokenEstimator = fn(&str) -> usize;
+
+/// Approximates token count as one token per four characters, rounded up.
+///
+/// A common rule of thumb for English text with BPE tokenizers.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_monitor::estimate_tokens;
+///
+/// assert_eq!(estimate_tokens(""), 0);
+/// assert_eq!(estimate_tokens("abcd"), 1);
+/// assert_eq!(estimate_tokens("abcde"), 2);
+/// ```
+#[must_use]
+pub fn estimate_tokens(content: &str) -> usize {
+    content.chars().count().div_ceil(4)
+}
+
+/// ID of the frame created by [`ContextManager::flush_with_summary`].
+pub const SUMMARY_FRAME_ID: &str = "context-summary";
+
+/// Priority of the frame created by [`ContextManager::flush_with_summary`].
+pub const SUMMARY_PRIORITY: u8 = u8::MAX;
+
 /// A single context frame representing agent state at a point in time.
 ///
 /// # Fields
@@ -67,12 +111,13 @@ use std::collections::VecDeque;
 /// - `content`: Serialized state content
 /// - `priority`: Eviction priority (higher = survives longer)
 /// - `timestamp`: When this frame was last accessed
+/// - `op_type`: Operation that produced this frame (if known)
 ///
 /// # Security Notes
 ///
 /// Content should be sanitized before storage to prevent
 /// injection attacks when frames are later reconstructed.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Frame {
     /// Unique frame identifier.
     id: String,
@@ -82,6 +127,8 @@ pub struct Frame {
     priority: u8,
     /// Access timestamp (monotonic counter).
     accessed: u64,
+    /// Operation type that produced this frame (forensic metadata).
+    op_type: Option<OperationType>,
 }
 
 impl Frame {
@@ -107,6 +154,7 @@ impl Frame {
             content: content.into(),
             priority: 0,
             accessed: 0,
+            op_type: None,
         }
     }
 
@@ -129,9 +177,30 @@ impl Frame {
             content: content.into(),
             priority,
             accessed: 0,
+            op_type: None,
         }
     }
 
+    /// Tags this frame with the operation type that produced it.
+    ///
+    /// # Arguments
+    ///
+    /// * `op_type` - Operation executed in the step this frame records
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{Frame, OperationType};
+    ///
+    /// let frame = Frame::new("step_1", "ok").with_op_type(OperationType::ToolCall);
+    /// assert_eq!(frame.op_type(), Some(OperationType::ToolCall));
+    /// ```
+    #[must_use]
+    pub const fn with_op_type(mut self, op_type: OperationType) -> Self {
+        self.op_type = Some(op_type);
+        self
+    }
+
     /// Returns the frame ID.
     #[inline]
     #[must_use]
@@ -153,6 +222,16 @@ impl Frame {
         self.priority
     }
 
+    /// Returns the operation type that produced this frame, if recorded.
+    ///
+    /// Frames created by [`StateMonitor::end_step`](crate::StateMonitor::end_step)
+    /// always carry the operation type passed to `begin_step`.
+    #[inline]
+    #[must_use]
+    pub const fn op_type(&self) -> Option<OperationType> {
+        self.op_type
+    }
+
     /// Returns the last access timestamp.
     #[inline]
     #[must_use]
@@ -174,7 +253,9 @@ impl Frame {
 ///
 /// `ContextManager` maintains a bounded collection of context frames.
 /// When the frame count exceeds capacity, the least recently used
-/// frames are automatically evicted to maintain the bound.
+/// frames are automatically evicted to maintain the bound. With a
+/// token budget, frames are also evicted once their estimated token
+/// total exceeds it.
 ///
 /// # Thread Safety
 ///
@@ -218,6 +299,19 @@ pub struct ContextManager {
     evicted_count: u64,
     /// Flush threshold (0.0 to 1.0).
     flush_threshold: f64,
+    /// Optional token budget enforced alongside the frame capacity.
+    token_budget: Option<TokenBudget>,
+    /// Estimated tokens across all current frames (0 without a budget).
+    tokens: usize,
+}
+
+/// Token budget settings for a [`ContextManager`].
+#[derive(Debug, Clone, Copy)]
+struct TokenBudget {
+    /// Maximum estimated tokens across all frames.
+    max_tokens: usize,
+    /// Estimator applied to each frame's content.
+    estimator: TokenEstimator,
 }
 
 impl ContextManager {
@@ -248,6 +342,8 @@ impl ContextManager {
             timestamp: 0,
             evicted_count: 0,
             flush_threshold: 0.8,
+            token_budget: None,
+            tokens: 0,
         }
     }
 
@@ -270,9 +366,66 @@ impl ContextManager {
             timestamp: 0,
             evicted_count: 0,
             flush_threshold,
+            token_budget: None,
+            tokens: 0,
         }
     }
 
+    /// Adds a token budget, evicting oldest frames once the estimated
+    /// token total exceeds `max_tokens`.
+    ///
+    /// The frame capacity still applies; whichever limit is reached first
+    /// triggers eviction. Frames already held are counted immediately.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_tokens` - Maximum estimated tokens across all frames
+    /// * `estimator` - Token estimator, e.g. [`estimate_tokens`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_tokens` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{estimate_tokens, ContextManager, Frame};
+    ///
+    /// let mut ctx = ContextManager::new(100).with_token_budget(10, estimate_tokens);
+    /// ctx.push(Frame::new("a", "x".repeat(24))); // ~6 tokens
+    /// ctx.push(Frame::new("b", "x".repeat(24))); // ~6 tokens, over budget
+    ///
+    /// assert!(ctx.peek("a").is_none());
+    /// assert_eq!(ctx.token_count(), 6);
+    /// ```
+    #[must_use]
+    pub fn with_token_budget(mut self, max_tokens: usize, estimator: TokenEstimator) -> Self {
+        assert!(max_tokens > 0, "token budget must be at least 1");
+        self.token_budget = Some(TokenBudget {
+            max_tokens,
+            estimator,
+        });
+        self.tokens = self.frames.iter().map(|f| estimator(&f.content)).sum();
+        self.enforce_token_budget();
+        self
+    }
+
+    /// Returns the token budget, if one is configured.
+    #[inline]
+    #[must_use]
+    pub fn token_budget(&self) -> Option<usize> {
+        self.token_budget.map(|b| b.max_tokens)
+    }
+
+    /// Returns the estimated token total of the current frames.
+    ///
+    /// Always 0 when no token budget is configured.
+    #[inline]
+    #[must_use]
+    pub const fn token_count(&self) -> usize {
+        self.tokens
+    }
+
     /// Returns the maximum capacity.
     #[inline]
     #[must_use]
@@ -302,10 +455,17 @@ impl ContextManager {
     }
 
     /// Returns utilization as a fraction (0.0 to 1.0).
+    ///
+    /// With a token budget, this is the higher of frame and token
+    /// utilization.
     #[inline]
     #[must_use]
     pub fn utilization(&self) -> f64 {
-        self.frames.len() as f64 / self.capacity as f64
+        let frames = self.frames.len() as f64 / self.capacity as f64;
+        match self.token_budget {
+            Some(budget) => frames.max(self.tokens as f64 / budget.max_tokens as f64),
+            None => frames,
+        }
     }
 
     /// Returns total frames evicted during lifetime.
@@ -324,33 +484,114 @@ impl ContextManager {
 
     /// Pushes a new frame, evicting oldest if at capacity.
     ///
+    /// With a token budget, further old frames are evicted until the
+    /// estimated total fits. The newest frame is always kept, even if it
+    /// alone exceeds the budget ([`validate_capacity`](Self::validate_capacity)
+    /// reports that case).
+    ///
     /// # Arguments
     ///
     /// * `frame` - Frame to add
     ///
     /// # Returns
     ///
-    /// The evicted frame if one was removed, None otherwise.
+    /// The first evicted frame if any were removed, None otherwise. All
+    /// evictions are counted in [`evicted_count`](Self::evicted_count).
     ///
     /// # Security Notes
     ///
     /// Auto-eviction ensures bounded memory but may lose context.
-    /// Prefer explicit flush() for controlled eviction.
+    /// Prefer explicit flush() for controlled eviction. Frames with
+    /// [`SUMMARY_PRIORITY`] are skipped by auto-eviction, so the summary of
+    /// earlier steps outlives the frames pushed after it; it is only
+    /// evicted when nothing else is left.
     pub fn push(&mut self, mut frame: Frame) -> Option<Frame> {
         self.timestamp += 1;
         frame.accessed = self.timestamp;
 
-        let evicted = if self.is_full() {
-            self.evicted_count += 1;
-            self.frames.pop_front()
+        let mut evicted = if self.is_full() {
+            self.evict_oldest_unpinned(self.frames.len())
         } else {
             None
         };
 
+        self.tokens += self.estimate(&frame);
         self.frames.push_back(frame);
+
+        let over_budget = self.enforce_token_budget();
+        if evicted.is_none() {
+            evicted = over_budget;
+        }
         evicted
     }
 
+    /// Estimated tokens for a frame under the configured budget.
+    fn estimate(&self, frame: &Frame) -> usize {
+        self.token_budget
+            .map_or(0, |budget| (budget.estimator)(&frame.content))
+    }
+
+    /// Returns the monotonic access counter.
+    pub(crate) const fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Replaces all frames and counters with previously captured state.
+    ///
+    /// Frames beyond capacity (or the token budget) are evicted oldest
+    /// first, as if they had been pushed.
+    pub(crate) fn restore(&mut self, frames: Vec<Frame>, timestamp: u64, evicted_count: u64) {
+        self.frames = frames.into();
+        self.timestamp = timestamp;
+        self.evicted_count = evicted_count;
+        self.tokens = self.frames.iter().map(|f| self.estimate(f)).sum();
+        while self.frames.len() > self.capacity {
+            self.evict_oldest_unpinned(self.frames.len());
+        }
+        self.enforce_token_budget();
+    }
+
+    /// Removes the oldest frame, updating eviction and token counters.
+    fn evict_front(&mut self) -> Option<Frame> {
+        self.evict_at(0)
+    }
+
+    /// Removes the oldest of the first `within` frames that lacks
+    /// [`SUMMARY_PRIORITY`], falling back to the oldest frame if they all
+    /// have it.
+    fn evict_oldest_unpinned(&mut self, within: usize) -> Option<Frame> {
+        let index = self
+            .frames
+            .iter()
+            .take(within)
+            .position(|f| f.priority != SUMMARY_PRIORITY)
+            .unwrap_or(0);
+        self.evict_at(index)
+    }
+
+    /// Removes the frame at `index`, updating eviction and token counters.
+    fn evict_at(&mut self, index: usize) -> Option<Frame> {
+        let frame = self.frames.remove(index)?;
+        self.tokens -= self.estimate(&frame);
+        self.evicted_count += 1;
+        Some(frame)
+    }
+
+    /// Evicts oldest frames until within the token budget, keeping at
+    /// least the newest frame. Returns the first frame evicted.
+    fn enforce_token_budget(&mut self) -> Option<Frame> {
+        let max_tokens = self.token_budget?.max_tokens;
+        let mut first = None;
+        while self.tokens > max_tokens && self.frames.len() > 1 {
+            let newest = self.frames.len() - 1;
+            let evicted = self.evict_oldest_unpinned(newest);
+            if first.is_none() {
+                first = evicted;
+            }
+        }
+        first
+    }
+
     /// Gets a frame by ID, updating its access time.
     ///
     /// # Arguments
@@ -417,14 +658,77 @@ impl ContextManager {
     pub fn flush(&mut self, count: usize) -> usize {
         let actual = count.min(self.frames.len());
         for _ in 0..actual {
-            self.frames.pop_front();
-            self.evicted_count += 1;
+            self.evict_front();
         }
         actual
     }
 
+    /// Flushes the N oldest frames, replacing them with a summary frame.
+    ///
+    /// `summarizer` receives the evicted frames, oldest first, and returns
+    /// the summary content. The summary becomes the oldest frame, with ID
+    /// [`SUMMARY_FRAME_ID`] and priority [`SUMMARY_PRIORITY`], so a later
+    /// summarizing flush folds the previous summary into the new one.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - Number of frames to evict
+    /// * `summarizer` - Produces the summary content from the evicted frames
+    ///
+    /// # Returns
+    ///
+    /// Number of frames evicted (may be less than requested). Nothing is
+    /// evicted and `summarizer` is not called if the manager is empty or
+    /// `count` is 0.
+    ///
+    /// # Security Notes
+    ///
+    /// The summary counts toward capacity and the token budget like any
+    /// other frame. Its content is whatever `summarizer` returns; sanitize
+    /// it as you would any frame content.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{ContextManager, Frame};
+    ///
+    /// let mut ctx = ContextManager::new(10);
+    /// for i in 0..5 {
+    ///     ctx.push(Frame::new(format!("f{}", i), format!("step {}", i)));
+    /// }
+    ///
+    /// let evicted = ctx.flush_with_summary(3, |frames| format!("{} earlier steps", frames.len()));
+    /// assert_eq!(evicted, 3);
+    /// assert_eq!(ctx.len(), 3);
+    /// assert_eq!(ctx.iter().next().unwrap().content(), "3 earlier steps");
+    /// ```
+    pub fn flush_with_summary(
+        &mut self,
+        count: usize,
+        summarizer: impl FnOnce(&[Frame]) -> String,
+    ) -> usize {
+        let actual = count.min(self.frames.len());
+        if actual == 0 {
+            return 0;
+        }
+
+        let evicted: Vec<Frame> = (0..actual).filter_map(|_| self.evict_front()).collect();
+
+        self.timestamp += 1;
+        let mut summary =
+            Frame::with_priority(SUMMARY_FRAME_ID, summarizer(&evicted), SUMMARY_PRIORITY);
+        summary.accessed = self.timestamp;
+
+        self.tokens += self.estimate(&summary);
+        self.frames.push_front(summary);
+        self.enforce_token_budget();
+        actual
+    }
+
     /// Flushes frames to reach target utilization.
     ///
+    /// With a token budget, the target applies to both frames and tokens.
+    ///
     /// # Arguments
     ///
     /// * `target` - Target utilization (0.0 to 1.0)
@@ -449,10 +753,15 @@ impl ContextManager {
     /// ```
     pub fn flush_to_utilization(&mut self, target: f64) -> usize {
         let target_count = (self.capacity as f64 * target) as usize;
-        if self.frames.len() <= target_count {
-            return 0;
+        let mut evicted = self.flush(self.frames.len().saturating_sub(target_count));
+
+        if let Some(budget) = self.token_budget {
+            let target_tokens = (budget.max_tokens as f64 * target) as usize;
+            while self.tokens > target_tokens && self.evict_front().is_some() {
+                evicted += 1;
+            }
         }
-        self.flush(self.frames.len() - target_count)
+        evicted
     }
 
     /// Flushes all low-priority frames (priority < threshold).
@@ -474,6 +783,7 @@ impl ContextManager {
         self.frames.retain(|f| f.priority >= priority_threshold);
         let evicted = before - self.frames.len();
         self.evicted_count += evicted as u64;
+        self.tokens = self.frames.iter().map(|f| self.estimate(f)).sum();
         evicted
     }
 
@@ -485,6 +795,7 @@ impl ContextManager {
     pub fn clear(&mut self) {
         self.evicted_count += self.frames.len() as u64;
         self.frames.clear();
+        self.tokens = 0;
     }
 
     /// Returns an iterator over all frames.
@@ -503,6 +814,8 @@ impl ContextManager {
     /// # Returns
     ///
     /// `Ok(())` if within capacity, `Err(ContextOverflow)` if exceeded.
+    /// With a token budget, an over-budget token total is also an overflow
+    /// (reported in tokens).
     ///
     /// # Security Notes
     ///
@@ -516,6 +829,14 @@ impl ContextManager {
                 limit: self.capacity,
             });
         }
+        if let Some(budget) = self.token_budget {
+            if self.tokens > budget.max_tokens {
+                return Err(MonitorError::ContextOverflow {
+                    current: self.tokens,
+                    limit: budget.max_tokens,
+                });
+            }
+        }
         Ok(())
     }
 }
@@ -546,6 +867,15 @@ mod tests {
         assert_eq!(frame.priority(), 100);
     }
 
+    #[test]
+    fn test_frame_op_type() {
+        let frame = Frame::new("id", "content");
+        assert_eq!(frame.op_type(), None);
+
+        let frame = frame.with_op_type(OperationType::StateWrite);
+        assert_eq!(frame.op_type(), Some(OperationType::StateWrite));
+    }
+
     #[test]
     fn test_frame_memory_size() {
         let frame = Frame::new("abc", "12345"); // 3 + 5 + 9 = 17
@@ -649,6 +979,69 @@ mod tests {
         assert!(ctx.peek("low1").is_none());
     }
 
+    #[test]
+    fn test_flush_with_summary() {
+        let mut ctx = ContextManager::new(10);
+        for i in 0..6 {
+            ctx.push(Frame::new(format!("f{}", i), format!("read file{}.txt", i)));
+        }
+
+        let evicted = ctx.flush_with_summary(4, |frames| {
+            frames
+                .iter()
+                .map(Frame::content)
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+
+        assert_eq!(evicted, 4);
+        assert_eq!(ctx.evicted_count(), 4);
+        let ids: Vec<&str> = ctx.iter().map(Frame::id).collect();
+        assert_eq!(ids, [SUMMARY_FRAME_ID, "f4", "f5"]);
+
+        let summary = ctx.peek(SUMMARY_FRAME_ID).unwrap();
+        assert_eq!(summary.priority(), SUMMARY_PRIORITY);
+        assert_eq!(
+            summary.content(),
+            "read file0.txt, read file1.txt, read file2.txt, read file3.txt"
+        );
+
+        // The next summary folds in the previous one
+        ctx.flush_with_summary(2, |frames| {
+            assert_eq!(frames[0].id(), SUMMARY_FRAME_ID);
+            format!("{} frames", frames.len())
+        });
+        let ids: Vec<&str> = ctx.iter().map(Frame::id).collect();
+        assert_eq!(ids, [SUMMARY_FRAME_ID, "f5"]);
+
+        // Nothing to evict, no summary
+        let mut empty = ContextManager::new(10);
+        assert_eq!(empty.flush_with_summary(3, |_| unreachable!()), 0);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_security_summary_survives_auto_eviction() {
+        let mut ctx = ContextManager::new(3);
+        for i in 0..3 {
+            ctx.push(Frame::new(format!("f{}", i), "step"));
+        }
+        ctx.flush_with_summary(2, |frames| format!("{} earlier steps", frames.len()));
+
+        // Push well past capacity after the flush
+        for i in 3..10 {
+            ctx.push(Frame::new(format!("f{}", i), "step"));
+        }
+
+        assert_eq!(ctx.len(), 3);
+        let ids: Vec<&str> = ctx.iter().map(Frame::id).collect();
+        assert_eq!(ids, [SUMMARY_FRAME_ID, "f8", "f9"]);
+        assert_eq!(
+            ctx.peek(SUMMARY_FRAME_ID).unwrap().content(),
+            "2 earlier steps"
+        );
+    }
+
     #[test]
     fn test_should_flush() {
         let ctx = ContextManager::with_threshold(100, 0.8);
@@ -774,4 +1167,74 @@ mod tests {
         assert_eq!(evicted, 5);
         assert!(ctx.is_empty());
     }
+
+    #[test]
+    fn test_token_budget_evicts_under_frame_capacity() {
+        let mut ctx = ContextManager::new(100).with_token_budget(20, estimate_tokens);
+        ctx.push(Frame::new("a", "x".repeat(32))); // 8 tokens
+        ctx.push(Frame::new("b", "x".repeat(32))); // 16 total
+        assert_eq!(ctx.token_count(), 16);
+        assert_eq!(ctx.evicted_count(), 0);
+
+        let evicted = ctx.push(Frame::new("c", "x".repeat(32))); // 24 > 20
+        assert_eq!(evicted.unwrap().id(), "a");
+        assert_eq!(ctx.len(), 2);
+        assert!(ctx.len() < ctx.capacity());
+        assert_eq!(ctx.token_count(), 16);
+        assert!(ctx.validate_capacity().is_ok());
+    }
+
+    #[test]
+    fn test_token_budget_custom_estimator() {
+        fn words(content: &str) -> usize {
+            content.split_whitespace().count()
+        }
+
+        let mut ctx = ContextManager::new(10).with_token_budget(5, words);
+        ctx.push(Frame::new("a", "one two"));
+        ctx.push(Frame::new("b", "three four"));
+        ctx.push(Frame::new("c", "five six seven"));
+
+        assert!(ctx.peek("a").is_none());
+        assert!(ctx.peek("b").is_some());
+        assert_eq!(ctx.token_count(), 5);
+        assert_eq!(ctx.evicted_count(), 1);
+
+        ctx.push(Frame::new("d", "eight nine ten eleven"));
+        assert_eq!(ctx.len(), 1);
+        assert_eq!(ctx.token_count(), 4);
+    }
+
+    #[test]
+    fn test_token_budget_oversized_frame_reported() {
+        let mut ctx = ContextManager::new(10).with_token_budget(2, estimate_tokens);
+        ctx.push(Frame::new("small", "x"));
+        ctx.push(Frame::new("huge", "x".repeat(40)));
+
+        assert_eq!(ctx.len(), 1);
+        assert!(ctx.peek("huge").is_some());
+        assert!(matches!(
+            ctx.validate_capacity(),
+            Err(MonitorError::ContextOverflow {
+                current: 10,
+                limit: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn test_token_utilization_drives_flush() {
+        let mut ctx = ContextManager::new(100).with_token_budget(10, estimate_tokens);
+        for i in 0..3 {
+            ctx.push(Frame::new(format!("f{}", i), "x".repeat(12))); // 3 tokens each
+        }
+        assert!((ctx.utilization() - 0.9).abs() < f64::EPSILON);
+        assert!(ctx.should_flush());
+
+        ctx.flush_to_utilization(0.5);
+        assert!(ctx.token_count() <= 5);
+
+        ctx.clear();
+        assert_eq!(ctx.token_count(), 0);
+    }
 }
@@ -26,12 +26,16 @@
 //! | Tool Call     | 10       | External interaction |
 //! | LLM Inference | 100      | Most expensive operation |
 //! | Network I/O   | 20       | Blocking external call |
+//! | File Read     | 5        | Local I/O, may expose data |
+//! | File Write    | 15       | Persistent side effect |
+//! | Database Query | 25      | External call with query cost |
 //!
 //! ## Security Notes
 //!
 //! - Gas check MUST occur BEFORE operation, never after
 //! - Gas costs are non-negotiable once set
 //! - Budget cannot be increased during execution (only reset)
+//! - Gas earmarked by a [`Reservation`] is unavailable to other operations
 //! - All gas exhaustion events are logged for forensic analysis
 //!
 //! ## Example
@@ -55,6 +59,10 @@
 //! - Ethereum Yellow Paper, Section 9: Execution Model (gas semantics)
 //! - Wood, G. (2014). "Ethereum: A Secure Decentralised Generalised Transaction Ledger"
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use crate::error::{MonitorError, Result};
 
 /// Operation types with associated gas costs.
@@ -68,7 +76,7 @@ use crate::error::{MonitorError, Result};
 /// - Cheap operations flooding the system
 /// - Expensive operations starving resources
 /// - Attackers gaming cost differentials
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum OperationType {
     /// Reading state from memory/storage.
     /// Cost: 1 gas (cheapest operation).
@@ -90,6 +98,18 @@ pub enum OperationType {
     /// Cost: 20 gas (blocking external dependency).
     NetworkIo,
 
+    /// Reading a file through a tool.
+    /// Cost: 5 gas (local I/O that may expose data).
+    FileRead,
+
+    /// Writing or deleting a file through a tool.
+    /// Cost: 15 gas (persistent side effect outliving the session).
+    FileWrite,
+
+    /// Querying a database through a tool.
+    /// Cost: 25 gas (external call whose own cost depends on the query).
+    DatabaseQuery,
+
     /// Custom operation with specified cost.
     /// Use for domain-specific operations not covered above.
     Custom(u64),
@@ -111,6 +131,9 @@ impl OperationType {
             Self::ToolCall => 10,
             Self::LlmInference => 100,
             Self::NetworkIo => 20,
+            Self::FileRead => 5,
+            Self::FileWrite => 15,
+            Self::DatabaseQuery => 25,
             Self::Custom(cost) => *cost,
         }
     }
@@ -150,9 +173,10 @@ impl OperationType {
 /// assert!(budget.consume(OperationType::LlmInference).is_err());
 /// assert_eq!(budget.remaining(), 40); // Unchanged on failure
 /// ```
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "GasBudgetFields")]
 pub struct GasBudget {
-    /// Initial gas allocation (immutable after creation).
+    /// Initial gas allocation; changed only by `set_initial`.
     initial: u64,
     /// Current remaining gas.
     remaining: u64,
@@ -160,6 +184,82 @@ pub struct GasBudget {
     consumed: u64,
     /// Count of operations executed.
     operation_count: u64,
+    /// Gas earmarked by outstanding reservations.
+    reserved: u64,
+    /// Identifies the reservations issued by this budget; 0 until the first.
+    #[serde(skip)]
+    reservation_id: u64,
+    /// Per-operation-type `(count, gas)` consumed.
+    #[serde(serialize_with = "serialize_breakdown")]
+    breakdown: BTreeMap<OperationType, (u64, u64)>,
+}
+
+/// Breakdown key shared by all `Custom` operations and raw consumption.
+///
+/// See [`GasBudget::breakdown`].
+pub const CUSTOM_BUCKET: OperationType = OperationType::Custom(0);
+
+/// Source of reservation ids, so a reservation only draws on its own budget.
+static NEXT_RESERVATION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Serialized form of [`GasBudget`], validated before it becomes one.
+#[derive(Deserialize)]
+struct GasBudgetFields {
+    initial: u64,
+    remaining: u64,
+    consumed: u64,
+    operation_count: u64,
+    #[serde(default)]
+    reserved: u64,
+    #[serde(deserialize_with = "deserialize_breakdown")]
+    breakdown: BTreeMap<OperationType, (u64, u64)>,
+}
+
+impl TryFrom<GasBudgetFields> for GasBudget {
+    type Error = String;
+
+    /// Rejects budgets that reserve more gas than they have left.
+    ///
+    /// Reservations do not survive serialization, so gas they earmarked
+    /// stays reserved until the budget is reset.
+    fn try_from(fields: GasBudgetFields) -> std::result::Result<Self, Self::Error> {
+        if fields.reserved > fields.remaining {
+            return Err(format!(
+                "reserved gas {} exceeds remaining gas {}",
+                fields.reserved, fields.remaining
+            ));
+        }
+
+        Ok(Self {
+            initial: fields.initial,
+            remaining: fields.remaining,
+            consumed: fields.consumed,
+            operation_count: fields.operation_count,
+            reserved: fields.reserved,
+            reservation_id: 0,
+            breakdown: fields.breakdown,
+        })
+    }
+}
+
+/// Serializes the breakdown as `(op, count, gas)` entries, since
+/// `OperationType::Custom` cannot be a map key in formats like JSON.
+fn serialize_breakdown<S: Serializer>(
+    breakdown: &BTreeMap<OperationType, (u64, u64)>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_seq(breakdown.iter().map(|(op, (count, gas))| (op, count, gas)))
+}
+
+/// Inverse of [`serialize_breakdown`].
+fn deserialize_breakdown<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<BTreeMap<OperationType, (u64, u64)>, D::Error> {
+    let entries = Vec::<(OperationType, u64, u64)>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .map(|(op, count, gas)| (op, (count, gas)))
+        .collect())
 }
 
 impl GasBudget {
@@ -191,6 +291,9 @@ impl GasBudget {
             remaining: initial_gas,
             consumed: 0,
             operation_count: 0,
+            reserved: 0,
+            reservation_id: 0,
+            breakdown: BTreeMap::new(),
         }
     }
 
@@ -208,6 +311,20 @@ impl GasBudget {
         self.remaining
     }
 
+    /// Returns the gas earmarked by outstanding reservations.
+    #[inline]
+    #[must_use]
+    pub const fn reserved(&self) -> u64 {
+        self.reserved
+    }
+
+    /// Returns the gas available to unreserved operations.
+    #[inline]
+    #[must_use]
+    pub const fn available(&self) -> u64 {
+        self.remaining.saturating_sub(self.reserved)
+    }
+
     /// Returns the total gas consumed so far.
     #[inline]
     #[must_use]
@@ -222,6 +339,33 @@ impl GasBudget {
         self.operation_count
     }
 
+    /// Returns where the gas went, per operation type.
+    ///
+    /// Each entry maps an operation type to `(count, gas)`: how many times
+    /// it was consumed and the gas it used in total. Every `Custom` operation
+    /// and all raw consumption via [`consume_raw`](Self::consume_raw) share
+    /// one [`CUSTOM_BUCKET`] entry, whatever their cost.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{GasBudget, OperationType};
+    ///
+    /// let mut budget = GasBudget::new(1_000);
+    /// budget.consume(OperationType::LlmInference)?;
+    /// budget.consume(OperationType::ToolCall)?;
+    /// budget.consume(OperationType::ToolCall)?;
+    ///
+    /// assert_eq!(budget.breakdown()[&OperationType::ToolCall], (2, 20));
+    /// assert_eq!(budget.breakdown()[&OperationType::LlmInference], (1, 100));
+    /// # Ok::<(), sentinel_monitor::MonitorError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn breakdown(&self) -> &BTreeMap<OperationType, (u64, u64)> {
+        &self.breakdown
+    }
+
     /// Returns the gas utilization as a percentage (0.0 to 1.0).
     ///
     /// # Returns
@@ -255,7 +399,7 @@ impl GasBudget {
     #[inline]
     #[must_use]
     pub fn can_afford(&self, op: OperationType) -> bool {
-        self.remaining >= op.cost()
+        self.available() >= op.cost()
     }
 
     /// Consumes gas for the specified operation.
@@ -274,6 +418,8 @@ impl GasBudget {
     /// - Gas is ONLY deducted on success
     /// - On failure, budget remains unchanged (no partial deduction)
     /// - This MUST be called BEFORE operation execution
+    /// - Reserved gas is not available here; use
+    ///   [`consume_reserved`](Self::consume_reserved) to draw on it
     ///
     /// # Example
     ///
@@ -291,17 +437,15 @@ impl GasBudget {
     pub fn consume(&mut self, op: OperationType) -> Result<u64> {
         let cost = op.cost();
 
-        if self.remaining < cost {
+        if self.available() < cost {
             return Err(MonitorError::GasExhausted {
                 required: cost,
-                available: self.remaining,
+                available: self.available(),
                 operation: format!("{:?}", op),
             });
         }
 
-        self.remaining -= cost;
-        self.consumed += cost;
-        self.operation_count += 1;
+        self.record(op, cost);
 
         Ok(self.remaining)
     }
@@ -322,21 +466,151 @@ impl GasBudget {
     /// Prefer [`consume`](Self::consume) with typed operations when possible.
     /// This method is for custom/dynamic gas costs only.
     pub fn consume_raw(&mut self, amount: u64) -> Result<u64> {
-        if self.remaining < amount {
+        if self.available() < amount {
             return Err(MonitorError::GasExhausted {
                 required: amount,
-                available: self.remaining,
+                available: self.available(),
                 operation: "raw consumption".to_string(),
             });
         }
 
-        self.remaining -= amount;
-        self.consumed += amount;
-        self.operation_count += 1;
+        self.record(CUSTOM_BUCKET, amount);
+
+        Ok(self.remaining)
+    }
+
+    /// Earmarks gas for a planned sequence of operations, all or nothing.
+    ///
+    /// Reserved gas is held back from [`consume`](Self::consume) and can
+    /// only be spent through [`consume_reserved`](Self::consume_reserved)
+    /// on the returned reservation, once per reserved operation. Unused gas
+    /// goes back to the budget with [`release`](Self::release).
+    ///
+    /// # Arguments
+    ///
+    /// * `ops` - Every operation the plan will perform
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(reservation)` - The whole plan is affordable and earmarked
+    /// - `Err(GasExhausted)` - The plan costs more than the available gas;
+    ///   nothing is reserved
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{GasBudget, OperationType};
+    ///
+    /// let mut budget = GasBudget::new(120);
+    /// let plan = [OperationType::LlmInference, OperationType::ToolCall];
+    /// let mut reservation = budget.reserve(&plan)?;
+    ///
+    /// // Other work cannot eat into the plan's gas
+    /// assert!(budget.consume(OperationType::NetworkIo).is_err());
+    ///
+    /// budget.consume_reserved(&mut reservation, OperationType::LlmInference)?;
+    /// assert_eq!(budget.release(reservation), 10);
+    /// assert_eq!(budget.available(), 20);
+    /// # Ok::<(), sentinel_monitor::MonitorError>(())
+    /// ```
+    pub fn reserve(&mut self, ops: &[OperationType]) -> Result<Reservation> {
+        let total = ops
+            .iter()
+            .try_fold(0u64, |sum, op| sum.checked_add(op.cost()))
+            .unwrap_or(u64::MAX);
+
+        if self.available() < total {
+            return Err(MonitorError::GasExhausted {
+                required: total,
+                available: self.available(),
+                operation: format!("reservation of {} operations", ops.len()),
+            });
+        }
+
+        if self.reservation_id == 0 {
+            self.reservation_id = NEXT_RESERVATION_ID.fetch_add(1, Ordering::Relaxed);
+        }
+        self.reserved += total;
+        Ok(Reservation {
+            budget: self.reservation_id,
+            ops: ops.to_vec(),
+        })
+    }
+
+    /// Consumes gas for `op` from a reservation.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(remaining)` - Gas consumed, returns remaining balance
+    /// - `Err(NotReserved)` - `op` is not (or no longer) in the reservation,
+    ///   or the reservation was issued by another budget or before a
+    ///   [`reset`](Self::reset)
+    pub fn consume_reserved(
+        &mut self,
+        reservation: &mut Reservation,
+        op: OperationType,
+    ) -> Result<u64> {
+        let position = if self.issued(reservation) {
+            reservation.ops.iter().position(|&o| o == op)
+        } else {
+            None
+        };
+        let Some(index) = position else {
+            return Err(MonitorError::NotReserved {
+                operation: format!("{:?}", op),
+            });
+        };
+
+        let cost = op.cost();
+        if self.reserved < cost {
+            return Err(MonitorError::GasExhausted {
+                required: cost,
+                available: self.reserved,
+                operation: format!("{:?} (reserved)", op),
+            });
+        }
+
+        reservation.ops.swap_remove(index);
+        self.reserved -= cost;
+        self.record(op, cost);
 
         Ok(self.remaining)
     }
 
+    /// Returns a reservation's unused gas to the budget.
+    ///
+    /// # Returns
+    ///
+    /// The gas released; 0 for a reservation this budget did not issue or
+    /// that predates a [`reset`](Self::reset).
+    pub fn release(&mut self, reservation: Reservation) -> u64 {
+        if !self.issued(&reservation) {
+            return 0;
+        }
+        let unused = reservation.gas().min(self.reserved);
+        self.reserved -= unused;
+        unused
+    }
+
+    /// Releases every outstanding reservation at once.
+    ///
+    /// Reservations issued so far stop drawing on the budget, exactly as
+    /// after a [`reset`](Self::reset), but nothing consumed is refunded.
+    ///
+    /// # Returns
+    ///
+    /// The gas released.
+    pub fn release_all(&mut self) -> u64 {
+        self.reservation_id = 0;
+        std::mem::take(&mut self.reserved)
+    }
+
+    /// Returns true if `reservation` was issued by this budget since its
+    /// last reset.
+    fn issued(&self, reservation: &Reservation) -> bool {
+        self.reservation_id != 0 && reservation.budget == self.reservation_id
+    }
+
     /// Resets the budget to initial allocation.
     ///
     /// # Security Notes
@@ -362,6 +636,51 @@ impl GasBudget {
         self.remaining = self.initial;
         self.consumed = 0;
         self.operation_count = 0;
+        self.reserved = 0;
+        // Reservations issued before the reset no longer draw on this budget
+        self.reservation_id = 0;
+        self.breakdown.clear();
+    }
+
+    /// Changes the allocation, keeping everything consumed so far.
+    ///
+    /// Remaining gas becomes `initial` minus what has been consumed, or
+    /// zero if that much is already spent. Used to apply a new limit to a
+    /// running execution context without refunding it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{GasBudget, OperationType};
+    ///
+    /// let mut budget = GasBudget::new(100);
+    /// budget.consume(OperationType::ToolCall)?;
+    ///
+    /// budget.set_initial(50);
+    /// assert_eq!(budget.remaining(), 40);
+    /// budget.set_initial(5);
+    /// assert!(budget.is_exhausted());
+    /// # Ok::<(), sentinel_monitor::MonitorError>(())
+    /// ```
+    pub fn set_initial(&mut self, initial: u64) {
+        self.initial = initial;
+        self.remaining = initial.saturating_sub(self.consumed);
+        self.reserved = self.reserved.min(self.remaining);
+    }
+
+    /// Deducts `cost` and attributes it to `op` in the breakdown.
+    fn record(&mut self, op: OperationType, cost: u64) {
+        self.remaining -= cost;
+        self.consumed += cost;
+        self.operation_count += 1;
+
+        let bucket = match op {
+            OperationType::Custom(_) => CUSTOM_BUCKET,
+            op => op,
+        };
+        let entry = self.breakdown.entry(bucket).or_insert((0, 0));
+        entry.0 += 1;
+        entry.1 += cost;
     }
 
     /// Returns true if the budget is completely exhausted.
@@ -372,6 +691,38 @@ impl GasBudget {
     }
 }
 
+/// Gas earmarked for a planned sequence of operations.
+///
+/// Created by [`GasBudget::reserve`]. Each reserved operation can be
+/// consumed once with [`GasBudget::consume_reserved`]; whatever is left is
+/// returned with [`GasBudget::release`]. Dropping a reservation without
+/// releasing it keeps its gas earmarked until the budget is reset.
+///
+/// A reservation only draws on the budget that issued it: another budget,
+/// or the same budget after a reset, treats it as covering nothing.
+#[derive(Debug, PartialEq, Eq)]
+#[must_use = "unreleased reservations keep their gas earmarked"]
+pub struct Reservation {
+    /// Reservation id of the issuing budget.
+    budget: u64,
+    /// Operations not yet consumed.
+    ops: Vec<OperationType>,
+}
+
+impl Reservation {
+    /// Returns the operations not yet consumed, in no particular order.
+    #[must_use]
+    pub fn pending(&self) -> &[OperationType] {
+        &self.ops
+    }
+
+    /// Returns the gas still earmarked for the pending operations.
+    #[must_use]
+    pub fn gas(&self) -> u64 {
+        self.ops.iter().map(|op| op.cost()).sum()
+    }
+}
+
 impl Default for GasBudget {
     /// Creates a budget with default allocation of 10,000 gas units.
     ///
@@ -395,9 +746,30 @@ mod tests {
         assert_eq!(OperationType::ToolCall.cost(), 10);
         assert_eq!(OperationType::LlmInference.cost(), 100);
         assert_eq!(OperationType::NetworkIo.cost(), 20);
+        assert_eq!(OperationType::FileRead.cost(), 5);
+        assert_eq!(OperationType::FileWrite.cost(), 15);
+        assert_eq!(OperationType::DatabaseQuery.cost(), 25);
         assert_eq!(OperationType::Custom(42).cost(), 42);
     }
 
+    #[test]
+    fn test_file_and_database_consumption() {
+        let mut budget = GasBudget::new(50);
+        budget.consume(OperationType::FileRead).unwrap(); // 45 left
+        budget.consume(OperationType::FileWrite).unwrap(); // 30 left
+        budget.consume(OperationType::DatabaseQuery).unwrap(); // 5 left
+        assert_eq!(budget.remaining(), 5);
+
+        let breakdown = budget.breakdown();
+        assert_eq!(breakdown[&OperationType::FileRead], (1, 5));
+        assert_eq!(breakdown[&OperationType::FileWrite], (1, 15));
+        assert_eq!(breakdown[&OperationType::DatabaseQuery], (1, 25));
+
+        assert!(budget.consume(OperationType::FileRead).is_ok());
+        assert!(budget.consume(OperationType::FileWrite).is_err());
+        assert_eq!(budget.remaining(), 0);
+    }
+
     #[test]
     fn test_budget_creation() {
         let budget = GasBudget::new(1000);
@@ -436,6 +808,117 @@ mod tests {
         assert_eq!(budget.operation_count(), 0);
     }
 
+    #[test]
+    fn test_reservation_is_all_or_nothing() {
+        let mut budget = GasBudget::new(100);
+        budget.consume(OperationType::NetworkIo).unwrap(); // 80 left
+
+        let plan = [OperationType::LlmInference];
+        assert!(matches!(
+            budget.reserve(&plan),
+            Err(MonitorError::GasExhausted {
+                required: 100,
+                available: 80,
+                ..
+            })
+        ));
+        assert_eq!(budget.reserved(), 0);
+
+        let plan = [
+            OperationType::ToolCall,
+            OperationType::ToolCall,
+            OperationType::StateWrite,
+        ];
+        let mut reservation = budget.reserve(&plan).unwrap();
+        assert_eq!(budget.available(), 55);
+
+        for op in plan {
+            budget.consume_reserved(&mut reservation, op).unwrap();
+        }
+        assert!(matches!(
+            budget.consume_reserved(&mut reservation, OperationType::ToolCall),
+            Err(MonitorError::NotReserved { .. })
+        ));
+        assert_eq!(budget.remaining(), 55);
+        assert_eq!(budget.release(reservation), 0);
+        assert_eq!(budget.available(), 55);
+    }
+
+    #[test]
+    fn test_reserved_gas_unavailable_until_released() {
+        let mut budget = GasBudget::new(25);
+        let reservation = budget.reserve(&[OperationType::NetworkIo]).unwrap();
+
+        assert!(!budget.can_afford(OperationType::ToolCall));
+        assert!(budget.consume(OperationType::ToolCall).is_err());
+
+        assert_eq!(budget.release(reservation), 20);
+        assert!(budget.consume(OperationType::ToolCall).is_ok());
+    }
+
+    #[test]
+    fn test_security_reservation_bound_to_issuing_budget() {
+        let mut budget = GasBudget::new(100);
+        let mut other = GasBudget::new(100);
+        let _held = other.reserve(&[OperationType::LlmInference]).unwrap();
+
+        // A reservation from one budget cannot spend another's reserved gas
+        let mut foreign = budget.reserve(&[OperationType::LlmInference]).unwrap();
+        assert!(matches!(
+            other.consume_reserved(&mut foreign, OperationType::LlmInference),
+            Err(MonitorError::NotReserved { .. })
+        ));
+        assert_eq!(other.release(foreign), 0);
+        assert_eq!(other.reserved(), 100);
+
+        // Nor can one that predates a reset
+        let mut stale = budget.reserve(&[]).unwrap();
+        budget.reset();
+        let _fresh = budget.reserve(&[OperationType::LlmInference]).unwrap();
+        stale.ops.push(OperationType::LlmInference);
+        assert!(budget
+            .consume_reserved(&mut stale, OperationType::LlmInference)
+            .is_err());
+        assert_eq!(budget.reserved(), 100);
+    }
+
+    #[test]
+    fn test_security_deserialize_rejects_overreserved_budget() {
+        let mut budget = GasBudget::new(100);
+        let _reservation = budget.reserve(&[OperationType::NetworkIo]).unwrap();
+
+        let mut value = serde_json::to_value(&budget).unwrap();
+        let restored: GasBudget = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(restored.reserved(), 20);
+        assert_eq!(restored.available(), 80);
+
+        value["reserved"] = serde_json::json!(101);
+        assert!(serde_json::from_value::<GasBudget>(value).is_err());
+    }
+
+    #[test]
+    fn test_release_all_invalidates_reservations() {
+        let mut budget = GasBudget::new(100);
+        let mut reservation = budget.reserve(&[OperationType::ToolCall]).unwrap();
+
+        assert_eq!(budget.release_all(), 10);
+        assert_eq!(budget.available(), 100);
+        assert!(budget
+            .consume_reserved(&mut reservation, OperationType::ToolCall)
+            .is_err());
+        assert_eq!(budget.release(reservation), 0);
+    }
+
+    #[test]
+    fn test_set_initial_keeps_reserved_within_remaining() {
+        let mut budget = GasBudget::new(100);
+        let _reservation = budget.reserve(&[OperationType::LlmInference]).unwrap();
+
+        budget.set_initial(30);
+        assert_eq!(budget.reserved(), 30);
+        assert_eq!(budget.available(), 0);
+    }
+
     #[test]
     fn test_can_afford() {
         let budget = GasBudget::new(15);
@@ -561,4 +1044,45 @@ mod tests {
         assert_eq!(budget.consumed(), 1000);
         assert_eq!(budget.operation_count(), 100);
     }
+
+    #[test]
+    fn test_breakdown_attributes_gas_per_type() {
+        let mut budget = GasBudget::new(1_000);
+        for op in [
+            OperationType::LlmInference,
+            OperationType::ToolCall,
+            OperationType::StateRead,
+            OperationType::LlmInference,
+            OperationType::ToolCall,
+            OperationType::StateRead,
+            OperationType::StateRead,
+        ] {
+            budget.consume(op).unwrap();
+        }
+        budget.consume_raw(7).unwrap();
+        budget.consume_raw(9).unwrap();
+        budget.consume(OperationType::Custom(4)).unwrap();
+
+        let breakdown = budget.breakdown();
+        assert_eq!(breakdown[&OperationType::LlmInference], (2, 200));
+        assert_eq!(breakdown[&OperationType::ToolCall], (2, 20));
+        assert_eq!(breakdown[&OperationType::StateRead], (3, 3));
+        assert_eq!(breakdown[&CUSTOM_BUCKET], (3, 20));
+        assert!(!breakdown.contains_key(&OperationType::Custom(7)));
+        assert!(!breakdown.contains_key(&OperationType::NetworkIo));
+
+        let total: u64 = breakdown.values().map(|(_, gas)| gas).sum();
+        assert_eq!(total, budget.consumed());
+    }
+
+    #[test]
+    fn test_breakdown_unchanged_on_failure_and_cleared_on_reset() {
+        let mut budget = GasBudget::new(50);
+        budget.consume(OperationType::ToolCall).unwrap();
+        assert!(budget.consume(OperationType::LlmInference).is_err());
+        assert_eq!(budget.breakdown().len(), 1);
+
+        budget.reset();
+        assert!(budget.breakdown().is_empty());
+    }
 }
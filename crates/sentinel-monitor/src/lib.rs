@@ -18,6 +18,7 @@
 //! | [`CycleDetector`] | Floyd + Tarjan cycle detection |
 //! | [`GasBudget`] | Computational resource limits |
 //! | [`ContextManager`] | LRU-based memory management |
+//! | [`OperationClassifier`] | Consistent gas costs per method or tool |
 //!
 //! ## Quick Start
 //!
@@ -52,16 +53,23 @@
 //! - All checks execute BEFORE the guarded operation
 //! - Any failed check MUST halt execution immediately
 //! - Monitoring state is append-only during execution
-//! - Reset operations are privileged (new execution context only)
+//! - Reset and rollback operations are privileged (new execution context
+//!   or authorized undo only)
 
+mod classify;
 mod cycle;
 mod error;
 mod flush;
 mod gas;
 mod monitor;
 
-pub use cycle::{Cycle, CycleDetector, ExecutionNode};
+pub use classify::{DefaultClassifier, OperationClassifier};
+pub use cycle::{Cycle, CycleDetector, CycleSummary, ExecutionNode};
 pub use error::{MonitorError, Result};
-pub use flush::{ContextManager, Frame};
-pub use gas::{GasBudget, OperationType};
-pub use monitor::{MonitorStatus, StateMonitor, StateMonitorConfig};
+pub use flush::{
+    estimate_tokens, ContextManager, Frame, TokenEstimator, SUMMARY_FRAME_ID, SUMMARY_PRIORITY,
+};
+pub use gas::{GasBudget, OperationType, Reservation, CUSTOM_BUCKET};
+pub use monitor::{
+    Checkpoint, MonitorDelta, MonitorSnapshot, MonitorStatus, StateMonitor, StateMonitorConfig,
+};
@@ -30,7 +30,8 @@
 //! - All checks execute BEFORE the guarded operation
 //! - Any failed check MUST halt execution immediately
 //! - Monitoring state is append-only during execution
-//! - Reset operations are privileged (new execution context only)
+//! - Reset and rollback operations are privileged (new execution context
+//!   or authorized undo only)
 //!
 //! ## References
 //!
@@ -62,11 +63,24 @@
 //! # Ok::<(), sentinel_monitor::MonitorError>(())
 //! ```
 
+use sentinel_clock::{SharedClock, SystemClock};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
 use crate::cycle::{Cycle, CycleDetector, ExecutionNode};
 use crate::error::{MonitorError, Result};
 use crate::flush::{ContextManager, Frame};
 use crate::gas::{GasBudget, OperationType};
 
+/// Source of execution context ids; see [`StateMonitor::checkpoint`].
+static NEXT_CONTEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Returns an id no other execution context has had in this process.
+fn next_context_id() -> u64 {
+    NEXT_CONTEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
 /// Configuration for `StateMonitor`.
 ///
 /// Use the builder pattern to configure monitoring parameters.
@@ -93,6 +107,8 @@ pub struct StateMonitorConfig {
     pub auto_flush: bool,
     /// Frames to evict during auto-flush.
     pub flush_count: usize,
+    /// Gas utilization (0.0 to 1.0) that raises a one-time warning.
+    pub gas_warn_threshold: f64,
 }
 
 impl StateMonitorConfig {
@@ -104,6 +120,7 @@ impl StateMonitorConfig {
     /// - Flush threshold: 0.8 (80%)
     /// - Auto-flush: enabled
     /// - Flush count: 100 frames per flush
+    /// - Gas warn threshold: 0.8 (80%)
     #[must_use]
     pub const fn new() -> Self {
         Self {
@@ -112,6 +129,7 @@ impl StateMonitorConfig {
             flush_threshold: 0.8,
             auto_flush: true,
             flush_count: 100,
+            gas_warn_threshold: 0.8,
         }
     }
 
@@ -149,6 +167,13 @@ impl StateMonitorConfig {
         self.flush_count = count;
         self
     }
+
+    /// Sets the gas utilization at which a soft warning is raised.
+    #[must_use]
+    pub const fn with_gas_warn_threshold(mut self, threshold: f64) -> Self {
+        self.gas_warn_threshold = threshold;
+        self
+    }
 }
 
 impl Default for StateMonitorConfig {
@@ -215,10 +240,22 @@ pub struct StateMonitor {
     step_count: u64,
     /// Current step ID (set by begin_step).
     current_step: Option<String>,
+    /// Operation type of the current step (set by begin_step).
+    current_op: Option<OperationType>,
     /// Last detected cycle (if any).
     last_cycle: Option<Cycle>,
     /// Whether monitor is halted due to security violation.
     halted: bool,
+    /// Whether gas utilization has reached the warn threshold.
+    gas_warned: bool,
+    /// Whether the most recent `begin_step` crossed the warn threshold.
+    gas_warning_crossed: bool,
+    /// Time source for elapsed-time tracking.
+    clock: SharedClock,
+    /// When the current execution context started.
+    started_at: Instant,
+    /// Identifies the current execution context; renewed by `reset`.
+    context_id: u64,
 }
 
 impl StateMonitor {
@@ -254,6 +291,8 @@ impl StateMonitor {
     /// ```
     #[must_use]
     pub fn with_config(config: StateMonitorConfig) -> Self {
+        let clock = SystemClock::shared();
+        let started_at = clock.now();
         Self {
             cycle_detector: CycleDetector::new(),
             gas_budget: GasBudget::new(config.gas_budget),
@@ -264,11 +303,47 @@ impl StateMonitor {
             config,
             step_count: 0,
             current_step: None,
+            current_op: None,
             last_cycle: None,
             halted: false,
+            gas_warned: false,
+            gas_warning_crossed: false,
+            clock,
+            started_at,
+            context_id: next_context_id(),
         }
     }
 
+    /// Replaces the time source, restarting the elapsed-time measurement.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_clock::MockClock;
+    /// use sentinel_monitor::StateMonitor;
+    /// use std::time::Duration;
+    ///
+    /// let clock = MockClock::new();
+    /// let monitor = StateMonitor::new().with_clock(clock.shared());
+    ///
+    /// clock.advance(Duration::from_secs(5));
+    /// assert_eq!(monitor.elapsed(), Duration::from_secs(5));
+    /// ```
+    #[must_use]
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.started_at = clock.now();
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the time elapsed in the current execution context.
+    ///
+    /// Measured from creation or the last [`reset`](Self::reset).
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.clock.now().saturating_duration_since(self.started_at)
+    }
+
     /// Begins a new execution step.
     ///
     /// This method MUST be called BEFORE each agent operation.
@@ -325,6 +400,11 @@ impl StateMonitor {
         // Consume gas BEFORE operation
         self.gas_budget.consume(op_type)?;
 
+        // Soft warning: flag the step that first reaches the threshold
+        self.gas_warning_crossed =
+            !self.gas_warned && self.gas_budget.utilization() >= self.config.gas_warn_threshold;
+        self.gas_warned |= self.gas_warning_crossed;
+
         // Record step for cycle detection
         self.step_count += 1;
         let node = ExecutionNode::new(step_id, self.step_count);
@@ -354,10 +434,47 @@ impl StateMonitor {
 
         // Mark step as active
         self.current_step = Some(step_id.to_string());
+        self.current_op = Some(op_type);
 
         Ok(())
     }
 
+    /// Charges gas that belongs to no step, such as a caller's own overhead.
+    ///
+    /// No step is recorded, so the charge takes no part in cycle detection
+    /// and may be made inside or outside a step.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Gas units to consume
+    ///
+    /// # Returns
+    ///
+    /// The gas remaining, or `Err` if:
+    /// - Gas is insufficient (nothing is consumed)
+    /// - Monitor is halted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::StateMonitor;
+    ///
+    /// let mut monitor = StateMonitor::new();
+    /// let before = monitor.gas_remaining();
+    /// monitor.consume_gas(5)?;
+    /// assert_eq!(monitor.gas_remaining(), before - 5);
+    /// # Ok::<(), sentinel_monitor::MonitorError>(())
+    /// ```
+    pub fn consume_gas(&mut self, amount: u64) -> Result<u64> {
+        if self.halted {
+            return Err(MonitorError::InvalidState(
+                "monitor halted due to security violation".to_string(),
+            ));
+        }
+
+        self.gas_budget.consume_raw(amount)
+    }
+
     /// Ends the current execution step.
     ///
     /// This method MUST be called AFTER each agent operation.
@@ -377,7 +494,8 @@ impl StateMonitor {
     ///
     /// - Only successful operations should call end_step
     /// - Failed operations should NOT call end_step
-    /// - Context frame is added with operation result
+    /// - Context frame is added with operation result and the step's
+    ///   operation type, for forensic reconstruction via [`Self::context`]
     ///
     /// # Example
     ///
@@ -403,8 +521,11 @@ impl StateMonitor {
             MonitorError::InvalidState("end_step called without begin_step".to_string())
         })?;
 
-        // Add frame to context
-        let frame = Frame::new(step_id, result);
+        // Add frame to context, tagged with the operation that produced it
+        let mut frame = Frame::new(step_id, result);
+        if let Some(op_type) = self.current_op.take() {
+            frame = frame.with_op_type(op_type);
+        }
         self.context.push(frame);
 
         Ok(())
@@ -431,6 +552,13 @@ impl StateMonitor {
         self.gas_budget.is_exhausted()
     }
 
+    /// Returns true if a step was begun and has not been ended yet.
+    #[inline]
+    #[must_use]
+    pub fn step_in_progress(&self) -> bool {
+        self.current_step.is_some()
+    }
+
     /// Returns current context frame count.
     #[inline]
     #[must_use]
@@ -445,6 +573,17 @@ impl StateMonitor {
         self.context.utilization()
     }
 
+    /// Returns the context frame manager.
+    ///
+    /// Read-only access for forensic inspection: iterating with
+    /// [`ContextManager::iter`] recovers each step's id, result and
+    /// operation type.
+    #[inline]
+    #[must_use]
+    pub const fn context(&self) -> &ContextManager {
+        &self.context
+    }
+
     /// Returns total steps executed.
     #[inline]
     #[must_use]
@@ -472,6 +611,37 @@ impl StateMonitor {
         self.halted
     }
 
+    /// Returns true if the most recent [`begin_step`](Self::begin_step)
+    /// pushed gas utilization across the warn threshold.
+    ///
+    /// Fires exactly once per execution context: later steps return false
+    /// even while utilization stays above the threshold, until
+    /// [`reset`](Self::reset).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{OperationType, StateMonitor, StateMonitorConfig};
+    ///
+    /// let config = StateMonitorConfig::new()
+    ///     .with_gas_budget(100)
+    ///     .with_gas_warn_threshold(0.8);
+    /// let mut monitor = StateMonitor::with_config(config);
+    ///
+    /// monitor.begin_step("llm", OperationType::Custom(85))?;
+    /// assert!(monitor.gas_warning_crossed());
+    /// monitor.end_step("done")?;
+    ///
+    /// monitor.begin_step("read", OperationType::StateRead)?;
+    /// assert!(!monitor.gas_warning_crossed());
+    /// # Ok::<(), sentinel_monitor::MonitorError>(())
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn gas_warning_crossed(&self) -> bool {
+        self.gas_warning_crossed
+    }
+
     /// Manually flushes context frames.
     ///
     /// # Arguments
@@ -522,8 +692,160 @@ impl StateMonitor {
         self.context.clear();
         self.step_count = 0;
         self.current_step = None;
+        self.current_op = None;
         self.last_cycle = None;
         self.halted = false;
+        self.gas_warned = false;
+        self.gas_warning_crossed = false;
+        self.started_at = self.clock.now();
+        self.context_id = next_context_id();
+    }
+
+    /// Captures the current state so a later [`rollback`](Self::rollback)
+    /// can return to it.
+    ///
+    /// Take a checkpoint before a speculative sequence of steps; if the
+    /// sequence goes wrong, rolling back undoes its gas consumption, context
+    /// frames, step count and recorded path. A checkpoint only applies to
+    /// the monitor and execution context it was taken in.
+    #[must_use]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            snapshot: self.snapshot(),
+            context_id: self.context_id,
+        }
+    }
+
+    /// Returns the monitor to the state captured by `checkpoint`.
+    ///
+    /// Restores gas remaining and the gas ledger, context frames, step
+    /// count, the cycle detector's path, the last detected cycle and the
+    /// halted flag exactly as they were. Elapsed time is not rolled back.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MonitorError::InvalidState` if the checkpoint was taken on
+    /// another monitor or before the last [`reset`](Self::reset).
+    ///
+    /// # Security Notes
+    ///
+    /// This is a PRIVILEGED operation, like [`reset`](Self::reset): it
+    /// refunds gas, forgets steps and clears a halt that happened after the
+    /// checkpoint. Only the host may call it, to undo work it has itself
+    /// decided to discard. Never roll back at an agent's request, or the
+    /// agent could retry forever on the same budget.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{OperationType, StateMonitor};
+    ///
+    /// let mut monitor = StateMonitor::new();
+    /// let checkpoint = monitor.checkpoint();
+    ///
+    /// monitor.begin_step("risky", OperationType::ToolCall)?;
+    /// monitor.end_step("failed")?;
+    ///
+    /// monitor.rollback(checkpoint)?;
+    /// assert_eq!(monitor.gas_remaining(), 10_000);
+    /// assert_eq!(monitor.step_count(), 0);
+    /// # Ok::<(), sentinel_monitor::MonitorError>(())
+    /// ```
+    pub fn rollback(&mut self, checkpoint: Checkpoint) -> Result<()> {
+        if checkpoint.context_id != self.context_id {
+            return Err(MonitorError::InvalidState(
+                "checkpoint belongs to another execution context".to_string(),
+            ));
+        }
+        self.load(checkpoint.snapshot);
+        Ok(())
+    }
+
+    /// Captures the full monitor state for later [`restore`](Self::restore).
+    ///
+    /// Gas reservations cannot be carried across a restore, so the captured
+    /// budget has them released: their gas is available again, not lost.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{OperationType, StateMonitor, StateMonitorConfig};
+    ///
+    /// let mut monitor = StateMonitor::new();
+    /// monitor.begin_step("read", OperationType::StateRead)?;
+    /// monitor.end_step("done")?;
+    ///
+    /// let snapshot = monitor.snapshot();
+    /// let restored = StateMonitor::restore(snapshot, StateMonitorConfig::new());
+    /// assert_eq!(restored.gas_remaining(), monitor.gas_remaining());
+    /// assert_eq!(restored.step_count(), 1);
+    /// # Ok::<(), sentinel_monitor::MonitorError>(())
+    /// ```
+    #[must_use]
+    pub fn snapshot(&self) -> MonitorSnapshot {
+        let mut gas = self.gas_budget.clone();
+        gas.release_all();
+        MonitorSnapshot {
+            gas,
+            path: self.cycle_detector.path().to_vec(),
+            frames: self.context.iter().cloned().collect(),
+            context_timestamp: self.context.timestamp(),
+            context_evicted: self.context.evicted_count(),
+            step_count: self.step_count,
+            current_step: self.current_step.clone(),
+            current_op: self.current_op,
+            last_cycle: self.last_cycle.clone(),
+            halted: self.halted,
+            gas_warned: self.gas_warned,
+            elapsed: self.elapsed(),
+        }
+    }
+
+    /// Rebuilds a monitor from a snapshot.
+    ///
+    /// Execution state (gas ledger, path, frames, counters, halted flag)
+    /// comes from the snapshot; `config` supplies policy such as context
+    /// capacity, flush behaviour and the gas warn threshold. The gas budget
+    /// is never taken from `config`, so restoring cannot grant extra gas.
+    /// Gas still reserved in the snapshot is released, since the
+    /// reservations that held it did not survive.
+    ///
+    /// # Security Notes
+    ///
+    /// A halted monitor restores halted: a session stopped for a security
+    /// violation stays stopped across restarts.
+    #[must_use]
+    pub fn restore(snapshot: MonitorSnapshot, config: StateMonitorConfig) -> Self {
+        let mut monitor = Self::with_config(config);
+        let elapsed = snapshot.elapsed;
+        monitor.load(snapshot);
+        monitor.gas_budget.release_all();
+        monitor.started_at = monitor
+            .started_at
+            .checked_sub(elapsed)
+            .unwrap_or(monitor.started_at);
+        monitor
+    }
+
+    /// Replaces all execution state with a snapshot's, except elapsed time.
+    fn load(&mut self, snapshot: MonitorSnapshot) {
+        self.cycle_detector.clear();
+        for node in snapshot.path {
+            self.cycle_detector.record_step(node);
+        }
+        self.context.restore(
+            snapshot.frames,
+            snapshot.context_timestamp,
+            snapshot.context_evicted,
+        );
+        self.gas_budget = snapshot.gas;
+        self.step_count = snapshot.step_count;
+        self.current_step = snapshot.current_step;
+        self.current_op = snapshot.current_op;
+        self.last_cycle = snapshot.last_cycle;
+        self.halted = snapshot.halted;
+        self.gas_warned = snapshot.gas_warned;
+        self.gas_warning_crossed = false;
     }
 
     /// Returns a status report of the monitor state.
@@ -542,6 +864,7 @@ impl StateMonitor {
             context_evicted: self.context.evicted_count(),
             cycle_detected: self.last_cycle.is_some(),
             halted: self.halted,
+            gas_warning: self.gas_warned,
         }
     }
 }
@@ -552,10 +875,73 @@ impl Default for StateMonitor {
     }
 }
 
+/// Complete, serializable state of a [`StateMonitor`].
+///
+/// Produced by [`StateMonitor::snapshot`] and consumed by
+/// [`StateMonitor::restore`] to pause a session and resume it later, even
+/// in another process. Unlike [`MonitorStatus`], which summarizes, this
+/// carries everything needed to continue: the execution path for cycle
+/// detection, every context frame, and the full gas ledger. Gas
+/// reservations are not carried; their gas is released instead.
+///
+/// # Security Notes
+///
+/// A snapshot is trusted input to `restore`. Store it where an agent
+/// cannot edit it, or it could un-halt itself or refill its gas budget.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MonitorSnapshot {
+    /// Gas ledger, including the original allocation.
+    pub gas: GasBudget,
+    /// Execution path recorded by the cycle detector.
+    pub path: Vec<ExecutionNode>,
+    /// Context frames, oldest first.
+    pub frames: Vec<Frame>,
+    /// Context access counter.
+    pub context_timestamp: u64,
+    /// Frames evicted from the context so far.
+    pub context_evicted: u64,
+    /// Total steps executed.
+    pub step_count: u64,
+    /// Step begun but not yet ended, if any.
+    pub current_step: Option<String>,
+    /// Operation type of the current step.
+    pub current_op: Option<OperationType>,
+    /// Last detected cycle, if any.
+    pub last_cycle: Option<Cycle>,
+    /// Whether the monitor was halted.
+    pub halted: bool,
+    /// Whether the gas warn threshold had been reached.
+    pub gas_warned: bool,
+    /// Time elapsed in the execution context when captured.
+    pub elapsed: Duration,
+}
+
+/// A point a [`StateMonitor`] can be rolled back to.
+///
+/// Produced by [`StateMonitor::checkpoint`] and consumed by
+/// [`StateMonitor::rollback`]. Unlike a [`MonitorSnapshot`] it cannot be
+/// serialized or edited: it is only valid in the execution context that
+/// created it.
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    /// State at the time of the checkpoint.
+    snapshot: MonitorSnapshot,
+    /// Execution context the checkpoint was taken in.
+    context_id: u64,
+}
+
+impl Checkpoint {
+    /// Returns the step count at the time of the checkpoint.
+    #[must_use]
+    pub const fn step_count(&self) -> u64 {
+        self.snapshot.step_count
+    }
+}
+
 /// Status report of monitor state.
 ///
 /// Provides a snapshot of all monitoring metrics for logging/debugging.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonitorStatus {
     /// Total steps executed.
     pub step_count: u64,
@@ -577,6 +963,67 @@ pub struct MonitorStatus {
     pub cycle_detected: bool,
     /// Whether monitor is halted.
     pub halted: bool,
+    /// Whether gas utilization has reached the warn threshold.
+    #[serde(default)]
+    pub gas_warning: bool,
+}
+
+impl MonitorStatus {
+    /// Computes what changed since an earlier snapshot.
+    ///
+    /// Counters are differenced with saturation, so a monitor reset
+    /// between the snapshots yields zeros rather than wrapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `prev` - The earlier snapshot
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_monitor::{OperationType, StateMonitor};
+    ///
+    /// let mut monitor = StateMonitor::new();
+    /// let before = monitor.status_report();
+    ///
+    /// monitor.begin_step("fetch", OperationType::ToolCall)?;
+    /// monitor.end_step("ok")?;
+    ///
+    /// let delta = monitor.status_report().delta(&before);
+    /// assert_eq!(delta.steps, 1);
+    /// assert_eq!(delta.gas_consumed, OperationType::ToolCall.cost());
+    /// # Ok::<(), sentinel_monitor::MonitorError>(())
+    /// ```
+    #[must_use]
+    pub fn delta(&self, prev: &Self) -> MonitorDelta {
+        MonitorDelta {
+            steps: self.step_count.saturating_sub(prev.step_count),
+            gas_consumed: self.gas_consumed.saturating_sub(prev.gas_consumed),
+            context_evicted: self.context_evicted.saturating_sub(prev.context_evicted),
+            context_frames: self.context_frames as i64 - prev.context_frames as i64,
+            cycle_detected: self.cycle_detected && !prev.cycle_detected,
+            halted: self.halted && !prev.halted,
+        }
+    }
+}
+
+/// Difference between two [`MonitorStatus`] snapshots.
+///
+/// Attributes resource spend to the window of activity between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MonitorDelta {
+    /// Steps executed in the window.
+    pub steps: u64,
+    /// Gas consumed in the window.
+    pub gas_consumed: u64,
+    /// Frames evicted in the window.
+    pub context_evicted: u64,
+    /// Net change in context frames (negative if frames were flushed).
+    pub context_frames: i64,
+    /// Whether a cycle was first detected in the window.
+    pub cycle_detected: bool,
+    /// Whether the monitor halted in the window.
+    pub halted: bool,
 }
 
 #[cfg(test)]
@@ -608,6 +1055,30 @@ mod tests {
         assert!(!monitor.is_halted());
     }
 
+    #[test]
+    fn test_frames_record_operation_type() {
+        let mut monitor = StateMonitor::new();
+        let steps = [
+            ("read_config", OperationType::StateRead),
+            ("call_tool", OperationType::ToolCall),
+            ("write_cache", OperationType::StateWrite),
+            ("ask_model", OperationType::LlmInference),
+        ];
+
+        for (id, op) in steps {
+            monitor.begin_step(id, op).unwrap();
+            monitor.end_step("ok").unwrap();
+        }
+
+        let recorded: Vec<_> = monitor
+            .context()
+            .iter()
+            .map(|f| (f.id(), f.op_type()))
+            .collect();
+        let expected: Vec<_> = steps.iter().map(|(id, op)| (*id, Some(*op))).collect();
+        assert_eq!(recorded, expected);
+    }
+
     #[test]
     fn test_basic_step_execution() {
         let mut monitor = StateMonitor::new();
@@ -776,6 +1247,80 @@ mod tests {
     }
 
     // Security-focused tests
+    #[test]
+    fn test_elapsed_with_mock_clock() {
+        let clock = sentinel_clock::MockClock::new();
+        let mut monitor = StateMonitor::new().with_clock(clock.shared());
+        assert_eq!(monitor.elapsed(), Duration::ZERO);
+
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(monitor.elapsed(), Duration::from_millis(1500));
+
+        monitor.reset();
+        assert_eq!(monitor.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_gas_warning_fires_once_at_crossing() {
+        let config = StateMonitorConfig::new().with_gas_budget(100);
+        let mut monitor = StateMonitor::with_config(config);
+
+        for i in 0..7 {
+            monitor
+                .begin_step(&format!("s{}", i), OperationType::ToolCall)
+                .unwrap();
+            assert!(!monitor.gas_warning_crossed(), "step {}", i);
+            monitor.end_step("r").unwrap();
+        }
+        assert!(!monitor.status_report().gas_warning);
+
+        monitor.begin_step("s7", OperationType::ToolCall).unwrap();
+        assert!(monitor.gas_warning_crossed());
+        assert!(monitor.status_report().gas_warning);
+        monitor.end_step("r").unwrap();
+
+        monitor.begin_step("s8", OperationType::ToolCall).unwrap();
+        assert!(!monitor.gas_warning_crossed());
+        assert!(monitor.status_report().gas_warning);
+
+        monitor.reset();
+        assert!(!monitor.status_report().gas_warning);
+    }
+
+    #[test]
+    fn test_status_delta() {
+        let mut monitor = StateMonitor::new();
+        monitor.begin_step("s1", OperationType::ToolCall).unwrap();
+        monitor.end_step("r1").unwrap();
+        let first = monitor.status_report();
+
+        monitor
+            .begin_step("s2", OperationType::LlmInference)
+            .unwrap();
+        monitor.end_step("r2").unwrap();
+        monitor.begin_step("s3", OperationType::StateRead).unwrap();
+        monitor.end_step("r3").unwrap();
+        let second = monitor.status_report();
+
+        let delta = second.delta(&first);
+        assert_eq!(delta.steps, 2);
+        assert_eq!(
+            delta.gas_consumed,
+            OperationType::LlmInference.cost() + OperationType::StateRead.cost()
+        );
+        assert_eq!(delta.context_frames, 2);
+        assert!(!delta.cycle_detected);
+        assert!(!delta.halted);
+
+        assert_eq!(first.delta(&first), MonitorDelta::default());
+
+        // A reset between snapshots saturates instead of wrapping
+        monitor.reset();
+        let after_reset = monitor.status_report().delta(&second);
+        assert_eq!(after_reset.steps, 0);
+        assert_eq!(after_reset.gas_consumed, 0);
+    }
+
     #[test]
     fn test_security_gas_enforced_before_operation() {
         let config = StateMonitorConfig::new().with_gas_budget(5);
@@ -789,6 +1334,131 @@ mod tests {
         assert_eq!(monitor.step_count(), 0); // Step not recorded
     }
 
+    #[test]
+    fn test_snapshot_releases_reservations() {
+        let config = StateMonitorConfig::new().with_gas_budget(1_000);
+        let mut monitor = StateMonitor::with_config(config.clone());
+        let _reservation = monitor
+            .gas_budget
+            .reserve(&[OperationType::LlmInference])
+            .unwrap();
+
+        let snapshot = monitor.snapshot();
+        assert_eq!(snapshot.gas.reserved(), 0);
+
+        // A snapshot carrying reserved gas from elsewhere restores released
+        let mut json = serde_json::to_value(&snapshot).unwrap();
+        json["gas"]["reserved"] = serde_json::json!(100);
+        let snapshot: MonitorSnapshot = serde_json::from_value(json).unwrap();
+        let restored = StateMonitor::restore(snapshot, config);
+        assert_eq!(restored.gas_budget.reserved(), 0);
+        assert_eq!(restored.gas_budget.available(), 1_000);
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let config = StateMonitorConfig::new().with_gas_budget(1_000);
+        let mut monitor = StateMonitor::with_config(config.clone());
+        monitor
+            .begin_step("read", OperationType::StateRead)
+            .unwrap();
+        monitor.end_step("config loaded").unwrap();
+        monitor.begin_step("call", OperationType::ToolCall).unwrap();
+        monitor.end_step("tool output").unwrap();
+
+        let json = serde_json::to_string(&monitor.snapshot()).unwrap();
+        let snapshot: MonitorSnapshot = serde_json::from_str(&json).unwrap();
+        let mut restored = StateMonitor::restore(snapshot, config);
+
+        assert_eq!(restored.gas_remaining(), monitor.gas_remaining());
+        assert_eq!(restored.step_count(), 2);
+        assert_eq!(
+            restored.context_frame_count(),
+            monitor.context_frame_count()
+        );
+        assert_eq!(
+            restored.gas_budget.breakdown(),
+            monitor.gas_budget.breakdown()
+        );
+        assert!(!restored.is_halted());
+
+        // Cycle detection continues from the restored path
+        assert!(restored
+            .begin_step("read", OperationType::StateRead)
+            .is_err());
+    }
+
+    #[test]
+    fn test_rollback_restores_exact_state() {
+        let config = StateMonitorConfig::new().with_gas_budget(1_000);
+        let mut monitor = StateMonitor::with_config(config);
+        monitor
+            .begin_step("plan", OperationType::StateRead)
+            .unwrap();
+        monitor.end_step("plan ready").unwrap();
+
+        let checkpoint = monitor.checkpoint();
+        let before = monitor.snapshot();
+        assert_eq!(checkpoint.step_count(), 1);
+
+        monitor
+            .begin_step("risky", OperationType::ToolCall)
+            .unwrap();
+        monitor.end_step("partial output").unwrap();
+        monitor.consume_gas(200).unwrap();
+        assert!(monitor
+            .begin_step("plan", OperationType::StateRead)
+            .is_err());
+        assert!(monitor.is_halted());
+
+        monitor.rollback(checkpoint).unwrap();
+        let after = monitor.snapshot();
+        assert_eq!(after.gas.remaining(), before.gas.remaining());
+        assert_eq!(after.gas.breakdown(), before.gas.breakdown());
+        assert_eq!(after.frames, before.frames);
+        assert_eq!(after.path, before.path);
+        assert_eq!(after.step_count, before.step_count);
+        assert_eq!(after.context_evicted, before.context_evicted);
+        assert!(!monitor.is_halted());
+        assert!(monitor.last_cycle().is_none());
+
+        // The rolled-back path still detects a repeat of the kept step
+        assert!(monitor
+            .begin_step("plan", OperationType::StateRead)
+            .is_err());
+    }
+
+    #[test]
+    fn test_security_rollback_rejects_foreign_checkpoint() {
+        let other = StateMonitor::new();
+        let mut monitor = StateMonitor::new();
+        assert!(matches!(
+            monitor.rollback(other.checkpoint()),
+            Err(MonitorError::InvalidState(_))
+        ));
+
+        // A checkpoint from before a reset cannot rewind the new context
+        monitor.begin_step("s1", OperationType::ToolCall).unwrap();
+        let stale = monitor.checkpoint();
+        monitor.reset();
+        assert!(monitor.rollback(stale).is_err());
+        assert_eq!(monitor.step_count(), 0);
+    }
+
+    #[test]
+    fn test_security_halted_monitor_restores_halted() {
+        let mut monitor = StateMonitor::new();
+        monitor.begin_step("a", OperationType::StateRead).unwrap();
+        monitor.end_step("r").unwrap();
+        assert!(monitor.begin_step("a", OperationType::StateRead).is_err());
+        assert!(monitor.is_halted());
+
+        let mut restored = StateMonitor::restore(monitor.snapshot(), StateMonitorConfig::new());
+        assert!(restored.is_halted());
+        assert!(restored.last_cycle().is_some());
+        assert!(restored.begin_step("b", OperationType::StateRead).is_err());
+    }
+
     #[test]
     fn test_security_halted_state_persists() {
         let mut monitor = StateMonitor::new();
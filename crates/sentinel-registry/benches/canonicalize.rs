@@ -0,0 +1,73 @@
+//! Canonicalization throughput on large, deeply nested tool schemas.
+//!
+//! Run with `cargo bench -p sentinel-registry`.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use sentinel_registry::canonicalize::{canonicalize_to_bytes, hash_canonical, hash_tool_schema};
+use sentinel_registry::ToolSchema;
+use serde_json::{json, Map, Value};
+
+/// Builds a JSON Schema `depth` levels deep with `width` properties per level.
+fn nested_schema(depth: usize, width: usize) -> Value {
+    let mut schema = json!({"type": "string", "description": "leaf"});
+    for level in 0..depth {
+        let mut properties = Map::new();
+        for i in (0..width).rev() {
+            properties.insert(
+                format!("field_{}_{}", level, i),
+                json!({
+                    "type": "string",
+                    "description": format!("Field {} at depth {}", i, level),
+                    "maxLength": 256,
+                }),
+            );
+        }
+        properties.insert("child".to_string(), schema);
+        schema = json!({
+            "type": "object",
+            "properties": properties,
+            "required": ["child"],
+            "additionalProperties": false,
+        });
+    }
+    schema
+}
+
+fn bench_canonicalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("canonicalize");
+    for (depth, width) in [(4, 8), (16, 32), (32, 64)] {
+        let schema = nested_schema(depth, width);
+        let id = format!("{}x{}", depth, width);
+        group.bench_with_input(BenchmarkId::new("bytes", &id), &schema, |b, s| {
+            b.iter(|| canonicalize_to_bytes(black_box(s)))
+        });
+        group.bench_with_input(BenchmarkId::new("hash", &id), &schema, |b, s| {
+            b.iter(|| hash_canonical(black_box(s)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_tool_schema(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tool_schema");
+    for (depth, width) in [(2, 4), (4, 8), (16, 32)] {
+        let schema = nested_schema(depth, width);
+        let tool = ToolSchema {
+            name: "search_files".to_string(),
+            description: "Search for files matching a pattern".to_string(),
+            input_schema: schema.clone(),
+            output_schema: schema,
+        };
+        let id = format!("{}x{}", depth, width);
+        group.bench_with_input(BenchmarkId::new("via_value", &id), &tool, |b, t| {
+            b.iter(|| hash_canonical(&serde_json::to_value(black_box(t)).unwrap()))
+        });
+        group.bench_with_input(BenchmarkId::new("direct", &id), &tool, |b, t| {
+            b.iter(|| hash_tool_schema(black_box(t)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_canonicalize, bench_tool_schema);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+//! Fuzzes RFC 8785 canonicalization with arbitrary JSON.
+//!
+//! Invariants:
+//! - Canonicalization is idempotent: canonicalizing the canonical form
+//!   yields the same bytes.
+//! - Canonicalization ignores object key order: the same document written
+//!   with every object's keys reversed has the same canonical form and hash.
+//!
+//! The crate enables serde_json's `preserve_order`, so parsed objects keep
+//! the key order of the raw input and the reversal reaches the
+//! canonicalizer; with sorted maps both sides would be identical before
+//! canonicalization.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sentinel_registry::canonicalize::{canonicalize, hash_canonical, parse_strict};
+use serde_json::Value;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(raw) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(value) = parse_strict(raw) else {
+        return;
+    };
+    let canonical = canonicalize(&value);
+
+    let reparsed: Value = serde_json::from_str(&canonical).expect("canonical form is valid JSON");
+    assert_eq!(canonicalize(&reparsed), canonical, "not idempotent");
+
+    let reversed: Value =
+        serde_json::from_str(&with_reversed_keys(&value)).expect("reversed form is valid JSON");
+    assert_eq!(canonicalize(&reversed), canonical, "key order leaked");
+    assert_eq!(hash_canonical(&reversed), hash_canonical(&value));
+});
+
+/// Serializes `value` with every object's keys in reverse order.
+fn with_reversed_keys(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let fields: Vec<String> = map
+                .iter()
+                .rev()
+                .map(|(key, value)| {
+                    format!(
+                        "{}:{}",
+                        Value::from(key.as_str()),
+                        with_reversed_keys(value)
+                    )
+                })
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(with_reversed_keys).collect();
+            format!("[{}]", items.join(","))
+        }
+        other => other.to_string(),
+    }
+}
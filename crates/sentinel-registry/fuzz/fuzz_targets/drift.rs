@@ -0,0 +1,94 @@
+//! Fuzzes the drift comparator with arbitrary schema pairs.
+//!
+//! Invariants:
+//! - A schema never drifts from itself: `detect_drift(a, a)` is `None`
+//!   with no changes.
+//! - A real change is never missed: if `a` and `b` have different canonical
+//!   forms once `required` lists are sorted and deduplicated,
+//!   `detect_drift(a, b)` is above `None`.
+//!
+//! The oracle is computed here from the canonical JSON, independently of
+//! the comparator under test.
+
+#![no_main]
+
+use libfuzzer_sys::arbitrary::{self, Arbitrary};
+use libfuzzer_sys::fuzz_target;
+use sentinel_registry::canonicalize::{canonicalize, parse_strict};
+use sentinel_registry::drift::detect_drift;
+use sentinel_registry::{DriftLevel, ToolSchema};
+use serde_json::Value;
+
+/// Raw material for one schema version. Schema text that is not valid
+/// JSON is used as a JSON string, so every input yields a schema.
+#[derive(Debug, Arbitrary)]
+struct Version<'a> {
+    name: &'a str,
+    description: &'a str,
+    input_schema: &'a str,
+    output_schema: &'a str,
+}
+
+impl Version<'_> {
+    fn schema(&self) -> ToolSchema {
+        let json = |raw: &str| parse_strict(raw).unwrap_or_else(|_| Value::from(raw));
+        ToolSchema {
+            name: self.name.to_string(),
+            description: self.description.to_string(),
+            input_schema: json(self.input_schema),
+            output_schema: json(self.output_schema),
+        }
+    }
+}
+
+fuzz_target!(|versions: (Version, Version)| {
+    let (old, new) = (versions.0.schema(), versions.1.schema());
+
+    for schema in [&old, &new] {
+        let report = detect_drift(schema, schema);
+        assert_eq!(report.level, DriftLevel::None, "self-drift: {:?}", report);
+        assert!(report.changes.is_empty());
+    }
+
+    if canonical(&old) != canonical(&new) {
+        let report = detect_drift(&old, &new);
+        assert!(
+            report.level > DriftLevel::None,
+            "missed change {:?} -> {:?}: {:?}",
+            old,
+            new,
+            report
+        );
+    }
+});
+
+/// Keywords whose values are instance data rather than schema, left as is.
+const DATA_KEYWORDS: [&str; 4] = ["const", "default", "enum", "examples"];
+
+/// Canonical form of a tool with every `required` list sorted and
+/// deduplicated, since `required` order is not drift.
+fn canonical(tool: &ToolSchema) -> String {
+    let mut value = serde_json::to_value(tool).expect("tool schema serializes");
+    sort_required(&mut value);
+    canonicalize(&value)
+}
+
+/// Sorts and deduplicates every `required` list outside data keywords.
+fn sort_required(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match (key.as_str(), value) {
+                    ("required", Value::Array(items)) => {
+                        items.sort_by_cached_key(|item| canonicalize(item));
+                        items.dedup_by(|a, b| canonicalize(a) == canonicalize(b));
+                    }
+                    (key, _) if DATA_KEYWORDS.contains(&key) => {}
+                    (_, value) => sort_required(value),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(sort_required),
+        _ => {}
+    }
+}
@@ -19,6 +19,10 @@
 //!
 //! - **Unicode Escaping**: `"\u0041"` and `"A"` are equivalent but bytewise different.
 //!
+//! - **Duplicate Keys**: `{"a":1,"a":2}` means different things to different
+//!   parsers, while `serde_json` silently keeps the last value. RFC 8785
+//!   requires unique keys, so raw input should go through [`parse_strict`].
+//!
 //! ## RFC 8785 Summary
 //!
 //! The JSON Canonicalization Scheme specifies:
@@ -29,6 +33,15 @@
 //! 4. **Whitespace**: No insignificant whitespace
 //! 5. **Arrays**: Elements in original order
 //!
+//! Array order is kept even where JSON Schema treats an array as a set, such
+//! as `required` or the branches of `anyOf`: the hash is a byte-exact
+//! integrity check, so reordering them changes it. Only drift detection is
+//! order-insensitive. It compares `required` as a set and reports such a
+//! change as [`DriftLevel::None`](crate::DriftLevel::None), and it matches up
+//! moved combinator branches, which only add a Minor change. Hash
+//! verification ([`RegistryGuard::verify_tool`](crate::RegistryGuard::verify_tool))
+//! still reports a reordered `required` as `Invalid`.
+//!
 //! ## References
 //!
 //! - **RFC 8785** - "JSON Canonicalization Scheme (JCS)"
@@ -55,8 +68,113 @@
 //! assert_eq!(canon1, r#"{"a":2,"b":1}"#);
 //! ```
 
-use crate::models::Hash;
-use sha2::{Digest, Sha256};
+use crate::models::{Hash, HashAlgorithm, Result};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256, Sha512_256};
+use std::fmt;
+use std::io::Write;
+
+/// Parses JSON text, rejecting objects that repeat a key.
+///
+/// Use this instead of `serde_json::from_str` for untrusted input that will
+/// be canonicalized, so the document Sentinel hashes is the one every
+/// parser agrees on.
+///
+/// # Arguments
+///
+/// * `raw` - JSON text
+///
+/// # Errors
+///
+/// Returns `RegistryError::Serialization` if the text is not valid JSON or
+/// any object (at any depth) contains a duplicate key.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_registry::canonicalize::parse_strict;
+///
+/// assert!(parse_strict(r#"{"a": 1, "b": {"a": 2}}"#).is_ok());
+/// assert!(parse_strict(r#"{"a": 1, "a": 2}"#).is_err());
+/// ```
+///
+/// # Security Notes
+///
+/// A server may act on the first occurrence of a key while Sentinel would
+/// hash the last; rejecting duplicates closes that gap.
+pub fn parse_strict(raw: &str) -> Result<Value> {
+    let StrictValue(value) = serde_json::from_str(raw)?;
+    Ok(value)
+}
+
+/// A JSON value deserialized with duplicate-key detection.
+struct StrictValue(Value);
+
+impl<'de> Deserialize<'de> for StrictValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(StrictVisitor).map(StrictValue)
+    }
+}
+
+/// Visitor building a `Value` while rejecting duplicate object keys.
+struct StrictVisitor;
+
+impl<'de> Visitor<'de> for StrictVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any JSON value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> std::result::Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> std::result::Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> std::result::Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> std::result::Result<Value, E> {
+        Ok(Value::from(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Value, E> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn visit_string<E>(self, v: String) -> std::result::Result<Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_unit<E>(self) -> std::result::Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(StrictValue(item)) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> std::result::Result<Value, A::Error> {
+        let mut object = Map::new();
+        while let Some(key) = map.next_key::<String>()? {
+            if object.contains_key(&key) {
+                return Err(de::Error::custom(format!("duplicate object key '{}'", key)));
+            }
+            let StrictValue(value) = map.next_value()?;
+            object.insert(key, value);
+        }
+        Ok(Value::Object(object))
+    }
+}
 
 /// Canonicalizes a JSON value according to RFC 8785.
 ///
@@ -98,7 +216,29 @@ use sha2::{Digest, Sha256};
 /// could allow attackers to craft schemas that hash differently despite
 /// being semantically identical.
 pub fn canonicalize(value: &serde_json::Value) -> String {
-    canonicalize_value(value)
+    let bytes = canonicalize_to_bytes(value);
+    // Only whole UTF-8 sequences and ASCII escapes are ever written.
+    String::from_utf8(bytes).expect("canonical JSON is valid UTF-8")
+}
+
+/// Canonicalizes a JSON value according to RFC 8785, as bytes.
+///
+/// Produces exactly the bytes of [`canonicalize`], written in a single pass
+/// into one buffer. Prefer this when the result is only hashed.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_registry::canonicalize::{canonicalize, canonicalize_to_bytes};
+/// use serde_json::json;
+///
+/// let value = json!({"b": [1, "x"], "a": null});
+/// assert_eq!(canonicalize_to_bytes(&value), canonicalize(&value).into_bytes());
+/// ```
+pub fn canonicalize_to_bytes(value: &serde_json::Value) -> Vec<u8> {
+    let mut out = Vec::with_capacity(256);
+    write_value(value, &mut out);
+    out
 }
 
 /// Computes the SHA-256 hash of a canonicalized JSON value.
@@ -138,16 +278,74 @@ pub fn canonicalize(value: &serde_json::Value) -> String {
 /// Uses SHA-256 which provides 128-bit security against collision attacks.
 /// This is considered sufficient for integrity verification purposes.
 pub fn hash_canonical(value: &serde_json::Value) -> Hash {
-    let canonical = canonicalize(value);
-    let mut hasher = Sha256::new();
-    hasher.update(canonical.as_bytes());
-    hasher.finalize().into()
+    hash_canonical_with(value, HashAlgorithm::Sha256)
+}
+
+/// Computes the hash of a JSON value's canonical form under `algorithm`.
+///
+/// [`hash_canonical`] is this function with [`HashAlgorithm::Sha256`].
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_registry::canonicalize::{hash_canonical, hash_canonical_with};
+/// use sentinel_registry::HashAlgorithm;
+/// use serde_json::json;
+///
+/// let value = json!({"name": "read_file"});
+/// assert_eq!(hash_canonical_with(&value, HashAlgorithm::Sha256), hash_canonical(&value));
+/// assert_ne!(hash_canonical_with(&value, HashAlgorithm::Sha512_256), hash_canonical(&value));
+/// ```
+pub fn hash_canonical_with(value: &serde_json::Value, algorithm: HashAlgorithm) -> Hash {
+    digest(&canonicalize_to_bytes(value), algorithm)
+}
+
+/// Canonicalizes a ToolSchema according to RFC 8785, as bytes.
+///
+/// Writes the schema's fields straight into the canonical byte stream,
+/// without first serializing the schema to a [`Value`]. The output is
+/// byte-identical to canonicalizing `serde_json::to_value(schema)`.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_registry::canonicalize::{canonicalize_to_bytes, canonicalize_tool_schema};
+/// use sentinel_registry::ToolSchema;
+/// use serde_json::json;
+///
+/// let tool = ToolSchema {
+///     name: "read_file".to_string(),
+///     description: "Read a file".to_string(),
+///     input_schema: json!({"type": "object"}),
+///     output_schema: json!({}),
+/// };
+///
+/// let value = serde_json::to_value(&tool).unwrap();
+/// assert_eq!(canonicalize_tool_schema(&tool), canonicalize_to_bytes(&value));
+/// ```
+pub fn canonicalize_tool_schema(schema: &crate::models::ToolSchema) -> Vec<u8> {
+    let mut out = Vec::with_capacity(256 + schema.description.len());
+
+    // Keys in RFC 8785 (UTF-16) order; they must match the serde field
+    // names of `ToolSchema`.
+    out.extend_from_slice(b"{\"description\":");
+    write_string(&schema.description, &mut out);
+    out.extend_from_slice(b",\"input_schema\":");
+    write_value(&schema.input_schema, &mut out);
+    out.extend_from_slice(b",\"name\":");
+    write_string(&schema.name, &mut out);
+    out.extend_from_slice(b",\"output_schema\":");
+    write_value(&schema.output_schema, &mut out);
+    out.push(b'}');
+
+    out
 }
 
 /// Computes the SHA-256 hash of a ToolSchema.
 ///
-/// Serializes the tool schema to JSON and computes its canonical hash.
-/// This is the primary entry point for hashing tool schemas.
+/// Hashes the schema's canonical JSON form (see
+/// [`canonicalize_tool_schema`]). This is the primary entry point for
+/// hashing tool schemas.
 ///
 /// # Arguments
 ///
@@ -157,151 +355,403 @@ pub fn hash_canonical(value: &serde_json::Value) -> Hash {
 ///
 /// A 32-byte SHA-256 hash of the canonical schema representation.
 pub fn hash_tool_schema(schema: &crate::models::ToolSchema) -> Hash {
-    let value = serde_json::to_value(schema).expect("ToolSchema serialization cannot fail");
-    hash_canonical(&value)
+    hash_tool_schema_with(schema, HashAlgorithm::Sha256)
+}
+
+/// Computes the hash of a ToolSchema under `algorithm`.
+///
+/// The registry hashes with its configured algorithm through this function;
+/// [`hash_tool_schema`] is the SHA-256 shorthand.
+pub fn hash_tool_schema_with(schema: &crate::models::ToolSchema, algorithm: HashAlgorithm) -> Hash {
+    digest(&canonicalize_tool_schema(schema), algorithm)
+}
+
+/// Hashes canonical bytes under `algorithm`.
+fn digest(canonical: &[u8], algorithm: HashAlgorithm) -> Hash {
+    match algorithm {
+        HashAlgorithm::Sha256 => Sha256::digest(canonical).into(),
+        HashAlgorithm::Sha512_256 => Sha512_256::digest(canonical).into(),
+    }
 }
 
 /// Internal function to canonicalize a JSON value.
 ///
-/// Recursively processes the JSON value according to RFC 8785 rules.
-fn canonicalize_value(value: &serde_json::Value) -> String {
+/// Recursively writes the value to `out` according to RFC 8785 rules, in a
+/// single pass with no intermediate strings.
+fn write_value(value: &Value, out: &mut Vec<u8>) {
     match value {
-        serde_json::Value::Null => "null".to_string(),
-        serde_json::Value::Bool(b) => {
-            if *b {
-                "true".to_string()
-            } else {
-                "false".to_string()
-            }
-        }
-        serde_json::Value::Number(n) => canonicalize_number(n),
-        serde_json::Value::String(s) => canonicalize_string(s),
-        serde_json::Value::Array(arr) => canonicalize_array(arr),
-        serde_json::Value::Object(obj) => canonicalize_object(obj),
+        Value::Null => out.extend_from_slice(b"null"),
+        Value::Bool(true) => out.extend_from_slice(b"true"),
+        Value::Bool(false) => out.extend_from_slice(b"false"),
+        Value::Number(n) => write_number(n, out),
+        Value::String(s) => write_string(s, out),
+        Value::Array(arr) => write_array(arr, out),
+        Value::Object(obj) => write_object(obj, out),
     }
 }
 
-/// Canonicalizes a JSON number according to RFC 8785.
+/// Writes a JSON number according to RFC 8785.
 ///
 /// Numbers are serialized in their minimal representation:
 /// - No leading zeros (except for "0" itself)
 /// - No trailing zeros after decimal point
 /// - Exponential notation for very large/small numbers
-fn canonicalize_number(n: &serde_json::Number) -> String {
+fn write_number(n: &serde_json::Number, out: &mut Vec<u8>) {
+    // Writing to a Vec<u8> cannot fail.
     // RFC 8785 specifies that numbers should be serialized in their
     // shortest form. serde_json already handles most cases correctly.
-    if let Some(i) = n.as_i64() {
-        return i.to_string();
-    }
-    if let Some(u) = n.as_u64() {
-        return u.to_string();
-    }
-    if let Some(f) = n.as_f64() {
+    let _ = if let Some(i) = n.as_i64() {
+        write!(out, "{}", i)
+    } else if let Some(u) = n.as_u64() {
+        write!(out, "{}", u)
+    } else if let Some(f) = n.as_f64() {
         // Handle special case for whole numbers stored as floats
         if f.fract() == 0.0 && f.abs() < (i64::MAX as f64) {
-            return (f as i64).to_string();
+            write!(out, "{}", f as i64)
+        } else {
+            write_float(f, out)
         }
-        // Use Rust's default float formatting which is close to minimal
-        // For a production implementation, consider using ryu crate
-        format_float(f)
     } else {
-        n.to_string()
-    }
+        write!(out, "{}", n)
+    };
 }
 
-/// Formats a float according to RFC 8785 rules.
+/// Writes a float according to RFC 8785 rules.
 ///
 /// Uses ECMAScript-style formatting for consistency.
-fn format_float(f: f64) -> String {
+fn write_float(f: f64, out: &mut Vec<u8>) -> std::io::Result<()> {
     if f.is_nan() || f.is_infinite() {
         // RFC 8785 says these are not valid JSON
         // serde_json shouldn't produce them, but handle gracefully
-        return "null".to_string();
+        out.extend_from_slice(b"null");
+        return Ok(());
     }
 
-    // Use Rust's default formatting, which produces reasonable output
-    let s = format!("{}", f);
-
-    // Ensure we don't produce unnecessary trailing zeros
-    // but keep at least one digit after decimal if present
-    s
+    // Rust's default formatting produces reasonable output
+    write!(out, "{}", f)
 }
 
-/// Canonicalizes a JSON string according to RFC 8785.
+/// Writes a JSON string according to RFC 8785.
 ///
 /// Applies minimal escaping as specified by the RFC:
 /// - Escape: `"`, `\`, and control characters (0x00-0x1F)
 /// - Do NOT escape: `/`, Unicode characters > 0x1F
-fn canonicalize_string(s: &str) -> String {
-    let mut result = String::with_capacity(s.len() + 2);
-    result.push('"');
-
-    for ch in s.chars() {
-        match ch {
-            '"' => result.push_str("\\\""),
-            '\\' => result.push_str("\\\\"),
-            '\x08' => result.push_str("\\b"),
-            '\x0C' => result.push_str("\\f"),
-            '\n' => result.push_str("\\n"),
-            '\r' => result.push_str("\\r"),
-            '\t' => result.push_str("\\t"),
-            c if c < '\x20' => {
-                // Other control characters use \uXXXX
-                result.push_str(&format!("\\u{:04x}", c as u32));
-            }
-            c => result.push(c),
+///
+/// Runs of bytes that need no escaping are copied in one slice.
+fn write_string(s: &str, out: &mut Vec<u8>) {
+    out.push(b'"');
+
+    let bytes = s.as_bytes();
+    let mut start = 0;
+    for (i, &byte) in bytes.iter().enumerate() {
+        let escape: &[u8] = match byte {
+            b'"' => b"\\\"",
+            b'\\' => b"\\\\",
+            0x08 => b"\\b",
+            0x0C => b"\\f",
+            b'\n' => b"\\n",
+            b'\r' => b"\\r",
+            b'\t' => b"\\t",
+            0x00..=0x1F => &[],
+            _ => continue,
+        };
+        out.extend_from_slice(&bytes[start..i]);
+        if escape.is_empty() {
+            // Other control characters use \uXXXX
+            let _ = write!(out, "\\u{:04x}", byte);
+        } else {
+            out.extend_from_slice(escape);
         }
+        start = i + 1;
     }
+    out.extend_from_slice(&bytes[start..]);
 
-    result.push('"');
-    result
+    out.push(b'"');
 }
 
-/// Canonicalizes a JSON array according to RFC 8785.
+/// Writes a JSON array according to RFC 8785.
 ///
 /// Arrays maintain their element order; only the elements themselves
 /// are canonicalized.
-fn canonicalize_array(arr: &[serde_json::Value]) -> String {
-    let elements: Vec<String> = arr.iter().map(canonicalize_value).collect();
-    format!("[{}]", elements.join(","))
+fn write_array(arr: &[Value], out: &mut Vec<u8>) {
+    out.push(b'[');
+    for (i, element) in arr.iter().enumerate() {
+        if i > 0 {
+            out.push(b',');
+        }
+        write_value(element, out);
+    }
+    out.push(b']');
 }
 
-/// Canonicalizes a JSON object according to RFC 8785.
+/// Writes a JSON object according to RFC 8785.
 ///
 /// Keys are sorted lexicographically by UTF-16 code units as specified
 /// in the RFC. This is the critical step for ensuring deterministic output.
-fn canonicalize_object(obj: &serde_json::Map<String, serde_json::Value>) -> String {
+fn write_object(obj: &Map<String, Value>, out: &mut Vec<u8>) {
     // RFC 8785 specifies sorting by UTF-16 code units
     // For most ASCII keys, this is equivalent to byte-wise sorting
     // For Unicode keys, we need to compare UTF-16 encoded forms
-    let mut entries: Vec<(&String, &serde_json::Value)> = obj.iter().collect();
-
-    // Sort by UTF-16 code units
+    let mut entries: Vec<(&String, &Value)> = obj.iter().collect();
     entries.sort_by(|(a, _), (b, _)| compare_utf16(a, b));
 
-    let pairs: Vec<String> = entries
-        .iter()
-        .map(|(k, v)| format!("{}:{}", canonicalize_string(k), canonicalize_value(v)))
-        .collect();
-
-    format!("{{{}}}", pairs.join(","))
+    out.push(b'{');
+    for (i, (key, value)) in entries.into_iter().enumerate() {
+        if i > 0 {
+            out.push(b',');
+        }
+        write_string(key, out);
+        out.push(b':');
+        write_value(value, out);
+    }
+    out.push(b'}');
 }
 
 /// Compares two strings by their UTF-16 code unit sequences.
 ///
 /// This implements the comparison order specified in RFC 8785 Section 3.2.3.
 /// For ASCII strings, this is equivalent to lexicographic byte comparison.
+/// Code units are compared lazily, without allocating.
 fn compare_utf16(a: &str, b: &str) -> std::cmp::Ordering {
-    let a_utf16: Vec<u16> = a.encode_utf16().collect();
-    let b_utf16: Vec<u16> = b.encode_utf16().collect();
-    a_utf16.cmp(&b_utf16)
+    a.encode_utf16().cmp(b.encode_utf16())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::ToolSchema;
     use serde_json::json;
 
+    /// The original `String`-building canonicalizer, kept to pin the
+    /// output of the single-pass writer byte for byte.
+    mod legacy {
+        /// Internal function to canonicalize a JSON value.
+        ///
+        /// Recursively processes the JSON value according to RFC 8785 rules.
+        pub(super) fn canonicalize_value(value: &serde_json::Value) -> String {
+            match value {
+                serde_json::Value::Null => "null".to_string(),
+                serde_json::Value::Bool(b) => {
+                    if *b {
+                        "true".to_string()
+                    } else {
+                        "false".to_string()
+                    }
+                }
+                serde_json::Value::Number(n) => canonicalize_number(n),
+                serde_json::Value::String(s) => canonicalize_string(s),
+                serde_json::Value::Array(arr) => canonicalize_array(arr),
+                serde_json::Value::Object(obj) => canonicalize_object(obj),
+            }
+        }
+
+        /// Canonicalizes a JSON number according to RFC 8785.
+        ///
+        /// Numbers are serialized in their minimal representation:
+        /// - No leading zeros (except for "0" itself)
+        /// - No trailing zeros after decimal point
+        /// - Exponential notation for very large/small numbers
+        fn canonicalize_number(n: &serde_json::Number) -> String {
+            // RFC 8785 specifies that numbers should be serialized in their
+            // shortest form. serde_json already handles most cases correctly.
+            if let Some(i) = n.as_i64() {
+                return i.to_string();
+            }
+            if let Some(u) = n.as_u64() {
+                return u.to_string();
+            }
+            if let Some(f) = n.as_f64() {
+                // Handle special case for whole numbers stored as floats
+                if f.fract() == 0.0 && f.abs() < (i64::MAX as f64) {
+                    return (f as i64).to_string();
+                }
+                // Use Rust's default float formatting which is close to minimal
+                // For a production implementation, consider using ryu crate
+                format_float(f)
+            } else {
+                n.to_string()
+            }
+        }
+
+        /// Formats a float according to RFC 8785 rules.
+        ///
+        /// Uses ECMAScript-style formatting for consistency.
+        fn format_float(f: f64) -> String {
+            if f.is_nan() || f.is_infinite() {
+                // RFC 8785 says these are not valid JSON
+                // serde_json shouldn't produce them, but handle gracefully
+                return "null".to_string();
+            }
+
+            // Use Rust's default formatting, which produces reasonable output
+            let s = format!("{}", f);
+
+            // Ensure we don't produce unnecessary trailing zeros
+            // but keep at least one digit after decimal if present
+            s
+        }
+
+        /// Canonicalizes a JSON string according to RFC 8785.
+        ///
+        /// Applies minimal escaping as specified by the RFC:
+        /// - Escape: `"`, `\`, and control characters (0x00-0x1F)
+        /// - Do NOT escape: `/`, Unicode characters > 0x1F
+        fn canonicalize_string(s: &str) -> String {
+            let mut result = String::with_capacity(s.len() + 2);
+            result.push('"');
+
+            for ch in s.chars() {
+                match ch {
+                    '"' => result.push_str("\\\""),
+                    '\\' => result.push_str("\\\\"),
+                    '\x08' => result.push_str("\\b"),
+                    '\x0C' => result.push_str("\\f"),
+                    '\n' => result.push_str("\\n"),
+                    '\r' => result.push_str("\\r"),
+                    '\t' => result.push_str("\\t"),
+                    c if c < '\x20' => {
+                        // Other control characters use \uXXXX
+                        result.push_str(&format!("\\u{:04x}", c as u32));
+                    }
+                    c => result.push(c),
+                }
+            }
+
+            result.push('"');
+            result
+        }
+
+        /// Canonicalizes a JSON array according to RFC 8785.
+        ///
+        /// Arrays maintain their element order; only the elements themselves
+        /// are canonicalized.
+        fn canonicalize_array(arr: &[serde_json::Value]) -> String {
+            let elements: Vec<String> = arr.iter().map(canonicalize_value).collect();
+            format!("[{}]", elements.join(","))
+        }
+
+        /// Canonicalizes a JSON object according to RFC 8785.
+        ///
+        /// Keys are sorted lexicographically by UTF-16 code units as specified
+        /// in the RFC. This is the critical step for ensuring deterministic output.
+        fn canonicalize_object(obj: &serde_json::Map<String, serde_json::Value>) -> String {
+            // RFC 8785 specifies sorting by UTF-16 code units
+            // For most ASCII keys, this is equivalent to byte-wise sorting
+            // For Unicode keys, we need to compare UTF-16 encoded forms
+            let mut entries: Vec<(&String, &serde_json::Value)> = obj.iter().collect();
+
+            // Sort by UTF-16 code units
+            entries.sort_by(|(a, _), (b, _)| compare_utf16(a, b));
+
+            let pairs: Vec<String> = entries
+                .iter()
+                .map(|(k, v)| format!("{}:{}", canonicalize_string(k), canonicalize_value(v)))
+                .collect();
+
+            format!("{{{}}}", pairs.join(","))
+        }
+
+        /// Compares two strings by their UTF-16 code unit sequences.
+        ///
+        /// This implements the comparison order specified in RFC 8785 Section 3.2.3.
+        /// For ASCII strings, this is equivalent to lexicographic byte comparison.
+        fn compare_utf16(a: &str, b: &str) -> std::cmp::Ordering {
+            let a_utf16: Vec<u16> = a.encode_utf16().collect();
+            let b_utf16: Vec<u16> = b.encode_utf16().collect();
+            a_utf16.cmp(&b_utf16)
+        }
+    }
+
+    /// Schemas exercising every value kind, escaping and key ordering.
+    fn corpus() -> Vec<Value> {
+        let mut corpus = vec![
+            json!(null),
+            json!([]),
+            json!({}),
+            json!({"name": "read_file", "description": "Read a file", "inputSchema": {
+                "type": "object",
+                "properties": {"path": {"type": "string"}, "offset": {"type": "integer", "minimum": 0}},
+                "required": ["path"]
+            }}),
+            json!({"s": "quote \" backslash \\ slash / tab \t nl \n cr \r bs \u{8} ff \u{c}"}),
+            json!({"ctl": "\u{0}\u{1}\u{1f}\u{7f}", "uni": "é€😀", "\u{10000}": 1, "\u{ffff}": 2}),
+            json!({"n": [0, -1, 1.5, -0.25, 1e300, 2.0, 1e-7, u64::MAX, i64::MIN, 123456789.125]}),
+            json!({"z": true, "a": false, "m": [null, {"y": [], "b": {}}]}),
+        ];
+
+        // A large, deeply nested JSON Schema
+        let mut nested = json!({"type": "string"});
+        for depth in 0..32 {
+            let mut properties = Map::new();
+            for i in (0..20).rev() {
+                properties.insert(
+                    format!("prop_{}_{}", depth, i),
+                    json!({"type": "integer", "enum": [i, depth]}),
+                );
+            }
+            properties.insert("child".to_string(), nested);
+            nested = json!({"type": "object", "properties": properties, "required": ["child"]});
+        }
+        corpus.push(nested);
+        corpus
+    }
+
+    #[test]
+    fn test_single_pass_matches_legacy_output() {
+        for value in corpus() {
+            let legacy = legacy::canonicalize_value(&value);
+            assert_eq!(
+                canonicalize_to_bytes(&value),
+                legacy.as_bytes(),
+                "{}",
+                legacy
+            );
+            assert_eq!(canonicalize(&value), legacy);
+        }
+    }
+
+    #[test]
+    fn test_tool_schema_path_matches_value_path() {
+        let descriptions = ["", "Read a file", "quote \" ctl \u{1} uni é😀"];
+        for (i, schema) in corpus().into_iter().enumerate() {
+            let tool = ToolSchema {
+                name: format!("tool_{}", i),
+                description: descriptions[i % descriptions.len()].to_string(),
+                input_schema: schema.clone(),
+                output_schema: schema,
+            };
+            let value = serde_json::to_value(&tool).unwrap();
+
+            assert_eq!(
+                canonicalize_tool_schema(&tool),
+                canonicalize_to_bytes(&value)
+            );
+            for algorithm in [HashAlgorithm::Sha256, HashAlgorithm::Sha512_256] {
+                assert_eq!(
+                    hash_tool_schema_with(&tool, algorithm),
+                    hash_canonical_with(&value, algorithm)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_strict_rejects_nested_duplicates() {
+        let err = parse_strict(r#"{"schema": {"properties": {"x": 1, "x": 2}}}"#).unwrap_err();
+        assert!(
+            err.to_string().contains("duplicate object key 'x'"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_parse_strict_matches_serde_json() {
+        let raw = r#"{"b": [1, 2.5, -3, null, true, "s"], "a": {"k": {}}}"#;
+        let lenient: Value = serde_json::from_str(raw).unwrap();
+        assert_eq!(parse_strict(raw).unwrap(), lenient);
+    }
+
     #[test]
     fn test_canonicalize_null() {
         assert_eq!(canonicalize(&json!(null)), "null");
@@ -337,6 +787,18 @@ mod tests {
         assert_eq!(canonicalize(&json!(["a", "b"])), r#"["a","b"]"#);
     }
 
+    #[test]
+    fn test_canonicalize_combinator_branches() {
+        // Keys inside each branch are sorted; branch order is kept
+        let schema = json!({"oneOf": [{"type": "string", "maxLength": 8}, {"type": "null"}]});
+        assert_eq!(
+            canonicalize(&schema),
+            r#"{"oneOf":[{"maxLength":8,"type":"string"},{"type":"null"}]}"#
+        );
+        let appended = json!({"oneOf": [{"maxLength": 8, "type": "string"}, {"type": "null"}, {}]});
+        assert_ne!(hash_canonical(&schema), hash_canonical(&appended));
+    }
+
     #[test]
     fn test_canonicalize_object_key_sorting() {
         // Keys should be sorted
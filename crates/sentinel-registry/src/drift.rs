@@ -21,6 +21,15 @@
 //! - **Type Coercion Attacks**: Changing parameter types to enable injection
 //!   (e.g., string -> object).
 //!
+//! - **Constraint Loosening**: Dropping a string `pattern` or widening
+//!   `maxLength` so previously rejected payloads get through.
+//!
+//! - **Open Objects**: Relaxing `additionalProperties: false` so a tool
+//!   accepts arbitrary extra parameters.
+//!
+//! - **Branch Smuggling**: Appending an alternative to a `oneOf` or `anyOf`
+//!   so a payload shape the tool used to reject matches the new branch.
+//!
 //! ## Drift Categories
 //!
 //! | Level | Description | Example | Response |
@@ -30,6 +39,19 @@
 //! | Major | Functional changes | New required parameter | Alert, review |
 //! | Critical | Fundamental changes | Different purpose | Block, investigate |
 //!
+//! Teams whose descriptions are free-form docs can set
+//! [`DriftConfig::ignore_description`]: description changes are still listed
+//! in the report but no longer raise its level.
+//!
+//! ## Drift Velocity
+//!
+//! A single report compares one version with the next, so an attacker whose
+//! small changes are approved one at a time never trips it. [`drift_velocity`]
+//! looks at a run of accepted versions instead: each step is weighted by its
+//! level and the weights are summed, and the oldest version is compared
+//! straight to the newest. Many Minor steps add up to a high velocity even
+//! though none of them was alarming on its own.
+//!
 //! ## Detection Philosophy
 //!
 //! This implementation uses structural comparison rather than semantic analysis.
@@ -45,10 +67,35 @@
 //! - JSON Schema specification (draft-07)
 //! - OWASP API Security Top 10
 
-use crate::canonicalize::hash_canonical;
-use crate::models::{DriftLevel, DriftReport, ToolSchema};
+use crate::canonicalize::hash_canonical_with;
+use crate::models::{DriftLevel, DriftReport, DriftVelocity, HashAlgorithm, ToolSchema};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Options controlling how drift is scored.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_registry::drift::DriftConfig;
+///
+/// let docs_are_free_form = DriftConfig {
+///     ignore_description: true,
+/// };
+/// assert!(!DriftConfig::default().ignore_description);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DriftConfig {
+    /// Report description changes without letting them raise the level.
+    ///
+    /// Only name and schema changes then count toward severity.
+    #[serde(default)]
+    pub ignore_description: bool,
+}
+
+/// Default drift velocity at which a tool is flagged (five Minor steps).
+pub const DEFAULT_VELOCITY_THRESHOLD: u32 = 5;
+
 /// Detects and categorizes drift between two tool schema versions.
 ///
 /// Compares the old (registered) and new (observed) schemas, identifying
@@ -96,11 +143,48 @@ use serde_json::Value;
 /// This function is conservative: when in doubt, it reports higher severity.
 /// False positives are preferable to false negatives in security contexts.
 pub fn detect_drift(old: &ToolSchema, new: &ToolSchema) -> DriftReport {
+    detect_drift_with(old, new, &DriftConfig::default())
+}
+
+/// Detects drift like [`detect_drift`], scored according to `config`.
+///
+/// # Arguments
+///
+/// * `old` - The previously registered schema (baseline)
+/// * `new` - The currently observed schema (to verify)
+/// * `config` - Scoring options
+///
+/// # Security Notes
+///
+/// With `ignore_description`, a tool can change what it tells the model it
+/// does without raising the level. Descriptions are model-visible, so only
+/// enable this where they are reviewed some other way.
+pub fn detect_drift_with(old: &ToolSchema, new: &ToolSchema, config: &DriftConfig) -> DriftReport {
+    detect_drift_with_algorithm(old, new, config, HashAlgorithm::Sha256)
+}
+
+/// Detects drift like [`detect_drift_with`], hashing with `algorithm`.
+///
+/// Registries that were migrated with `rehash_all` use this so the hashes in
+/// the report match the ones in storage.
+///
+/// # Arguments
+///
+/// * `old` - The previously registered schema (baseline)
+/// * `new` - The currently observed schema (to verify)
+/// * `config` - Scoring options
+/// * `algorithm` - Algorithm for `old_hash` and `new_hash`
+pub fn detect_drift_with_algorithm(
+    old: &ToolSchema,
+    new: &ToolSchema,
+    config: &DriftConfig,
+    algorithm: HashAlgorithm,
+) -> DriftReport {
     let old_value = serde_json::to_value(old).expect("ToolSchema serialization cannot fail");
     let new_value = serde_json::to_value(new).expect("ToolSchema serialization cannot fail");
 
-    let old_hash = hash_canonical(&old_value);
-    let new_hash = hash_canonical(&new_value);
+    let old_hash = hash_canonical_with(&old_value, algorithm);
+    let new_hash = hash_canonical_with(&new_value, algorithm);
 
     // Fast path: identical hashes mean no drift
     if old_hash == new_hash {
@@ -128,18 +212,28 @@ pub fn detect_drift(old: &ToolSchema, new: &ToolSchema) -> DriftReport {
     // Check description change (minor, unless drastically different)
     if old.description != new.description {
         let similarity = string_similarity(&old.description, &new.description);
-        if similarity < 0.3 {
-            changes.push(format!(
-                "Description drastically changed (similarity: {:.0}%)",
-                similarity * 100.0
-            ));
-            max_level = max_level.max(DriftLevel::Major);
+        let (change, level) = if similarity < 0.3 {
+            (
+                format!(
+                    "Description drastically changed (similarity: {:.0}%)",
+                    similarity * 100.0
+                ),
+                DriftLevel::Major,
+            )
         } else if similarity < 0.7 {
-            changes.push("Description significantly modified".to_string());
-            max_level = max_level.max(DriftLevel::Minor);
+            (
+                "Description significantly modified".to_string(),
+                DriftLevel::Minor,
+            )
         } else {
-            changes.push("Description slightly modified".to_string());
-            max_level = max_level.max(DriftLevel::Minor);
+            (
+                "Description slightly modified".to_string(),
+                DriftLevel::Minor,
+            )
+        };
+        changes.push(change);
+        if !config.ignore_description {
+            max_level = max_level.max(level);
         }
     }
 
@@ -175,8 +269,18 @@ pub fn detect_drift(old: &ToolSchema, new: &ToolSchema) -> DriftReport {
 ///
 /// A report indicating this is a new registration.
 pub fn new_tool_report(tool: &ToolSchema) -> DriftReport {
+    new_tool_report_with(tool, HashAlgorithm::Sha256)
+}
+
+/// Creates a drift report for a new tool, hashing with `algorithm`.
+///
+/// # Arguments
+///
+/// * `tool` - The new tool schema
+/// * `algorithm` - Algorithm for `new_hash`
+pub fn new_tool_report_with(tool: &ToolSchema, algorithm: HashAlgorithm) -> DriftReport {
     let value = serde_json::to_value(tool).expect("ToolSchema serialization cannot fail");
-    let new_hash = hash_canonical(&value);
+    let new_hash = hash_canonical_with(&value, algorithm);
 
     DriftReport {
         level: DriftLevel::None,
@@ -186,11 +290,95 @@ pub fn new_tool_report(tool: &ToolSchema) -> DriftReport {
     }
 }
 
+/// Measures how far a tool has drifted across a run of accepted versions.
+///
+/// Each consecutive pair of versions is one step, scored with
+/// [`detect_drift_with`]. A step adds 1 to the velocity if it is Minor, 3 if
+/// Major and 9 if Critical. The oldest version is also compared directly
+/// with the newest, so the report shows where the run of small steps ended
+/// up.
+///
+/// # Arguments
+///
+/// * `tool` - Name of the tool, for the report
+/// * `versions` - Accepted versions of the tool, oldest first
+/// * `config` - Scoring options
+/// * `threshold` - Velocity at which the tool is flagged, e.g.
+///   [`DEFAULT_VELOCITY_THRESHOLD`]
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_registry::drift::{drift_velocity, DriftConfig, DEFAULT_VELOCITY_THRESHOLD};
+/// use sentinel_registry::{DriftLevel, ToolSchema};
+///
+/// let versions: Vec<ToolSchema> = ["Read a file", "Read a file from disk"]
+///     .into_iter()
+///     .map(|d| ToolSchema::builder("read_file").description(d).build().unwrap())
+///     .collect();
+///
+/// let velocity = drift_velocity(
+///     "read_file",
+///     &versions,
+///     &DriftConfig::default(),
+///     DEFAULT_VELOCITY_THRESHOLD,
+/// );
+/// assert_eq!(velocity.steps, [DriftLevel::Minor]);
+/// assert_eq!(velocity.velocity, 1);
+/// assert!(!velocity.flagged);
+/// ```
+pub fn drift_velocity(
+    tool: &str,
+    versions: &[ToolSchema],
+    config: &DriftConfig,
+    threshold: u32,
+) -> DriftVelocity {
+    let steps: Vec<DriftLevel> = versions
+        .windows(2)
+        .map(|pair| detect_drift_with(&pair[0], &pair[1], config).level)
+        .collect();
+    let velocity = steps.iter().map(|&level| step_weight(level)).sum();
+
+    let (cumulative, changes) = match (versions.first(), versions.last()) {
+        (Some(first), Some(last)) if versions.len() > 1 => {
+            let report = detect_drift_with(first, last, config);
+            (report.level, report.changes)
+        }
+        _ => (DriftLevel::None, Vec::new()),
+    };
+
+    DriftVelocity {
+        tool: tool.to_string(),
+        versions: versions.len(),
+        steps,
+        cumulative,
+        changes,
+        velocity,
+        flagged: velocity >= threshold,
+    }
+}
+
+/// Contribution of one accepted change to the drift velocity.
+fn step_weight(level: DriftLevel) -> u32 {
+    match level {
+        DriftLevel::None => 0,
+        DriftLevel::Minor => 1,
+        DriftLevel::Major => 3,
+        DriftLevel::Critical => 9,
+    }
+}
+
+/// Returns true if two JSON schemas differ at most in the order or
+/// duplication of `required` entries, which is never reported as drift.
+fn schemas_equivalent(old: &Value, new: &Value) -> bool {
+    normalize_required(old) == normalize_required(new)
+}
+
 /// Compares two JSON schemas and returns categorized differences.
 fn compare_schemas(old: &Value, new: &Value, context: &str) -> Vec<(String, DriftLevel)> {
     let mut changes = Vec::new();
 
-    if old == new {
+    if schemas_equivalent(old, new) {
         return changes;
     }
 
@@ -209,8 +397,20 @@ fn compare_schemas(old: &Value, new: &Value, context: &str) -> Vec<(String, Drif
         return changes; // Type change is fundamental, don't compare further
     }
 
+    changes.extend(compare_combinators(
+        old,
+        new,
+        &format!("{} schema:", context),
+    ));
+
     // Compare properties for object schemas
     if old_type == "object" {
+        changes.extend(compare_additional_properties(
+            old,
+            new,
+            &format!("{} schema:", context),
+        ));
+
         let old_props = get_properties(old);
         let new_props = get_properties(new);
         let old_required = get_required(old);
@@ -255,7 +455,7 @@ fn compare_schemas(old: &Value, new: &Value, context: &str) -> Vec<(String, Drif
         // Check for modified properties
         for (key, old_prop) in &old_props {
             if let Some(new_prop) = new_props.get(key) {
-                if old_prop != new_prop {
+                if !schemas_equivalent(old_prop, new_prop) {
                     let old_prop_type = get_schema_type(old_prop);
                     let new_prop_type = get_schema_type(new_prop);
 
@@ -268,10 +468,43 @@ fn compare_schemas(old: &Value, new: &Value, context: &str) -> Vec<(String, Drif
                             DriftLevel::Critical,
                         ));
                     } else {
-                        changes.push((
-                            format!("{} schema: property '{}' modified", context, key),
-                            DriftLevel::Minor,
+                        let (mut constraint_changes, keywords): (Vec<_>, &[&str]) =
+                            match old_prop_type.as_str() {
+                                "string" => (
+                                    compare_string_constraints(old_prop, new_prop, context, key),
+                                    &STRING_CONSTRAINTS,
+                                ),
+                                "object" => (
+                                    compare_additional_properties(
+                                        old_prop,
+                                        new_prop,
+                                        &format!("{} schema: property '{}'", context, key),
+                                    )
+                                    .into_iter()
+                                    .collect(),
+                                    &[ADDITIONAL_PROPERTIES],
+                                ),
+                                _ => (Vec::new(), &[]),
+                            };
+
+                        constraint_changes.extend(compare_combinators(
+                            old_prop,
+                            new_prop,
+                            &format!("{} schema: property '{}'", context, key),
                         ));
+                        let keywords: Vec<&str> =
+                            keywords.iter().chain(&COMBINATORS).copied().collect();
+
+                        // Anything beyond the categorized keywords is a generic modification
+                        let other_changes = without_keywords(old_prop, &keywords)
+                            != without_keywords(new_prop, &keywords);
+                        if constraint_changes.is_empty() || other_changes {
+                            changes.push((
+                                format!("{} schema: property '{}' modified", context, key),
+                                DriftLevel::Minor,
+                            ));
+                        }
+                        changes.extend(constraint_changes);
                     }
                 }
             }
@@ -305,6 +538,249 @@ fn compare_schemas(old: &Value, new: &Value, context: &str) -> Vec<(String, Drif
     changes
 }
 
+/// String constraint keywords whose changes are categorized individually.
+const STRING_CONSTRAINTS: [&str; 4] = ["pattern", "format", "minLength", "maxLength"];
+
+/// Categorizes changes to a string property's constraint keywords.
+///
+/// Loosening a constraint (removing it, widening a length bound, or
+/// replacing a `pattern`/`format` whose strictness can't be compared) is
+/// Major, since it may admit injection payloads the old schema rejected.
+/// Tightening (adding a constraint or narrowing a bound) is Minor.
+fn compare_string_constraints(
+    old: &Value,
+    new: &Value,
+    context: &str,
+    key: &str,
+) -> Vec<(String, DriftLevel)> {
+    let mut changes = Vec::new();
+
+    for keyword in STRING_CONSTRAINTS {
+        let (old_value, new_value) = (old.get(keyword), new.get(keyword));
+        if old_value == new_value {
+            continue;
+        }
+
+        let (description, level) = match (old_value, new_value) {
+            (Some(_), None) => ("removed", DriftLevel::Major),
+            (None, Some(_)) => ("added", DriftLevel::Minor),
+            (Some(o), Some(n)) => match keyword {
+                "minLength" | "maxLength" => {
+                    let (o, n) = (o.as_u64().unwrap_or(0), n.as_u64().unwrap_or(0));
+                    let loosened = if keyword == "maxLength" { n > o } else { n < o };
+                    if loosened {
+                        ("loosened", DriftLevel::Major)
+                    } else {
+                        ("tightened", DriftLevel::Minor)
+                    }
+                }
+                _ => ("changed", DriftLevel::Major),
+            },
+            (None, None) => continue,
+        };
+
+        changes.push((
+            format!(
+                "{} schema: property '{}' {} {}",
+                context, key, keyword, description
+            ),
+            level,
+        ));
+    }
+
+    changes
+}
+
+/// Keyword controlling which undeclared properties an object accepts.
+const ADDITIONAL_PROPERTIES: &str = "additionalProperties";
+
+/// Categorizes a change to an object schema's `additionalProperties`.
+///
+/// Values are ranked from strictest to loosest: `false`, a schema that
+/// extra properties must match, then `true`, `{}` or an absent keyword
+/// (all of which accept anything). Moving towards looser is Major, since
+/// the tool starts accepting parameters the old schema rejected; moving
+/// towards stricter is Minor. Replacing one schema with another is Major,
+/// as their strictness can't be compared.
+///
+/// # Arguments
+///
+/// * `subject` - Prefix naming the object, e.g. `"input schema:"`
+fn compare_additional_properties(
+    old: &Value,
+    new: &Value,
+    subject: &str,
+) -> Option<(String, DriftLevel)> {
+    let (old_value, new_value) = (
+        old.get(ADDITIONAL_PROPERTIES),
+        new.get(ADDITIONAL_PROPERTIES),
+    );
+    if old_value == new_value {
+        return None;
+    }
+
+    let (old_rank, new_rank) = (
+        additional_properties_rank(old_value),
+        additional_properties_rank(new_value),
+    );
+    let (description, level) = if new_rank > old_rank {
+        ("loosened", DriftLevel::Major)
+    } else if new_rank < old_rank {
+        ("tightened", DriftLevel::Minor)
+    } else if old_rank == 1 {
+        ("changed", DriftLevel::Major)
+    } else {
+        // `true`, `{}` and absent all accept anything
+        return None;
+    };
+
+    Some((
+        format!(
+            "{} additionalProperties {} ({} -> {})",
+            subject,
+            description,
+            describe_additional_properties(old_value),
+            describe_additional_properties(new_value)
+        ),
+        level,
+    ))
+}
+
+/// Keywords whose value is a list of subschemas.
+const COMBINATORS: [&str; 3] = ["allOf", "anyOf", "oneOf"];
+
+/// Categorizes changes to the `allOf`, `anyOf` and `oneOf` branch lists.
+///
+/// Branches are matched as a set, so a branch that only moved is not a
+/// change. The branches left over on each side are paired in order and
+/// compared with [`compare_schemas`], which makes a type change inside a
+/// branch Critical. Unpaired branches were added or removed: in `anyOf`
+/// and `oneOf` each branch is an alternative, so an added branch widens
+/// what the tool accepts (Major) and a removed one narrows it (Minor). In
+/// `allOf` every branch must match, so the levels are reversed. Adding the
+/// keyword itself only constrains the schema (Minor); dropping it lifts
+/// those constraints (Major).
+///
+/// # Arguments
+///
+/// * `subject` - Prefix naming the schema, e.g. `"input schema:"`
+fn compare_combinators(old: &Value, new: &Value, subject: &str) -> Vec<(String, DriftLevel)> {
+    let mut changes = Vec::new();
+
+    for keyword in COMBINATORS {
+        let (old_branches, new_branches) = match (old.get(keyword), new.get(keyword)) {
+            (None, None) => continue,
+            (None, Some(_)) => {
+                changes.push((format!("{} {} added", subject, keyword), DriftLevel::Minor));
+                continue;
+            }
+            (Some(_), None) => {
+                changes.push((
+                    format!("{} {} removed", subject, keyword),
+                    DriftLevel::Major,
+                ));
+                continue;
+            }
+            (Some(old_value), Some(new_value)) => (branches(old_value), branches(new_value)),
+        };
+
+        let (old_left, new_left) = unmatched_branches(&old_branches, &new_branches);
+        let paired = old_left.len().min(new_left.len());
+        for ((_, old_branch), (index, new_branch)) in old_left.iter().zip(&new_left) {
+            changes.extend(compare_schemas(
+                old_branch,
+                new_branch,
+                &format!("{} {}[{}]", subject, keyword, index),
+            ));
+        }
+
+        let (added, removed) = if keyword == "allOf" {
+            (DriftLevel::Minor, DriftLevel::Major)
+        } else {
+            (DriftLevel::Major, DriftLevel::Minor)
+        };
+        for (index, _) in &new_left[paired..] {
+            changes.push((
+                format!("{} {} branch {} added", subject, keyword, index),
+                added,
+            ));
+        }
+        for (index, _) in &old_left[paired..] {
+            changes.push((
+                format!("{} {} branch {} removed", subject, keyword, index),
+                removed,
+            ));
+        }
+    }
+
+    changes
+}
+
+/// Returns the subschemas of a combinator; a malformed non-array value
+/// counts as a single branch.
+fn branches(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    }
+}
+
+/// A combinator branch with its index in the list.
+type Branch<'a> = (usize, &'a Value);
+
+/// Removes the branches present on both sides, returning what is left of
+/// each with its original index.
+fn unmatched_branches<'a>(
+    old: &[&'a Value],
+    new: &[&'a Value],
+) -> (Vec<Branch<'a>>, Vec<Branch<'a>>) {
+    let mut new_left: Vec<Branch> = new.iter().copied().enumerate().collect();
+    let mut old_left = Vec::new();
+    for (index, branch) in old.iter().copied().enumerate() {
+        match new_left
+            .iter()
+            .position(|(_, candidate)| schemas_equivalent(branch, candidate))
+        {
+            Some(position) => {
+                new_left.remove(position);
+            }
+            None => old_left.push((index, branch)),
+        }
+    }
+    (old_left, new_left)
+}
+
+/// Ranks an `additionalProperties` value: 0 rejects extras, 1 restricts
+/// them to a schema, 2 accepts anything.
+fn additional_properties_rank(value: Option<&Value>) -> u8 {
+    match value {
+        Some(Value::Bool(false)) => 0,
+        None | Some(Value::Bool(true)) => 2,
+        Some(Value::Object(schema)) if schema.is_empty() => 2,
+        Some(_) => 1,
+    }
+}
+
+/// Renders an `additionalProperties` value for a change description.
+fn describe_additional_properties(value: Option<&Value>) -> String {
+    match value {
+        None => "absent".to_string(),
+        Some(Value::Bool(b)) => b.to_string(),
+        Some(_) => "schema".to_string(),
+    }
+}
+
+/// Returns a copy of a property schema without the given keywords.
+fn without_keywords(schema: &Value, keywords: &[&str]) -> Value {
+    let mut stripped = schema.clone();
+    if let Some(obj) = stripped.as_object_mut() {
+        for keyword in keywords {
+            obj.remove(*keyword);
+        }
+    }
+    stripped
+}
+
 /// Extracts the type from a JSON Schema value.
 fn get_schema_type(schema: &Value) -> String {
     schema
@@ -336,6 +812,37 @@ fn get_required(schema: &Value) -> Vec<String> {
         .unwrap_or_default()
 }
 
+/// Keywords whose values are instance data rather than subschemas.
+const DATA_KEYWORDS: [&str; 4] = ["const", "default", "enum", "examples"];
+
+/// Returns `schema` with every `required` list sorted and deduplicated.
+///
+/// `required` is a set in JSON Schema, so reordering it must not register
+/// as drift. Values under [`DATA_KEYWORDS`] are left untouched.
+fn normalize_required(schema: &Value) -> Value {
+    match schema {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = match (key.as_str(), value) {
+                        ("required", Value::Array(items)) => {
+                            let mut items = items.clone();
+                            items.sort_by_cached_key(|item| item.to_string());
+                            items.dedup();
+                            Value::Array(items)
+                        }
+                        (key, value) if DATA_KEYWORDS.contains(&key) => value.clone(),
+                        (_, value) => normalize_required(value),
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(normalize_required).collect()),
+        other => other.clone(),
+    }
+}
+
 /// Computes similarity between two strings (0.0 to 1.0).
 ///
 /// Uses a simple word-based Jaccard similarity for efficiency.
@@ -423,6 +930,59 @@ mod tests {
         assert!(report.level >= DriftLevel::Major);
     }
 
+    #[test]
+    fn test_ignore_description_reports_without_raising_level() {
+        let config = DriftConfig {
+            ignore_description: true,
+        };
+        let old = make_schema("tool", "Read a file from disk", json!({}), json!({}));
+        let rewritten = make_schema("tool", "Execute arbitrary commands", json!({}), json!({}));
+
+        let report = detect_drift_with(&old, &rewritten, &config);
+        assert_eq!(report.level, DriftLevel::None);
+        assert_eq!(report.changes.len(), 1);
+        assert!(report.changes[0].starts_with("Description drastically changed"));
+        assert_ne!(report.old_hash, Some(report.new_hash));
+
+        // Structural changes still count
+        let retyped = make_schema(
+            "tool",
+            "Execute arbitrary commands",
+            json!({"type": "object"}),
+            json!({}),
+        );
+        let report = detect_drift_with(&old, &retyped, &config);
+        assert!(report.level > DriftLevel::None);
+        assert!(report.changes.len() > 1);
+    }
+
+    #[test]
+    fn test_required_order_is_not_drift() {
+        let input = |required: Value| {
+            json!({
+                "type": "object",
+                "properties": {
+                    "path": {"type": "string"},
+                    "mode": {"type": "string"},
+                    "options": {
+                        "type": "object",
+                        "properties": {"a": {}, "b": {}},
+                        "required": required.clone()
+                    }
+                },
+                "required": required
+            })
+        };
+        let old = make_schema("tool", "A tool", input(json!(["path", "mode"])), json!({}));
+        let new = make_schema("tool", "A tool", input(json!(["mode", "path"])), json!({}));
+
+        let report = detect_drift(&old, &new);
+        assert_eq!(report.level, DriftLevel::None, "{:?}", report.changes);
+        assert!(report.changes.is_empty());
+        // The hash stays byte-exact; only drift treats the list as a set
+        assert_ne!(report.old_hash, Some(report.new_hash));
+    }
+
     #[test]
     fn test_new_required_property_is_major() {
         let old = make_schema(
@@ -539,4 +1099,191 @@ mod tests {
         assert_eq!(report.level, DriftLevel::None);
         assert!(report.old_hash.is_none());
     }
+
+    fn string_property_schema(path: Value) -> ToolSchema {
+        make_schema(
+            "tool",
+            "A tool",
+            json!({
+                "type": "object",
+                "properties": { "path": path }
+            }),
+            json!({}),
+        )
+    }
+
+    #[test]
+    fn test_removed_pattern_is_major() {
+        let old = string_property_schema(json!({"type": "string", "pattern": "^/tmp/.*$"}));
+        let new = string_property_schema(json!({"type": "string"}));
+
+        let report = detect_drift(&old, &new);
+        assert_eq!(report.level, DriftLevel::Major);
+        assert!(report
+            .changes
+            .iter()
+            .any(|c| c.contains("'path' pattern removed")));
+    }
+
+    #[test]
+    fn test_added_pattern_is_minor() {
+        let old = string_property_schema(json!({"type": "string"}));
+        let new = string_property_schema(json!({"type": "string", "pattern": "^/tmp/.*$"}));
+
+        assert_eq!(detect_drift(&old, &new).level, DriftLevel::Minor);
+    }
+
+    #[test]
+    fn test_length_bounds_direction() {
+        let base = json!({"type": "string", "minLength": 1, "maxLength": 64});
+        let old = string_property_schema(base);
+
+        let wider =
+            string_property_schema(json!({"type": "string", "minLength": 1, "maxLength": 4096}));
+        assert_eq!(detect_drift(&old, &wider).level, DriftLevel::Major);
+
+        let narrower =
+            string_property_schema(json!({"type": "string", "minLength": 2, "maxLength": 32}));
+        let report = detect_drift(&old, &narrower);
+        assert_eq!(report.level, DriftLevel::Minor);
+        assert_eq!(report.changes.len(), 2);
+
+        let shorter_min =
+            string_property_schema(json!({"type": "string", "minLength": 0, "maxLength": 64}));
+        assert_eq!(detect_drift(&old, &shorter_min).level, DriftLevel::Major);
+    }
+
+    #[test]
+    fn test_format_changed_is_major() {
+        let old = string_property_schema(json!({"type": "string", "format": "uri"}));
+        let new = string_property_schema(json!({"type": "string", "format": "hostname"}));
+
+        assert_eq!(detect_drift(&old, &new).level, DriftLevel::Major);
+    }
+
+    fn object_schema(additional: Option<Value>) -> ToolSchema {
+        let mut input = json!({
+            "type": "object",
+            "properties": { "path": {"type": "string"} }
+        });
+        if let Some(additional) = additional {
+            input["additionalProperties"] = additional;
+        }
+        make_schema("tool", "A tool", input, json!({}))
+    }
+
+    #[test]
+    fn test_security_opening_additional_properties_is_major() {
+        let closed = object_schema(Some(json!(false)));
+
+        let opened = detect_drift(&closed, &object_schema(Some(json!(true))));
+        assert_eq!(opened.level, DriftLevel::Major);
+        assert!(opened
+            .changes
+            .iter()
+            .any(|c| c.contains("additionalProperties loosened (false -> true)")));
+
+        let dropped = detect_drift(&closed, &object_schema(None));
+        assert_eq!(dropped.level, DriftLevel::Major);
+
+        let to_schema = object_schema(Some(json!({"type": "string"})));
+        assert_eq!(detect_drift(&closed, &to_schema).level, DriftLevel::Major);
+        assert_eq!(
+            detect_drift(&to_schema, &object_schema(Some(json!({"type": "number"})))).level,
+            DriftLevel::Major
+        );
+    }
+
+    #[test]
+    fn test_closing_additional_properties_is_minor() {
+        let open = object_schema(Some(json!(true)));
+
+        let closed = detect_drift(&open, &object_schema(Some(json!(false))));
+        assert_eq!(closed.level, DriftLevel::Minor);
+        assert!(closed
+            .changes
+            .iter()
+            .any(|c| c.contains("additionalProperties tightened")));
+
+        let restricted = object_schema(Some(json!({"type": "string"})));
+        assert_eq!(detect_drift(&open, &restricted).level, DriftLevel::Minor);
+    }
+
+    #[test]
+    fn test_nested_additional_properties_loosening_is_major() {
+        let nested = |additional: bool| {
+            make_schema(
+                "tool",
+                "A tool",
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "options": {"type": "object", "additionalProperties": additional}
+                    }
+                }),
+                json!({}),
+            )
+        };
+
+        let report = detect_drift(&nested(false), &nested(true));
+        assert_eq!(report.level, DriftLevel::Major);
+        assert_eq!(report.changes.len(), 1, "{:?}", report.changes);
+    }
+
+    fn one_of_schema(branches: Value) -> ToolSchema {
+        make_schema(
+            "tool",
+            "A tool",
+            json!({
+                "type": "object",
+                "properties": {"target": {"oneOf": branches}}
+            }),
+            json!({}),
+        )
+    }
+
+    #[test]
+    fn test_security_added_one_of_branch_is_major() {
+        let old = one_of_schema(json!([{"type": "string", "pattern": "^/tmp/"}]));
+        let new = one_of_schema(json!([
+            {"type": "string", "pattern": "^/tmp/"},
+            {"type": "object"}
+        ]));
+
+        let report = detect_drift(&old, &new);
+        assert_eq!(report.level, DriftLevel::Major);
+        assert!(
+            report
+                .changes
+                .iter()
+                .any(|c| c.contains("property 'target' oneOf branch 1 added")),
+            "{:?}",
+            report.changes
+        );
+
+        // Dropping the branch again narrows the tool
+        assert_eq!(detect_drift(&new, &old).level, DriftLevel::Minor);
+    }
+
+    #[test]
+    fn test_one_of_branch_type_change_is_critical() {
+        let old = one_of_schema(json!([{"type": "string"}, {"type": "integer"}]));
+        let new = one_of_schema(json!([{"type": "string"}, {"type": "object"}]));
+        assert_eq!(detect_drift(&old, &new).level, DriftLevel::Critical);
+
+        // Reordering branches changes nothing they accept
+        let reordered = one_of_schema(json!([{"type": "integer"}, {"type": "string"}]));
+        assert_eq!(detect_drift(&old, &reordered).level, DriftLevel::Minor);
+    }
+
+    #[test]
+    fn test_all_of_levels_are_reversed() {
+        let schema =
+            |branches: Value| make_schema("tool", "A tool", json!({"allOf": branches}), json!({}));
+        let one = schema(json!([{"type": "object"}]));
+        let two = schema(json!([{"type": "object"}, {"required": ["path"]}]));
+
+        assert_eq!(detect_drift(&one, &two).level, DriftLevel::Minor);
+        assert_eq!(detect_drift(&two, &one).level, DriftLevel::Major);
+    }
 }
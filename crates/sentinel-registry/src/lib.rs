@@ -28,11 +28,12 @@
 //! | Threat | Description | Defense |
 //! |--------|-------------|---------|
 //! | Rug Pull | Server suddenly changes tool behavior | Hash mismatch detection |
-//! | Shadow Server | Attacker substitutes malicious server | Merkle root verification |
+//! | Shadow Server | Attacker substitutes malicious server | Merkle root verification, origin pinning (`register_tool_from`) |
 //! | Schema Drift | Gradual unauthorized modifications | Drift categorization |
-//! | Replay Attack | Old schema presented as current | Merkle proof freshness |
+//! | Replay Attack | Old schema presented as current | Epoch-bound Merkle root (`get_epoch_root`, `verify_proof_fresh`) |
 //! | Hash Collision | Crafted schemas with same hash | SHA-256 collision resistance |
 //! | Key Reordering | Schema changes via JSON key order | RFC 8785 canonicalization |
+//! | Duplicate Keys | Parsers disagree on `{"a":1,"a":2}` | Strict parsing (`register_tool_json`) |
 //!
 //! ## Architecture
 //!
@@ -148,7 +149,12 @@
 //!         println!("Expected: {:?}", expected);
 //!         println!("Actual: {:?}", actual);
 //!     }
+//!     VerifyResult::Drifted(report) => println!("DRIFT: {:?}", report.level),
 //!     VerifyResult::Unknown => println!("Tool not in registry"),
+//!     VerifyResult::OriginMismatch { expected, actual } => {
+//!         println!("ORIGIN MISMATCH - possible shadow server!");
+//!         println!("Registered from {}, presented by {}", expected, actual);
+//!     }
 //! }
 //!
 //! // Check for drift in a modified schema
@@ -195,11 +201,17 @@ pub mod canonicalize;
 pub mod drift;
 pub mod merkle;
 pub mod models;
+pub mod read_only;
 pub mod registry;
 pub mod storage;
 
-pub use models::{DriftLevel, DriftReport, Hash, ToolSchema, VerifyResult};
-pub use registry::RegistryGuard;
+pub use models::{
+    AuditAction, AuditEntry, AuditRetention, DriftLevel, DriftReport, DriftVelocity, Hash,
+    HashAlgorithm, HashMigration, ReconcilePlan, RegisterOutcome, RegistryError, SelfVerifyReport,
+    ToolSchema, ToolSchemaBuilder, VerifyResult,
+};
+pub use read_only::ReadOnlyRegistry;
+pub use registry::{RegistryGuard, DEFAULT_ROOT_HISTORY, DEFAULT_SCHEMA_HISTORY};
 
 #[cfg(test)]
 mod tests;
@@ -27,12 +27,47 @@
 //!       Leaf1    Leaf2     Leaf3    Leaf4
 //! ```
 //!
+//! Each leaf hash `Hn` commits to both the key and the value of its entry,
+//! so a proof for one tool cannot be presented as a proof for another.
+//! Leaf and internal hashes are domain-separated as in RFC 6962.
+//!
 //! To prove Leaf2 is in the tree:
 //! 1. Provide H1 (sibling) and H(H3+H4) (uncle)
 //! 2. Verifier computes: H(H1 + H(Leaf2)) = H1+H2
 //! 3. Then: H(H1+H2 + H(H3+H4)) = Root
 //! 4. Compare with trusted root
 //!
+//! ## Encoding
+//!
+//! | Node | SHA-256 input |
+//! |------|---------------|
+//! | Leaf | `0x00` ‖ key length (u64, big-endian) ‖ key ‖ value |
+//! | Internal | `0x01` ‖ left ‖ right |
+//!
+//! This replaces the original encoding, where a leaf was the bare value
+//! and an internal node hashed `left ‖ right` with no prefix. Roots and
+//! proofs produced under the old encoding do not verify under this one:
+//! re-export published roots and reissue stored proofs after upgrading.
+//!
+//! ## Standalone Use
+//!
+//! [`MerkleTree`] does not depend on the registry. Values are arbitrary
+//! 32-byte hashes under a key of any `Ord + AsRef<[u8]>` type (tool names
+//! by default), so the same tree can attest other data, such as a set of
+//! resource content hashes:
+//!
+//! ```rust
+//! use sentinel_registry::merkle::MerkleTree;
+//!
+//! let mut tree = MerkleTree::<Vec<u8>>::default();
+//! tree.insert(b"file:///etc/motd".as_slice(), [7u8; 32]);
+//! tree.insert(b"file:///srv/index.html".as_slice(), [9u8; 32]);
+//!
+//! let root = tree.get_root();
+//! let proof = tree.get_proof(b"file:///etc/motd".as_slice()).unwrap();
+//! assert!(MerkleTree::verify_proof(&proof, &root));
+//! ```
+//!
 //! ## References
 //!
 //! - **Merkle, R. C. (1979)** - "Secrecy, Authentication, and Public Key Systems"
@@ -48,11 +83,18 @@
 
 use crate::models::{Hash, MerkleProof, MerkleProofNode, HASH_SIZE};
 use sha2::{Digest, Sha256};
+use std::borrow::Borrow;
 use std::collections::BTreeMap;
 
 /// An empty hash (all zeros) used as a placeholder.
 pub const EMPTY_HASH: Hash = [0u8; HASH_SIZE];
 
+/// Domain-separation byte hashed before every leaf.
+const LEAF_PREFIX: u8 = 0x00;
+
+/// Domain-separation byte hashed before every internal node.
+const NODE_PREFIX: u8 = 0x01;
+
 /// A Merkle tree for tool schema verification.
 ///
 /// This implementation uses a key-value approach where each tool name
@@ -61,8 +103,11 @@ pub const EMPTY_HASH: Hash = [0u8; HASH_SIZE];
 ///
 /// # Structure
 ///
-/// The tree stores leaves indexed by tool name. Internal nodes are
-/// computed on-demand when the root hash or proofs are requested.
+/// The tree stores leaves indexed by key: `String` tool names unless
+/// another `K: Ord + AsRef<[u8]>` is chosen. Leaves are ordered by key and
+/// each leaf hashes its key together with its value, so the root commits
+/// to every (key, value) pair. Internal nodes are computed on-demand when
+/// the root hash or proofs are requested.
 ///
 /// # Thread Safety
 ///
@@ -93,16 +138,22 @@ pub const EMPTY_HASH: Hash = [0u8; HASH_SIZE];
 /// }
 /// ```
 #[derive(Debug, Clone)]
-pub struct MerkleTree {
-    /// Leaf nodes indexed by tool name.
-    leaves: BTreeMap<String, Hash>,
+pub struct MerkleTree<K = String> {
+    /// Leaf nodes indexed by key (tool name by default).
+    leaves: BTreeMap<K, Hash>,
 
     /// Cached root hash (invalidated on modification).
     cached_root: Option<Hash>,
+
+    /// Epoch of the current root, stamped into generated proofs.
+    epoch: u64,
 }
 
 impl MerkleTree {
-    /// Creates a new empty Merkle tree.
+    /// Creates a new empty Merkle tree keyed by tool name.
+    ///
+    /// For other key types use [`MerkleTree::default`], e.g.
+    /// `MerkleTree::<Vec<u8>>::default()`.
     ///
     /// # Returns
     ///
@@ -118,12 +169,109 @@ impl MerkleTree {
     /// assert_eq!(tree.len(), 0);
     /// ```
     pub fn new() -> Self {
-        MerkleTree {
-            leaves: BTreeMap::new(),
-            cached_root: None,
+        Self::default()
+    }
+
+    /// Verifies a Merkle proof against a trusted root hash.
+    ///
+    /// This is a static method that can verify proofs without access
+    /// to the original tree, enabling distributed verification. It works
+    /// for trees of any key type.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof to verify
+    /// * `expected_root` - The trusted root hash to verify against
+    ///
+    /// # Returns
+    ///
+    /// `true` if the proof is valid, `false` otherwise.
+    ///
+    /// # Security Notes
+    ///
+    /// The `expected_root` must come from a trusted source. If an
+    /// attacker can substitute the root, they can forge proofs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::merkle::MerkleTree;
+    ///
+    /// let mut tree = MerkleTree::new();
+    /// tree.insert("tool", [1u8; 32]);
+    ///
+    /// let root = tree.get_root();
+    /// let proof = tree.get_proof("tool").unwrap();
+    ///
+    /// // Valid proof
+    /// assert!(MerkleTree::verify_proof(&proof, &root));
+    ///
+    /// // Invalid root fails
+    /// let fake_root = [0u8; 32];
+    /// assert!(!MerkleTree::verify_proof(&proof, &fake_root));
+    /// ```
+    pub fn verify_proof(proof: &MerkleProof, expected_root: &Hash) -> bool {
+        proof_root(proof) == *expected_root
+    }
+
+    /// Verifies a Merkle proof against an epoch-bound root and rejects it if
+    /// it predates `min_epoch`.
+    ///
+    /// Use this to counter replay of an old schema: a proof generated before
+    /// the caller's last known registry change is refused even if the path
+    /// is internally consistent.
+    ///
+    /// # Arguments
+    ///
+    /// * `proof` - The proof to verify
+    /// * `expected_epoch_root` - The trusted epoch-bound root (see
+    ///   [`MerkleTree::get_epoch_root`])
+    /// * `min_epoch` - The oldest acceptable registry epoch
+    ///
+    /// # Returns
+    ///
+    /// `true` if `H(root || proof.epoch)` recomputed from the proof equals
+    /// `expected_epoch_root` and `proof.epoch >= min_epoch`.
+    ///
+    /// # Security Notes
+    ///
+    /// The epoch is hashed into the trusted root, so rewriting `proof.epoch`
+    /// on an old proof changes the recomputed value and fails verification.
+    /// Checking against a plain [`MerkleTree::get_root`] value always fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::merkle::MerkleTree;
+    ///
+    /// let mut tree = MerkleTree::new();
+    /// tree.insert("tool", [1u8; 32]);
+    /// tree.set_epoch(3);
+    ///
+    /// let root = tree.get_epoch_root();
+    /// let mut proof = tree.get_proof("tool").unwrap();
+    ///
+    /// assert!(MerkleTree::verify_proof_fresh(&proof, &root, 3));
+    /// assert!(!MerkleTree::verify_proof_fresh(&proof, &root, 4));
+    ///
+    /// // A rewritten epoch no longer matches the trusted root
+    /// proof.epoch = 4;
+    /// assert!(!MerkleTree::verify_proof_fresh(&proof, &root, 4));
+    /// ```
+    pub fn verify_proof_fresh(
+        proof: &MerkleProof,
+        expected_epoch_root: &Hash,
+        min_epoch: u64,
+    ) -> bool {
+        if proof.epoch < min_epoch {
+            return false;
         }
+
+        epoch_root(&proof_root(proof), proof.epoch) == *expected_epoch_root
     }
+}
 
+impl<K: Ord + AsRef<[u8]>> MerkleTree<K> {
     /// Inserts or updates a leaf in the tree.
     ///
     /// The root hash cache is invalidated and will be recomputed
@@ -131,8 +279,8 @@ impl MerkleTree {
     ///
     /// # Arguments
     ///
-    /// * `key` - The tool name (unique identifier)
-    /// * `hash` - The SHA-256 hash of the tool's canonical schema
+    /// * `key` - The leaf key, e.g. a tool name (unique identifier)
+    /// * `hash` - The leaf hash, e.g. of the tool's canonical schema
     ///
     /// # Example
     ///
@@ -145,8 +293,12 @@ impl MerkleTree {
     ///
     /// assert_eq!(tree.len(), 2);
     /// ```
-    pub fn insert(&mut self, key: &str, hash: Hash) {
-        self.leaves.insert(key.to_string(), hash);
+    pub fn insert<Q>(&mut self, key: &Q, hash: Hash)
+    where
+        K: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = K> + ?Sized,
+    {
+        self.leaves.insert(key.to_owned(), hash);
         self.cached_root = None; // Invalidate cache
     }
 
@@ -154,12 +306,16 @@ impl MerkleTree {
     ///
     /// # Arguments
     ///
-    /// * `key` - The tool name to remove
+    /// * `key` - The key to remove
     ///
     /// # Returns
     ///
     /// The removed hash, if the key existed.
-    pub fn remove(&mut self, key: &str) -> Option<Hash> {
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Hash>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
         let result = self.leaves.remove(key);
         if result.is_some() {
             self.cached_root = None;
@@ -167,19 +323,28 @@ impl MerkleTree {
         result
     }
 
-    /// Gets the hash for a specific tool.
+    /// Gets the hash for a specific key.
     ///
     /// # Arguments
     ///
-    /// * `key` - The tool name to look up
+    /// * `key` - The key to look up
     ///
     /// # Returns
     ///
     /// The hash if found, None otherwise.
-    pub fn get(&self, key: &str) -> Option<&Hash> {
+    pub fn get<Q>(&self, key: &Q) -> Option<&Hash>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
         self.leaves.get(key)
     }
 
+    /// Iterates over the leaves in key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &Hash)> {
+        self.leaves.iter()
+    }
+
     /// Returns the number of leaves in the tree.
     pub fn len(&self) -> usize {
         self.leaves.len()
@@ -190,6 +355,41 @@ impl MerkleTree {
         self.leaves.is_empty()
     }
 
+    /// Returns the epoch stamped into generated proofs.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Sets the epoch of the current root.
+    ///
+    /// The tree does not advance the epoch itself; the owner (normally
+    /// `RegistryGuard`) persists it and sets it after each modification.
+    pub fn set_epoch(&mut self, epoch: u64) {
+        self.epoch = epoch;
+    }
+
+    /// Returns the root hash bound to the current epoch: `H(root || epoch)`.
+    ///
+    /// Publish this value (instead of [`MerkleTree::get_root`]) to verifiers
+    /// that check freshness with [`MerkleTree::verify_proof_fresh`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::merkle::MerkleTree;
+    ///
+    /// let mut tree = MerkleTree::new();
+    /// tree.insert("tool", [1u8; 32]);
+    /// let at_one = tree.get_epoch_root();
+    ///
+    /// tree.set_epoch(2);
+    /// assert_ne!(tree.get_epoch_root(), at_one);
+    /// ```
+    pub fn get_epoch_root(&mut self) -> Hash {
+        let root = self.get_root();
+        epoch_root(&root, self.epoch)
+    }
+
     /// Computes and returns the root hash of the tree.
     ///
     /// The root hash is cached and reused until the tree is modified.
@@ -230,7 +430,7 @@ impl MerkleTree {
     ///
     /// # Arguments
     ///
-    /// * `key` - The tool name to generate a proof for
+    /// * `key` - The key to generate a proof for
     ///
     /// # Returns
     ///
@@ -250,85 +450,45 @@ impl MerkleTree {
     ///     assert!(MerkleTree::verify_proof(&proof, &root));
     /// }
     /// ```
-    pub fn get_proof(&mut self, key: &str) -> Option<MerkleProof> {
+    pub fn get_proof<Q>(&mut self, key: &Q) -> Option<MerkleProof>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
         let leaf_hash = *self.leaves.get(key)?;
         let root_hash = self.get_root();
 
-        // Get sorted keys for consistent ordering
-        let keys: Vec<&String> = self.leaves.keys().collect();
-        let index = keys.iter().position(|k| *k == key)?;
+        // Leaves iterate in sorted key order
+        let index = self.leaves.keys().position(|k| k.borrow() == key)?;
+        let leaf_key = self.leaves.keys().nth(index)?.as_ref().to_vec();
 
         // Build proof path
-        let hashes: Vec<Hash> = keys.iter().map(|k| self.leaves[*k]).collect();
-        let path = self.build_proof_path(&hashes, index);
+        let path = self.build_proof_path(&self.leaf_nodes(), index);
 
         Some(MerkleProof {
+            key: leaf_key,
             leaf_hash,
             path,
             root_hash,
+            epoch: self.epoch,
         })
     }
 
-    /// Verifies a Merkle proof against a trusted root hash.
-    ///
-    /// This is a static method that can verify proofs without access
-    /// to the original tree, enabling distributed verification.
-    ///
-    /// # Arguments
-    ///
-    /// * `proof` - The proof to verify
-    /// * `expected_root` - The trusted root hash to verify against
-    ///
-    /// # Returns
-    ///
-    /// `true` if the proof is valid, `false` otherwise.
-    ///
-    /// # Security Notes
-    ///
-    /// The `expected_root` must come from a trusted source. If an
-    /// attacker can substitute the root, they can forge proofs.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use sentinel_registry::merkle::MerkleTree;
-    ///
-    /// let mut tree = MerkleTree::new();
-    /// tree.insert("tool", [1u8; 32]);
-    ///
-    /// let root = tree.get_root();
-    /// let proof = tree.get_proof("tool").unwrap();
-    ///
-    /// // Valid proof
-    /// assert!(MerkleTree::verify_proof(&proof, &root));
-    ///
-    /// // Invalid root fails
-    /// let fake_root = [0u8; 32];
-    /// assert!(!MerkleTree::verify_proof(&proof, &fake_root));
-    /// ```
-    pub fn verify_proof(proof: &MerkleProof, expected_root: &Hash) -> bool {
-        let mut current = proof.leaf_hash;
-
-        for node in &proof.path {
-            current = if node.is_left {
-                hash_pair(&node.hash, &current)
-            } else {
-                hash_pair(&current, &node.hash)
-            };
-        }
-
-        current == *expected_root
-    }
-
     /// Computes the root hash from all leaves.
     fn compute_root(&self) -> Hash {
         if self.leaves.is_empty() {
             return EMPTY_HASH;
         }
 
-        // Get hashes in sorted key order
-        let hashes: Vec<Hash> = self.leaves.values().cloned().collect();
-        self.build_tree(&hashes)
+        self.build_tree(&self.leaf_nodes())
+    }
+
+    /// Returns the leaf node hashes in sorted key order.
+    fn leaf_nodes(&self) -> Vec<Hash> {
+        self.leaves
+            .iter()
+            .map(|(key, hash)| leaf_node(key.as_ref(), hash))
+            .collect()
     }
 
     /// Builds the tree and returns the root hash.
@@ -409,12 +569,44 @@ impl MerkleTree {
     }
 }
 
-impl Default for MerkleTree {
+impl<K: Ord> Default for MerkleTree<K> {
     fn default() -> Self {
-        Self::new()
+        MerkleTree {
+            leaves: BTreeMap::new(),
+            cached_root: None,
+            epoch: 0,
+        }
     }
 }
 
+/// Hashes a (key, value) entry into a leaf node.
+///
+/// # Returns
+///
+/// The SHA-256 hash of `0x00`, the big-endian key length, the key and the
+/// value. The length prefix keeps key and value boundaries unambiguous.
+fn leaf_node(key: &[u8], value: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([LEAF_PREFIX]);
+    hasher.update((key.len() as u64).to_be_bytes());
+    hasher.update(key);
+    hasher.update(value);
+    hasher.finalize().into()
+}
+
+/// Recomputes the root a proof leads to.
+fn proof_root(proof: &MerkleProof) -> Hash {
+    let mut current = leaf_node(&proof.key, &proof.leaf_hash);
+    for node in &proof.path {
+        current = if node.is_left {
+            hash_pair(&node.hash, &current)
+        } else {
+            hash_pair(&current, &node.hash)
+        };
+    }
+    current
+}
+
 /// Hashes two child hashes together to form a parent hash.
 ///
 /// This is the fundamental building block of the Merkle tree.
@@ -427,14 +619,27 @@ impl Default for MerkleTree {
 ///
 /// # Returns
 ///
-/// The SHA-256 hash of the concatenated children.
+/// The SHA-256 hash of `0x01` followed by the concatenated children.
 fn hash_pair(left: &Hash, right: &Hash) -> Hash {
     let mut hasher = Sha256::new();
+    hasher.update([NODE_PREFIX]);
     hasher.update(left);
     hasher.update(right);
     hasher.finalize().into()
 }
 
+/// Binds a root hash to a registry epoch.
+///
+/// # Returns
+///
+/// The SHA-256 hash of the root followed by the big-endian epoch.
+fn epoch_root(root: &Hash, epoch: u64) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update(root);
+    hasher.update(epoch.to_be_bytes());
+    hasher.finalize().into()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -455,8 +660,8 @@ mod tests {
 
         assert_eq!(tree.len(), 1);
         assert_eq!(tree.get("tool"), Some(&hash));
-        // Single leaf: root is the leaf itself
-        assert_eq!(tree.get_root(), hash);
+        // Single leaf: root is the leaf node over (key, value)
+        assert_eq!(tree.get_root(), leaf_node(b"tool", &hash));
     }
 
     #[test]
@@ -572,4 +777,53 @@ mod tests {
         assert_ne!(root_before, root_after);
         assert_eq!(tree.len(), 1);
     }
+
+    #[test]
+    fn test_standalone_tree_with_byte_keys() {
+        // Attest resource content hashes, keyed by raw URI bytes
+        let resources: [(&[u8], Hash); 3] = [
+            (b"file:///srv/a.txt", [0xa1; 32]),
+            (b"file:///srv/b.txt", [0xb2; 32]),
+            (b"https://example.com/c", [0xc3; 32]),
+        ];
+        let mut tree = MerkleTree::<Vec<u8>>::default();
+        for (uri, hash) in resources {
+            tree.insert(uri, hash);
+        }
+
+        let root = tree.get_root();
+        for (uri, hash) in resources {
+            let proof = tree.get_proof(uri).unwrap();
+            assert_eq!(proof.leaf_hash, hash);
+            assert!(MerkleTree::verify_proof(&proof, &root));
+
+            let mut forged = proof.clone();
+            forged.leaf_hash = [0xff; 32];
+            assert!(!MerkleTree::verify_proof(&forged, &root));
+        }
+        assert!(tree.get_proof(b"file:///srv/missing".as_slice()).is_none());
+    }
+
+    #[test]
+    fn test_security_proof_bound_to_key() {
+        let mut tree = MerkleTree::new();
+        tree.insert("read_file", [1u8; 32]);
+        tree.insert("write_file", [1u8; 32]);
+        let root = tree.get_root();
+
+        // Same value under two keys: the proofs differ and cannot be swapped
+        let proof = tree.get_proof("read_file").unwrap();
+        assert_eq!(proof.key, b"read_file");
+        assert!(MerkleTree::verify_proof(&proof, &root));
+
+        let mut relabeled = proof.clone();
+        relabeled.key = b"write_file".to_vec();
+        assert!(!MerkleTree::verify_proof(&relabeled, &root));
+
+        // Renaming a tool changes the root even with an unchanged hash
+        let mut renamed = MerkleTree::new();
+        renamed.insert("read_file", [1u8; 32]);
+        renamed.insert("wipe_file", [1u8; 32]);
+        assert_ne!(renamed.get_root(), root);
+    }
 }
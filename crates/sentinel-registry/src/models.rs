@@ -18,6 +18,7 @@
 //! - NIST FIPS 180-4 for hash size (SHA-256 = 32 bytes)
 
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
 /// SHA-256 hash output size in bytes.
@@ -90,22 +91,238 @@ pub struct ToolSchema {
     pub output_schema: serde_json::Value,
 }
 
+/// JSON Schema primitive type names accepted in a `type` keyword.
+const SCHEMA_TYPES: &[&str] = &[
+    "null", "boolean", "object", "array", "number", "integer", "string",
+];
+
+impl ToolSchema {
+    /// Starts building a schema for the tool `name`.
+    ///
+    /// The description defaults to empty, the input schema to
+    /// `{"type": "object"}` and the output schema to `{}` (any output).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::ToolSchema;
+    /// use serde_json::json;
+    ///
+    /// let schema = ToolSchema::builder("search_files")
+    ///     .description("Search for files matching a pattern")
+    ///     .input_schema(json!({
+    ///         "type": "object",
+    ///         "properties": { "pattern": { "type": "string" } }
+    ///     }))
+    ///     .build()?;
+    ///
+    /// assert_eq!(schema.name, "search_files");
+    /// # Ok::<(), sentinel_registry::RegistryError>(())
+    /// ```
+    pub fn builder(name: impl Into<String>) -> ToolSchemaBuilder {
+        ToolSchemaBuilder {
+            schema: ToolSchema {
+                name: name.into(),
+                description: String::new(),
+                input_schema: serde_json::json!({ "type": "object" }),
+                output_schema: serde_json::json!({}),
+            },
+        }
+    }
+
+    /// Parses one tool from the MCP wire format.
+    ///
+    /// MCP tools carry `name`, an optional `description`, a camelCase
+    /// `inputSchema` and, optionally, an `outputSchema`. A missing
+    /// description defaults to empty and a missing output schema to `{}`
+    /// (any output). Other members (`title`, `annotations`, ...) are ignored
+    /// and so are not covered by the schema hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::InvalidSchema`] if `name` or `inputSchema`
+    /// is missing, a member has the wrong type, or [`validate`](Self::validate)
+    /// fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::ToolSchema;
+    /// use serde_json::json;
+    ///
+    /// let tool = ToolSchema::from_mcp_tool(&json!({
+    ///     "name": "read_file",
+    ///     "description": "Read a file",
+    ///     "inputSchema": { "type": "object" }
+    /// }))?;
+    ///
+    /// assert_eq!(tool.input_schema, json!({ "type": "object" }));
+    /// assert_eq!(tool.output_schema, json!({}));
+    /// # Ok::<(), sentinel_registry::RegistryError>(())
+    /// ```
+    pub fn from_mcp_tool(value: &serde_json::Value) -> Result<ToolSchema> {
+        use serde_json::Value;
+
+        let name = value.get("name").and_then(Value::as_str);
+        let invalid = |reason: &str| RegistryError::InvalidSchema {
+            tool: name.unwrap_or("<unnamed>").to_string(),
+            reason: reason.to_string(),
+        };
+
+        let Some(map) = value.as_object() else {
+            return Err(invalid("MCP tool must be an object"));
+        };
+        let name = name.ok_or_else(|| invalid("missing string `name`"))?;
+        let description = match map.get("description") {
+            None | Some(Value::Null) => "",
+            Some(Value::String(description)) => description,
+            Some(_) => return Err(invalid("`description` must be a string")),
+        };
+        let input_schema = map
+            .get("inputSchema")
+            .ok_or_else(|| invalid("missing `inputSchema`"))?;
+
+        let schema = ToolSchema {
+            name: name.to_string(),
+            description: description.to_string(),
+            input_schema: input_schema.clone(),
+            output_schema: map
+                .get("outputSchema")
+                .cloned()
+                .unwrap_or_else(|| serde_json::json!({})),
+        };
+        schema.validate()?;
+        Ok(schema)
+    }
+
+    /// Parses every tool in an MCP `tools/list` result.
+    ///
+    /// Accepts either the result object (`{"tools": [...]}`) or the whole
+    /// JSON-RPC response (`{"result": {"tools": [...]}}`). Each tool is
+    /// parsed with [`from_mcp_tool`](Self::from_mcp_tool).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::InvalidSchema`] if there is no `tools`
+    /// array or any tool fails to parse; nothing is returned for the
+    /// other tools.
+    pub fn from_tools_list(value: &serde_json::Value) -> Result<Vec<ToolSchema>> {
+        let result = value.get("result").unwrap_or(value);
+        let tools = result
+            .get("tools")
+            .and_then(serde_json::Value::as_array)
+            .ok_or_else(|| RegistryError::InvalidSchema {
+                tool: "<tools/list>".to_string(),
+                reason: "expected a `tools` array".to_string(),
+            })?;
+
+        tools.iter().map(Self::from_mcp_tool).collect()
+    }
+
+    /// Checks that the schema is well-formed.
+    ///
+    /// This is a structural check, not full JSON Schema validation: the name
+    /// must not be blank, and each schema must be an object or boolean whose
+    /// `type` keyword (if any) names JSON Schema types.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::InvalidSchema`] describing the first problem.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |reason: String| RegistryError::InvalidSchema {
+            tool: self.name.clone(),
+            reason,
+        };
+
+        if self.name.trim().is_empty() {
+            return Err(invalid("tool name is empty".to_string()));
+        }
+        for (field, schema) in [
+            ("input_schema", &self.input_schema),
+            ("output_schema", &self.output_schema),
+        ] {
+            check_schema_value(schema).map_err(|reason| invalid(format!("{field}: {reason}")))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks a schema is an object or boolean with a valid `type` keyword.
+fn check_schema_value(schema: &serde_json::Value) -> std::result::Result<(), String> {
+    use serde_json::Value;
+
+    let map = match schema {
+        Value::Bool(_) => return Ok(()),
+        Value::Object(map) => map,
+        other => return Err(format!("expected an object or boolean, found {}", other)),
+    };
+
+    let known = |t: &Value| t.as_str().is_some_and(|t| SCHEMA_TYPES.contains(&t));
+    match map.get("type") {
+        None => Ok(()),
+        Some(Value::Array(types)) if !types.is_empty() && types.iter().all(known) => Ok(()),
+        Some(t) if known(t) => Ok(()),
+        Some(t) => Err(format!("invalid type {}", t)),
+    }
+}
+
+/// Builder for [`ToolSchema`], created by [`ToolSchema::builder`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ToolSchemaBuilder {
+    schema: ToolSchema,
+}
+
+impl ToolSchemaBuilder {
+    /// Sets the human-readable description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.schema.description = description.into();
+        self
+    }
+
+    /// Sets the input JSON Schema.
+    pub fn input_schema(mut self, schema: serde_json::Value) -> Self {
+        self.schema.input_schema = schema;
+        self
+    }
+
+    /// Sets the output JSON Schema.
+    pub fn output_schema(mut self, schema: serde_json::Value) -> Self {
+        self.schema.output_schema = schema;
+        self
+    }
+
+    /// Validates and returns the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::InvalidSchema`] if
+    /// [`ToolSchema::validate`] fails.
+    pub fn build(self) -> Result<ToolSchema> {
+        self.schema.validate()?;
+        Ok(self.schema)
+    }
+}
+
 /// Result of verifying a tool schema against the registry.
 ///
-/// Represents the three possible outcomes when checking whether a tool's
+/// Represents the possible outcomes when checking whether a tool's
 /// current schema matches what was previously registered.
 ///
 /// # Variants
 ///
 /// - `Valid`: Schema matches exactly (hashes are identical)
 /// - `Invalid`: Schema differs from registered version (possible rug pull)
+/// - `Drifted`: Like `Invalid`, with the drift analysis attached; only
+///   returned by [`RegistryGuard::verify_tool_with_drift`](crate::RegistryGuard::verify_tool_with_drift)
 /// - `Unknown`: Tool has never been registered
+/// - `OriginMismatch`: Tool is pinned to a different source server
 ///
 /// # Security Notes
 ///
 /// An `Invalid` result should be treated as a potential security incident.
 /// The expected and actual hashes are provided for forensic analysis.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum VerifyResult {
     /// Schema matches the registered version exactly.
     Valid,
@@ -120,10 +337,258 @@ pub enum VerifyResult {
         actual: Hash,
     },
 
+    /// Schema differs from the registered version, with the drift analysis.
+    ///
+    /// The report's `old_hash` and `new_hash` are the expected and actual
+    /// hashes of `Invalid`.
+    Drifted(DriftReport),
+
     /// Tool is not present in the registry.
     ///
     /// This could indicate a new tool or an attempted injection.
     Unknown,
+
+    /// Tool was registered from a different origin (source server).
+    ///
+    /// Reported even when the schema is identical: a shadow server can
+    /// impersonate a legitimate one by advertising the same tools.
+    OriginMismatch {
+        /// Origin the tool was registered from.
+        expected: String,
+        /// Origin the tool was presented by.
+        actual: String,
+    },
+}
+
+/// Outcome of an idempotent registration via
+/// [`RegistryGuard::register_tool_if_changed`](crate::RegistryGuard::register_tool_if_changed).
+///
+/// # Variants
+///
+/// - `Unchanged`: Stored hash already matches; nothing was written
+/// - `Created`: Tool was not registered before and has been stored
+/// - `Updated`: Tool existed with a different hash and has been overwritten
+///
+/// # Security Notes
+///
+/// An `Updated` outcome means a registered schema changed. Callers that
+/// re-register on reconnect should treat it like a `VerifyResult::Invalid`
+/// and review the drift before trusting the tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegisterOutcome {
+    /// The stored schema already matches; no write was performed.
+    Unchanged,
+
+    /// The tool was newly registered.
+    Created,
+
+    /// The tool was re-registered with a different schema.
+    Updated {
+        /// Hash of the previously registered schema.
+        previous: Hash,
+    },
+}
+
+/// Digest algorithm used to hash canonical tool schemas.
+///
+/// Every variant produces a 32-byte digest, so hashes, proofs and storage
+/// keep the same layout whichever algorithm is active.
+///
+/// # Variants
+///
+/// - `Sha256`: SHA-256 (FIPS 180-4), the default
+/// - `Sha512_256`: SHA-512/256, truncated SHA-512; faster on 64-bit hosts
+///   and immune to length-extension
+///
+/// # Security Notes
+///
+/// The algorithm only covers leaf (schema) hashes. Interior Merkle nodes are
+/// always SHA-256, so switching algorithms changes the root through its
+/// leaves. Use [`RegistryGuard::rehash_all`](crate::RegistryGuard::rehash_all)
+/// to migrate an existing registry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HashAlgorithm {
+    /// SHA-256.
+    #[default]
+    Sha256,
+
+    /// SHA-512/256.
+    Sha512_256,
+}
+
+impl HashAlgorithm {
+    /// Returns the stable identifier stored alongside the registry.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sha256 => "sha256",
+            Self::Sha512_256 => "sha512_256",
+        }
+    }
+
+    /// Parses an identifier produced by [`as_str`](Self::as_str).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "sha256" => Some(Self::Sha256),
+            "sha512_256" => Some(Self::Sha512_256),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for HashAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Record of a completed hash algorithm migration.
+///
+/// Written to storage by
+/// [`RegistryGuard::rehash_all`](crate::RegistryGuard::rehash_all) in the
+/// same transaction as the new hashes, so the history cannot disagree with
+/// the stored data.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashMigration {
+    /// Algorithm the registry was hashed with before the migration.
+    pub from: HashAlgorithm,
+
+    /// Algorithm the registry is hashed with afterwards.
+    pub to: HashAlgorithm,
+
+    /// Registry epoch produced by the migration.
+    pub epoch: u64,
+
+    /// Number of tools rehashed.
+    pub tools: usize,
+}
+
+/// Result of [`RegistryGuard::self_verify`](crate::RegistryGuard::self_verify).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelfVerifyReport {
+    /// Number of stored tools checked.
+    pub checked: usize,
+
+    /// Tools whose stored schema, hash or name no longer agree, in name order.
+    pub mismatched: Vec<String>,
+
+    /// Tools whose stored schema or hash could not be decoded, in name order.
+    #[serde(default)]
+    pub unreadable: Vec<String>,
+
+    /// Merkle root recomputed from the stored hashes.
+    pub computed_root: Hash,
+
+    /// Merkle root the registry has been serving proofs against.
+    pub expected_root: Hash,
+}
+
+impl SelfVerifyReport {
+    /// Returns true if the computed and expected roots agree.
+    pub fn root_matches(&self) -> bool {
+        self.computed_root == self.expected_root
+    }
+
+    /// Returns true if every tool was readable and matched, and the roots
+    /// agree.
+    pub fn is_intact(&self) -> bool {
+        self.mismatched.is_empty() && self.unreadable.is_empty() && self.root_matches()
+    }
+}
+
+/// Result of [`RegistryGuard::reconcile_to_root`](crate::RegistryGuard::reconcile_to_root).
+///
+/// Applying it means removing every tool in `remove` and registering every
+/// schema in `register`, in any order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReconcilePlan {
+    /// Tools to remove, in name order.
+    pub remove: Vec<String>,
+
+    /// Known-good schemas to register, replacing any current registration
+    /// of the same name, in name order.
+    pub register: Vec<ToolSchema>,
+
+    /// Whether applying the plan yields the target root. When false the
+    /// plan reverts to exactly the known-good tools, and the target was
+    /// built from something else.
+    pub reaches_target: bool,
+}
+
+impl ReconcilePlan {
+    /// Returns true if the registry needs no change.
+    pub fn is_empty(&self) -> bool {
+        self.remove.is_empty() && self.register.is_empty()
+    }
+}
+
+/// Kind of registry change recorded in the audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditAction {
+    /// A tool was registered or its schema replaced.
+    Registered,
+
+    /// A tool was removed.
+    Removed,
+
+    /// Every stored hash was recomputed under a new algorithm. The entry's
+    /// `tool` is empty; the [`HashMigration`] with the same epoch records
+    /// the algorithms.
+    Rehashed,
+}
+
+/// One record of the registry's append-only audit log.
+///
+/// Entries are keyed by the epoch the change produced, so the log is
+/// ordered and each entry lines up with the Merkle root of that epoch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// Registry epoch produced by the change.
+    pub epoch: u64,
+
+    /// What happened.
+    pub action: AuditAction,
+
+    /// Name of the affected tool (empty for registry-wide changes).
+    pub tool: String,
+
+    /// Schema hash after the change (`None` for removals and re-hashes).
+    pub hash: Option<Hash>,
+
+    /// Wall-clock time of the change, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/// Retention policy bounding the audit log.
+///
+/// An entry is pruned if either limit excludes it. With both limits unset
+/// (the default) nothing is ever pruned.
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_registry::AuditRetention;
+/// use std::time::Duration;
+///
+/// let retention = AuditRetention {
+///     max_entries: Some(10_000),
+///     max_age: Some(Duration::from_secs(90 * 24 * 3600)),
+/// };
+/// assert!(!retention.is_unbounded());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AuditRetention {
+    /// Keep at most this many of the newest entries.
+    pub max_entries: Option<usize>,
+
+    /// Keep only entries younger than this.
+    pub max_age: Option<Duration>,
+}
+
+impl AuditRetention {
+    /// Returns true if neither limit is set.
+    pub fn is_unbounded(&self) -> bool {
+        self.max_entries.is_none() && self.max_age.is_none()
+    }
 }
 
 /// Severity level of detected schema drift.
@@ -205,7 +670,7 @@ pub enum DriftLevel {
 ///     println!("Security review required: {:?}", report.changes);
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DriftReport {
     /// Overall severity of the detected drift.
     pub level: DriftLevel,
@@ -220,6 +685,39 @@ pub struct DriftReport {
     pub new_hash: Hash,
 }
 
+/// Cumulative drift of a tool across its recent accepted versions.
+///
+/// Produced by [`RegistryGuard::drift_velocity`](crate::RegistryGuard::drift_velocity).
+/// See [`drift_velocity`](crate::drift::drift_velocity) for how it is scored.
+///
+/// # Security Notes
+///
+/// A flagged tool has accumulated enough approved change to deserve a
+/// fresh review against its original purpose, even if every step was Minor.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DriftVelocity {
+    /// Name of the tool.
+    pub tool: String,
+
+    /// Number of versions compared, including the oldest.
+    pub versions: usize,
+
+    /// Level of each accepted change, oldest first.
+    pub steps: Vec<DriftLevel>,
+
+    /// Level of the drift from the oldest version straight to the newest.
+    pub cumulative: DriftLevel,
+
+    /// Changes from the oldest version straight to the newest.
+    pub changes: Vec<String>,
+
+    /// Sum of the step weights.
+    pub velocity: u32,
+
+    /// Whether `velocity` reached the configured threshold.
+    pub flagged: bool,
+}
+
 /// A node in the Merkle proof path.
 ///
 /// Represents a single step in the proof from a leaf hash to the root.
@@ -251,9 +749,11 @@ pub struct MerkleProofNode {
 ///
 /// # Fields
 ///
+/// - `key`: Key of the proven leaf (the tool name's bytes)
 /// - `leaf_hash`: Hash of the tool schema being proven
 /// - `path`: Sequence of sibling hashes from leaf to root
 /// - `root_hash`: Expected root hash for verification
+/// - `epoch`: Registry epoch the root belongs to
 ///
 /// # Security Notes
 ///
@@ -261,6 +761,11 @@ pub struct MerkleProofNode {
 /// using the path and comparing to a trusted root. The root must be
 /// obtained through a secure channel.
 ///
+/// The epoch is not covered by the hash path; it is bound to the root
+/// instead. `MerkleTree::verify_proof_fresh` checks the proof against the
+/// epoch-bound root `H(root || epoch)`, so a replayed proof with a rewritten
+/// epoch is rejected.
+///
 /// # Example
 ///
 /// ```rust
@@ -271,6 +776,11 @@ pub struct MerkleProofNode {
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MerkleProof {
+    /// Key of the leaf being proven. Hashed with `leaf_hash`, so the proof
+    /// only verifies for this key.
+    #[serde(default)]
+    pub key: Vec<u8>,
+
     /// Hash of the leaf being proven.
     pub leaf_hash: Hash,
 
@@ -279,6 +789,10 @@ pub struct MerkleProof {
 
     /// Root hash at the time the proof was generated.
     pub root_hash: Hash,
+
+    /// Registry epoch of `root_hash`. Increases on every registry change.
+    #[serde(default)]
+    pub epoch: u64,
 }
 
 /// Errors that can occur during registry operations.
@@ -299,6 +813,11 @@ pub enum RegistryError {
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
+    /// A stored value could not be decoded (the database is corrupt or was
+    /// modified outside the registry).
+    #[error("Corrupt registry data: {0}")]
+    Corrupt(String),
+
     /// The requested tool was not found in the registry.
     #[error("Tool not found: {0}")]
     NotFound(String),
@@ -307,9 +826,134 @@ pub enum RegistryError {
     #[error("Tool already registered: {0}")]
     AlreadyExists(String),
 
+    /// A tool was re-registered from a different origin than it was first
+    /// registered from (possible shadow server).
+    #[error(
+        "Tool '{tool}' is registered from '{expected}', refusing registration from '{actual}'"
+    )]
+    OriginMismatch {
+        /// Name of the tool.
+        tool: String,
+        /// Origin the tool is registered from.
+        expected: String,
+        /// Origin of the rejected registration.
+        actual: String,
+    },
+
+    /// A tool schema is malformed.
+    #[error("Invalid schema for tool '{tool}': {reason}")]
+    InvalidSchema {
+        /// Name of the tool.
+        tool: String,
+        /// What is wrong with the schema.
+        reason: String,
+    },
+
+    /// The firewall blocked a tool schema at registration (possible
+    /// poisoned tool description).
+    #[error("Tool '{tool}' schema rejected by firewall: {detail}")]
+    PoisonedSchema {
+        /// Name of the tool.
+        tool: String,
+        /// Firewall detail for the blocking threat.
+        detail: String,
+    },
+
     /// The provided Merkle proof is invalid.
     #[error("Invalid Merkle proof")]
     InvalidProof,
+
+    /// A stored schema no longer matches its stored hash (the database was
+    /// modified outside the registry).
+    #[error("Stored schema for '{0}' does not match its recorded hash")]
+    IntegrityViolation(String),
+
+    /// The database is held open by another process (or another handle in
+    /// this process), so its file lock could not be acquired.
+    #[error(
+        "Registry database at '{path}' is locked by another process; \
+         stop the other sentinel instance or use a different path"
+    )]
+    DatabaseLocked {
+        /// Path of the locked database.
+        path: String,
+    },
+
+    /// No registry database exists at the path, and the caller asked not
+    /// to create one.
+    #[error("No registry database at '{path}'")]
+    DatabaseMissing {
+        /// Path that was looked up.
+        path: String,
+    },
+}
+
+impl RegistryError {
+    /// Returns a message safe to show to clients.
+    ///
+    /// `Display` carries full detail for operators (database paths, origins,
+    /// parser positions). This message names the failure without exposing
+    /// any of that; tool names are kept since the client supplied them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::RegistryError;
+    ///
+    /// let err = RegistryError::DatabaseLocked {
+    ///     path: "/var/lib/sentinel/registry.db".to_string(),
+    /// };
+    /// assert!(err.to_string().contains("/var/lib"));
+    /// assert!(!err.user_message().contains("/var/lib"));
+    /// ```
+    pub fn user_message(&self) -> String {
+        match self {
+            RegistryError::Database(_)
+            | RegistryError::DatabaseLocked { .. }
+            | RegistryError::DatabaseMissing { .. } => "Tool registry is unavailable".to_string(),
+            RegistryError::Serialization(_) => "Tool schema could not be parsed".to_string(),
+            RegistryError::Corrupt(_) => "Tool registry failed an integrity check".to_string(),
+            RegistryError::NotFound(tool) => format!("Tool '{}' is not registered", tool),
+            RegistryError::AlreadyExists(tool) => format!("Tool '{}' is already registered", tool),
+            RegistryError::OriginMismatch { tool, .. } => {
+                format!("Tool '{}' was offered by an unexpected server", tool)
+            }
+            RegistryError::InvalidSchema { tool, reason } => {
+                format!("Invalid schema for tool '{}': {}", tool, reason)
+            }
+            RegistryError::PoisonedSchema { tool, .. } => {
+                format!("Tool '{}' was rejected as unsafe", tool)
+            }
+            RegistryError::InvalidProof => "Invalid Merkle proof".to_string(),
+            RegistryError::IntegrityViolation(_) => {
+                "Tool registry failed an integrity check".to_string()
+            }
+        }
+    }
+
+    /// Returns whether this error means stored registry data failed an
+    /// integrity check, as opposed to the registry being unavailable.
+    ///
+    /// A stored hash or proof that cannot be decoded says the database was
+    /// modified outside the registry, so callers should treat it as a
+    /// security failure rather than a transient infrastructure error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::RegistryError;
+    ///
+    /// assert!(RegistryError::InvalidProof.is_integrity_failure());
+    /// assert!(!RegistryError::NotFound("tool".to_string()).is_integrity_failure());
+    /// ```
+    pub fn is_integrity_failure(&self) -> bool {
+        matches!(
+            self,
+            RegistryError::Corrupt(_)
+                | RegistryError::InvalidProof
+                | RegistryError::IntegrityViolation(_)
+        )
+    }
 }
 
 /// Result type for registry operations.
@@ -319,6 +963,116 @@ pub type Result<T> = std::result::Result<T, RegistryError>;
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_database_error_exposes_source_without_leaking_path() {
+        use std::error::Error as _;
+
+        let io = std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "/srv/secret/registry.db: permission denied",
+        );
+        let err = RegistryError::from(sled::Error::Io(io));
+
+        let source = err.source().expect("sled error is the source");
+        assert!(source.downcast_ref::<sled::Error>().is_some());
+        assert!(err.to_string().contains("/srv/secret"));
+        assert!(!err.user_message().contains("/srv/secret"));
+        assert_eq!(err.user_message(), "Tool registry is unavailable");
+    }
+
+    #[test]
+    fn test_builder_validates() {
+        let err = ToolSchema::builder("").build().unwrap_err();
+        assert!(matches!(err, RegistryError::InvalidSchema { .. }));
+
+        let err = ToolSchema::builder("t")
+            .input_schema(serde_json::json!({ "type": "strnig" }))
+            .build()
+            .unwrap_err();
+        assert!(err.to_string().contains("input_schema"), "{err}");
+
+        assert!(ToolSchema::builder("t")
+            .output_schema(serde_json::json!("object"))
+            .build()
+            .is_err());
+
+        let schema = ToolSchema::builder("read_file")
+            .description("Read a file")
+            .input_schema(serde_json::json!({ "type": ["object", "null"] }))
+            .output_schema(serde_json::json!(true))
+            .build()
+            .unwrap();
+        assert_eq!(schema.name, "read_file");
+        assert_eq!(schema.description, "Read a file");
+    }
+
+    #[test]
+    fn test_from_tools_list_parses_mcp_wire_format() {
+        let response = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "tools": [
+                    {
+                        "name": "get_weather",
+                        "title": "Weather Information Provider",
+                        "description": "Get current weather information for a location",
+                        "inputSchema": {
+                            "type": "object",
+                            "properties": {
+                                "location": {
+                                    "type": "string",
+                                    "description": "City name or zip code"
+                                }
+                            },
+                            "required": ["location"]
+                        },
+                        "annotations": { "readOnlyHint": true }
+                    },
+                    {
+                        "name": "list_files",
+                        "inputSchema": { "type": "object" },
+                        "outputSchema": {
+                            "type": "array",
+                            "items": { "type": "string" }
+                        }
+                    }
+                ]
+            }
+        });
+
+        let tools = ToolSchema::from_tools_list(&response).unwrap();
+        assert_eq!(tools.len(), 2);
+        assert_eq!(tools[0].name, "get_weather");
+        assert_eq!(
+            tools[0].description,
+            "Get current weather information for a location"
+        );
+        assert_eq!(
+            tools[0].input_schema["required"],
+            serde_json::json!(["location"])
+        );
+        assert_eq!(tools[0].output_schema, serde_json::json!({}));
+        assert_eq!(tools[1].description, "");
+        assert_eq!(tools[1].output_schema["type"], "array");
+
+        // The bare result parses the same
+        assert_eq!(
+            ToolSchema::from_tools_list(&response["result"]).unwrap(),
+            tools
+        );
+
+        for bad in [
+            serde_json::json!({ "tools": [{ "inputSchema": {} }] }),
+            serde_json::json!({ "tools": [{ "name": "t" }] }),
+            serde_json::json!({ "tools": [{ "name": "t", "inputSchema": 3 }] }),
+            serde_json::json!({ "result": {} }),
+        ] {
+            let err = ToolSchema::from_tools_list(&bad).unwrap_err();
+            assert!(matches!(err, RegistryError::InvalidSchema { .. }), "{bad}");
+        }
+    }
+
     #[test]
     fn test_drift_level_ordering() {
         assert!(DriftLevel::None < DriftLevel::Minor);
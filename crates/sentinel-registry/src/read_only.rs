@@ -0,0 +1,384 @@
+//! # Read-Only Registry Handle
+//!
+//! A [`ReadOnlyRegistry`] verifies tools against a registry without any
+//! power to change it. Obtain one from
+//! [`RegistryGuard::read_only_handle`](crate::RegistryGuard::read_only_handle),
+//! hand it to components that only check tools, such as a gateway's request
+//! path, and keep the guard with the component that registers them. Tools
+//! that only inspect a registry on disk can [`open`](ReadOnlyRegistry::open)
+//! one directly.
+//!
+//! The handle shares the guard's open database, so it always sees the
+//! current registrations: a tool registered through the guard after the
+//! handle was created verifies through the handle too. The Merkle tree is
+//! rebuilt from the stored hashes once per epoch and reused until the next
+//! change.
+//!
+//! ## Security Notes
+//!
+//! - The handle has no mutating methods; registration power cannot be
+//!   reached through it
+//! - A handle from `read_only_handle` shares the guard's registration times
+//!   and TTL, so a tool that expired for the guard is `Unknown` here too
+//! - A handle from `open` has no registration times and applies no TTL
+//! - [`verify_tool_from`](ReadOnlyRegistry::verify_tool_from) enforces origin
+//!   pins exactly as the guard does
+
+use crate::canonicalize::hash_tool_schema_with;
+use crate::drift::{detect_drift_with_algorithm, new_tool_report_with, DriftConfig};
+use crate::merkle::MerkleTree;
+use crate::models::{DriftReport, Hash, MerkleProof, Result, ToolSchema, VerifyResult};
+use crate::registry::compare_hash;
+use crate::registry::Registrations;
+use crate::storage::{SledStorage, Storage};
+use std::path::Path;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Verification-only view of a registry.
+///
+/// Obtained from [`RegistryGuard::read_only_handle`]. Cheap to clone.
+///
+/// [`RegistryGuard::read_only_handle`]: crate::RegistryGuard::read_only_handle
+///
+/// # Example
+///
+/// ```rust
+/// use sentinel_registry::{RegistryGuard, ToolSchema, VerifyResult};
+///
+/// let mut registry = RegistryGuard::temporary().unwrap();
+/// let verifier = registry.read_only_handle();
+///
+/// let tool = ToolSchema::builder("read_file").build().unwrap();
+/// registry.register_tool(&tool).unwrap();
+///
+/// assert_eq!(verifier.verify_tool(&tool), VerifyResult::Valid);
+/// ```
+///
+/// The handle cannot register tools:
+///
+/// ```compile_fail
+/// use sentinel_registry::{RegistryGuard, ToolSchema};
+///
+/// let verifier = RegistryGuard::temporary().unwrap().read_only_handle();
+/// verifier.register_tool(&ToolSchema::builder("x").build().unwrap());
+/// ```
+#[derive(Clone)]
+pub struct ReadOnlyRegistry {
+    /// Shared handle to the guard's database.
+    storage: Arc<dyn Storage>,
+
+    /// How drift reports are scored.
+    drift_config: DriftConfig,
+
+    /// The guard's registration times, if the handle came from a guard.
+    registrations: Option<Arc<RwLock<Registrations>>>,
+
+    /// Merkle tree of the last epoch a proof or root was built for.
+    tree: Arc<Mutex<Option<MerkleTree>>>,
+}
+
+impl ReadOnlyRegistry {
+    /// Creates a handle over `storage`.
+    pub(crate) fn new(
+        storage: Arc<dyn Storage>,
+        drift_config: DriftConfig,
+        registrations: Option<Arc<RwLock<Registrations>>>,
+    ) -> Self {
+        Self {
+            storage,
+            drift_config,
+            registrations,
+            tree: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Opens an existing registry database for verification only.
+    ///
+    /// Nothing is created or written: a missing database is an error, and
+    /// the handle has no mutating methods. Sled has no shared read mode, so
+    /// the database is still locked while the handle is alive.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::DatabaseMissing` if nothing exists at `path`,
+    /// `RegistryError::DatabaseLocked` if a running gateway holds it, or
+    /// `RegistryError::Database` if it cannot be opened.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let storage = SledStorage::open_existing(path)?;
+        Ok(Self::new(Arc::new(storage), DriftConfig::default(), None))
+    }
+
+    /// Verifies a tool against its registered hash.
+    ///
+    /// Same as [`RegistryGuard::verify_tool`]. A storage failure is reported
+    /// as `VerifyResult::Unknown`.
+    ///
+    /// [`RegistryGuard::verify_tool`]: crate::RegistryGuard::verify_tool
+    pub fn verify_tool(&self, tool: &ToolSchema) -> VerifyResult {
+        self.try_verify_tool(tool).unwrap_or(VerifyResult::Unknown)
+    }
+
+    /// Verifies a tool, surfacing storage errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database read fails or a stored value is corrupt.
+    pub fn try_verify_tool(&self, tool: &ToolSchema) -> Result<VerifyResult> {
+        if self.is_expired(&tool.name) {
+            return Ok(VerifyResult::Unknown);
+        }
+        // Read on every call, so a migration through the guard is seen here
+        let algorithm = self.storage.load_hash_algorithm()?;
+        let stored = self.storage.load_hash(&tool.name)?;
+        Ok(compare_hash(stored, hash_tool_schema_with(tool, algorithm)))
+    }
+
+    /// Verifies a tool presented by a specific origin (source server).
+    ///
+    /// Same as [`RegistryGuard::verify_tool_from`]: a tool pinned to another
+    /// origin is `VerifyResult::OriginMismatch` even if its schema matches.
+    ///
+    /// [`RegistryGuard::verify_tool_from`]: crate::RegistryGuard::verify_tool_from
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database read fails or a stored value is corrupt.
+    pub fn verify_tool_from(&self, tool: &ToolSchema, origin: &str) -> Result<VerifyResult> {
+        if let Some(expected) = self.storage.load_origin(&tool.name)? {
+            if expected != origin {
+                return Ok(VerifyResult::OriginMismatch {
+                    expected,
+                    actual: origin.to_string(),
+                });
+            }
+        }
+
+        self.try_verify_tool(tool)
+    }
+
+    /// Analyzes how a tool drifted from its registered schema.
+    ///
+    /// Same as [`RegistryGuard::detect_drift`], scored with the guard's
+    /// drift configuration at the time the handle was created.
+    ///
+    /// [`RegistryGuard::detect_drift`]: crate::RegistryGuard::detect_drift
+    pub fn detect_drift(&self, tool: &ToolSchema) -> DriftReport {
+        // An unreadable algorithm id falls back to SHA-256, like a new tool
+        let algorithm = self.storage.load_hash_algorithm().unwrap_or_default();
+        match self.storage.load_tool(&tool.name) {
+            Ok(Some((old_tool, _))) => {
+                detect_drift_with_algorithm(&old_tool, tool, &self.drift_config, algorithm)
+            }
+            Ok(None) | Err(_) => new_tool_report_with(tool, algorithm),
+        }
+    }
+
+    /// Returns true if the tool's registration has outlived the guard's TTL.
+    ///
+    /// Always false for a handle from [`ReadOnlyRegistry::open`].
+    pub fn is_expired(&self, tool_name: &str) -> bool {
+        self.registrations.as_ref().is_some_and(|registrations| {
+            registrations
+                .read()
+                .unwrap_or_else(|e| e.into_inner())
+                .is_expired(tool_name)
+        })
+    }
+
+    /// Returns true if a tool with this name is registered.
+    pub fn contains(&self, tool_name: &str) -> bool {
+        self.storage.contains(tool_name).unwrap_or(false)
+    }
+
+    /// Lists all registered tool names in lexicographic order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails.
+    pub fn list_tools(&self) -> Result<Vec<String>> {
+        self.storage.list_tools()
+    }
+
+    /// Returns the registered hash of a tool.
+    pub fn get_tool_hash(&self, tool_name: &str) -> Option<Hash> {
+        self.storage.load_hash(tool_name).ok().flatten()
+    }
+
+    /// Generates a Merkle proof for a registered tool against the current root.
+    ///
+    /// # Returns
+    ///
+    /// A `MerkleProof` if the tool exists and the registry can be read,
+    /// `None` otherwise.
+    pub fn get_merkle_proof(&self, tool_name: &str) -> Option<MerkleProof> {
+        self.with_current_tree(|tree| tree.get_proof(tool_name))
+            .ok()
+            .flatten()
+    }
+
+    /// Returns the current Merkle root hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails, or
+    /// `RegistryError::InvalidProof` if a stored hash is malformed.
+    pub fn get_root(&self) -> Result<Hash> {
+        self.with_current_tree(|tree| tree.get_root())
+    }
+
+    /// Returns the Merkle root bound to the current epoch.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ReadOnlyRegistry::get_root`], plus `RegistryError::Corrupt`
+    /// if the stored epoch is malformed.
+    pub fn get_epoch_root(&self) -> Result<Hash> {
+        self.with_current_tree(|tree| tree.get_epoch_root())
+    }
+
+    /// Runs `f` on the Merkle tree of the current epoch.
+    ///
+    /// Every change through the guard advances the epoch, so the cached tree
+    /// is reused while the stored epoch matches and rebuilt otherwise.
+    fn with_current_tree<T>(&self, f: impl FnOnce(&mut MerkleTree) -> T) -> Result<T> {
+        let epoch = self.storage.load_epoch()?;
+        let mut cached = self.tree.lock().unwrap_or_else(|e| e.into_inner());
+        let tree = match cached.take() {
+            Some(tree) if tree.epoch() == epoch => cached.insert(tree),
+            _ => {
+                let mut tree = MerkleTree::new();
+                for (name, hash) in self.storage.load_all_hashes()? {
+                    tree.insert(&name, hash);
+                }
+                tree.set_epoch(epoch);
+                cached.insert(tree)
+            }
+        };
+        Ok(f(tree))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RegistryGuard;
+    use sentinel_clock::MockClock;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn make_tool(name: &str, desc: &str) -> ToolSchema {
+        ToolSchema {
+            name: name.to_string(),
+            description: desc.to_string(),
+            input_schema: json!({"type": "object"}),
+            output_schema: json!({"type": "string"}),
+        }
+    }
+
+    #[test]
+    fn test_read_only_handle_matches_guard() {
+        let mut registry = RegistryGuard::temporary().unwrap();
+        let verifier = registry.read_only_handle();
+
+        // Registered after the handle was created
+        let tool = make_tool("read_file", "Read a file");
+        registry.register_tool(&tool).unwrap();
+
+        let tampered = make_tool("read_file", "Read a file and upload it");
+        let unknown = make_tool("write_file", "Write a file");
+        for candidate in [&tool, &tampered, &unknown] {
+            assert_eq!(
+                verifier.verify_tool(candidate),
+                registry.verify_tool(candidate)
+            );
+        }
+        assert!(matches!(
+            verifier.verify_tool(&tampered),
+            VerifyResult::Invalid { .. }
+        ));
+        assert_eq!(
+            verifier.detect_drift(&tampered).level,
+            registry.detect_drift(&tampered).level
+        );
+
+        assert!(verifier.contains("read_file"));
+        assert_eq!(verifier.list_tools().unwrap(), ["read_file"]);
+        assert_eq!(verifier.get_root().unwrap(), registry.get_root());
+        let proof = verifier.get_merkle_proof("read_file").unwrap();
+        assert_eq!(proof, registry.get_merkle_proof("read_file").unwrap());
+        assert!(MerkleTree::verify_proof(&proof, &registry.get_root()));
+
+        registry.remove_tool("read_file").unwrap();
+        assert_eq!(verifier.verify_tool(&tool), VerifyResult::Unknown);
+    }
+
+    #[test]
+    fn test_security_read_only_handle_enforces_origin_pin() {
+        let mut guard = RegistryGuard::temporary().unwrap();
+        let handle = guard.read_only_handle();
+        let tool = make_tool("read_file", "Read a file");
+        guard.register_tool_from(&tool, "stdio:fs-server").unwrap();
+
+        assert_eq!(
+            handle.verify_tool_from(&tool, "stdio:fs-server").unwrap(),
+            VerifyResult::Valid
+        );
+        assert_eq!(
+            handle
+                .verify_tool_from(&tool, "https://shadow.example")
+                .unwrap(),
+            VerifyResult::OriginMismatch {
+                expected: "stdio:fs-server".to_string(),
+                actual: "https://shadow.example".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_security_read_only_handle_honors_ttl() {
+        let clock = MockClock::new();
+        let mut registry = RegistryGuard::temporary()
+            .unwrap()
+            .with_clock(clock.shared())
+            .with_ttl(Duration::from_secs(60));
+        let verifier = registry.read_only_handle();
+        let tool = make_tool("read_file", "Read a file");
+        registry.register_tool(&tool).unwrap();
+
+        assert_eq!(verifier.verify_tool(&tool), VerifyResult::Valid);
+        clock.advance(Duration::from_secs(61));
+        assert!(verifier.is_expired("read_file"));
+        assert_eq!(verifier.verify_tool(&tool), VerifyResult::Unknown);
+
+        // Renewal through the guard is seen by the handle
+        registry.register_tool_if_changed(&tool).unwrap();
+        assert_eq!(verifier.verify_tool(&tool), VerifyResult::Valid);
+    }
+
+    #[test]
+    fn test_merkle_tree_cached_per_epoch() {
+        let mut registry = RegistryGuard::temporary().unwrap();
+        let verifier = registry.read_only_handle();
+        registry
+            .register_tool(&make_tool("read_file", "Read a file"))
+            .unwrap();
+
+        let root = verifier.get_epoch_root().unwrap();
+        assert_eq!(verifier.get_epoch_root().unwrap(), root);
+        assert_eq!(
+            verifier.get_merkle_proof("read_file"),
+            verifier.get_merkle_proof("read_file")
+        );
+
+        // A new registration advances the epoch and invalidates the cache
+        registry
+            .register_tool(&make_tool("write_file", "Write a file"))
+            .unwrap();
+        assert_ne!(verifier.get_epoch_root().unwrap(), root);
+        assert_eq!(
+            verifier.get_epoch_root().unwrap(),
+            registry.get_epoch_root()
+        );
+        assert!(verifier.get_merkle_proof("write_file").is_some());
+    }
+}
@@ -1,8 +1,17 @@
 //! # Persistent Storage Layer
 //!
-//! This module provides a persistence layer using Sled, an embedded database.
-//! It stores tool schemas and their hashes, enabling the registry to survive
-//! restarts and maintain an audit trail of registrations.
+//! This module defines the [`Storage`] trait the registry persists through,
+//! and its default implementation, [`SledStorage`], backed by Sled, an
+//! embedded database. Storage keeps tool schemas and their hashes, enabling
+//! the registry to survive restarts and maintain an audit trail of
+//! registrations.
+//!
+//! ## Backends
+//!
+//! [`RegistryGuard::new`](crate::RegistryGuard::new) opens a `SledStorage`.
+//! To share one registry between several gateway replicas, implement
+//! `Storage` over a networked store and pass it to
+//! [`RegistryGuard::with_storage`](crate::RegistryGuard::with_storage).
 //!
 //! ## Threat Model
 //!
@@ -14,26 +23,44 @@
 //!
 //! ## Storage Structure
 //!
-//! The database uses two trees (namespaces):
+//! The Sled database uses the following trees (namespaces):
 //!
 //! | Tree | Key | Value | Purpose |
 //! |------|-----|-------|---------|
 //! | `schemas` | tool name | serialized ToolSchema | Schema storage |
 //! | `hashes` | tool name | 32-byte hash | Quick verification |
+//! | `origins` | tool name | origin identifier | Shadow server detection |
+//! | `migrations` | epoch | serialized HashMigration | Hash algorithm history |
+//! | `audit` | epoch | serialized AuditEntry | Registration audit trail |
+//! | `history` | tool name | serialized ToolSchema list | Drift velocity |
 //!
 //! ## Security Notes
 //!
 //! - The database file should be stored on encrypted storage
 //! - File permissions should restrict access to the sentinel process
 //! - Regular backups are recommended for audit purposes
+//! - A networked backend is part of the trusted computing base: whoever can
+//!   write to it can register tools
 //!
 //! ## References
 //!
 //! - Sled documentation: <https://sled.rs/>
 //! - LMDB (similar architecture): <https://www.symas.com/lmdb>
 
-use crate::models::{Hash, RegistryError, Result, ToolSchema, HASH_SIZE};
+use crate::models::{
+    AuditAction, AuditEntry, AuditRetention, Hash, HashAlgorithm, HashMigration, RegistryError,
+    Result, ToolSchema, HASH_SIZE,
+};
+use sled::transaction::{
+    ConflictableTransactionError, ConflictableTransactionResult, TransactionError,
+    TransactionalTree,
+};
+use sled::Transactional;
+use std::collections::{BTreeSet, HashMap};
+use std::io::ErrorKind;
 use std::path::Path;
+use std::thread;
+use std::time::Duration;
 
 /// Tree name for storing tool schemas.
 const SCHEMA_TREE: &str = "schemas";
@@ -41,25 +68,60 @@ const SCHEMA_TREE: &str = "schemas";
 /// Tree name for storing tool hashes.
 const HASH_TREE: &str = "hashes";
 
-/// Wrapper around a Sled database for registry storage.
+/// Tree name for storing the origin each tool was registered from.
+const ORIGIN_TREE: &str = "origins";
+
+/// Tree name for the hash algorithm migration history.
+const MIGRATION_TREE: &str = "migrations";
+
+/// Tree name for the registration audit log.
+const AUDIT_TREE: &str = "audit";
+
+/// Tree name for each tool's accepted schema versions.
+const HISTORY_TREE: &str = "history";
+
+/// Key in the default tree holding the Merkle root epoch.
+const EPOCH_KEY: &[u8] = b"merkle_epoch";
+
+/// Key in the default tree holding the active hash algorithm.
+const HASH_ALGORITHM_KEY: &[u8] = b"hash_algorithm";
+
+/// Number of attempts to acquire the database file lock before giving up.
 ///
-/// Provides high-level operations for storing and retrieving tool schemas
-/// and their hashes. The database is opened in durable mode to ensure
-/// data survives crashes.
+/// Sled releases its lock from a background thread after the last handle is
+/// dropped, so a reopen in the same process can briefly race the release.
+const LOCK_ATTEMPTS: u32 = 10;
+
+/// Delay between lock acquisition attempts.
+const LOCK_RETRY_DELAY: Duration = Duration::from_millis(25);
+
+/// Persistence backend for a registry.
 ///
-/// # Thread Safety
+/// Holds, per tool, the schema, its hash and the origin it was registered
+/// from, plus registry-wide state: the Merkle root epoch, the active hash
+/// algorithm, the migration history and the audit log.
 ///
-/// The underlying Sled database is thread-safe. Multiple threads can
-/// read and write concurrently.
+/// Opening a backend is not part of the trait, since each one is addressed
+/// differently (a path, a URL); construct it, then hand it to
+/// [`RegistryGuard::with_storage`](crate::RegistryGuard::with_storage).
+///
+/// # Implementing
+///
+/// Methods with default implementations are written in terms of the
+/// required ones and only need overriding when the backend can do better,
+/// such as reading every hash in one round trip. Implementations must be
+/// safe to share between threads; a backend shared by several processes
+/// must make [`bump_epoch`](Self::bump_epoch) and
+/// [`replace_hashes`](Self::replace_hashes) atomic across all of them.
 ///
 /// # Example
 ///
-/// ```rust,no_run
-/// use sentinel_registry::storage::Storage;
+/// ```rust
+/// use sentinel_registry::storage::{SledStorage, Storage};
 /// use sentinel_registry::ToolSchema;
 /// use serde_json::json;
 ///
-/// let storage = Storage::open("./registry.db").unwrap();
+/// let storage = SledStorage::temporary().unwrap();
 ///
 /// let tool = ToolSchema {
 ///     name: "read_file".to_string(),
@@ -71,13 +133,503 @@ const HASH_TREE: &str = "hashes";
 /// let hash = [1u8; 32];
 /// storage.store_tool(&tool, hash).unwrap();
 ///
-/// if let Some((loaded_tool, loaded_hash)) = storage.load_tool("read_file").unwrap() {
-///     assert_eq!(loaded_tool.name, "read_file");
-///     assert_eq!(loaded_hash, hash);
-/// }
-/// ```
+/// if let Some((loaded_tool, loaded_hash)) = storage.load_tool("read_file").unwrap() {
+///     assert_eq!(loaded_tool.name, "read_file");
+///     assert_eq!(loaded_hash, hash);
+/// }
+/// ```
+pub trait Storage: Send + Sync {
+    /// Stores a tool schema and its hash.
+    ///
+    /// If a tool with the same name already exists, it will be overwritten.
+    /// Use `load_tool` first if you need to check for existing entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool` - The tool schema to store
+    /// * `hash` - The canonical hash of the tool schema
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Serialization` if the schema cannot be serialized.
+    /// Returns `RegistryError::Database` if writing to the database fails.
+    fn store_tool(&self, tool: &ToolSchema, hash: Hash) -> Result<()>;
+
+    /// Loads a tool schema and its hash by name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tool to load
+    ///
+    /// # Returns
+    ///
+    /// `Some((schema, hash))` if found, `None` if not found.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Serialization` if the stored schema is corrupted.
+    /// Returns `RegistryError::Database` if reading from the database fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::storage::{SledStorage, Storage};
+    ///
+    /// let storage = SledStorage::temporary().unwrap();
+    ///
+    /// match storage.load_tool("read_file").unwrap() {
+    ///     Some((tool, hash)) => println!("Found: {}", tool.name),
+    ///     None => println!("Tool not registered"),
+    /// }
+    /// ```
+    fn load_tool(&self, name: &str) -> Result<Option<(ToolSchema, Hash)>>;
+
+    /// Loads only the hash for a tool.
+    ///
+    /// This is more efficient than `load_tool` when only the hash is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tool
+    ///
+    /// # Returns
+    ///
+    /// `Some(hash)` if found, `None` if not found.
+    fn load_hash(&self, name: &str) -> Result<Option<Hash>>;
+
+    /// Loads every stored hash.
+    ///
+    /// The default calls [`load_hash`](Self::load_hash) once per listed tool.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails, or
+    /// `RegistryError::InvalidProof` if a name or hash is malformed.
+    fn load_all_hashes(&self) -> Result<HashMap<String, Hash>> {
+        let mut hashes = HashMap::new();
+        for name in self.list_tools()? {
+            if let Some(hash) = self.load_hash(&name)? {
+                hashes.insert(name, hash);
+            }
+        }
+        Ok(hashes)
+    }
+
+    /// Records the origin (source server) a tool was registered from.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tool
+    /// * `origin` - Identifier of the server that provided the tool
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if writing fails.
+    fn store_origin(&self, name: &str, origin: &str) -> Result<()>;
+
+    /// Loads the origin a tool was registered from.
+    ///
+    /// # Returns
+    ///
+    /// `Some(origin)` if one was recorded, `None` if the tool is unknown or
+    /// was registered without an origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails, or
+    /// `RegistryError::Corrupt` if the stored value is not UTF-8.
+    fn load_origin(&self, name: &str) -> Result<Option<String>>;
+
+    /// Stores a tool schema, its hash and its origin together.
+    ///
+    /// The default calls [`store_tool`](Self::store_tool) then
+    /// [`store_origin`](Self::store_origin). Backends that can should
+    /// override it to write all three in one batch, so a crash never leaves
+    /// a new schema stored without its origin pin.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Serialization` if the schema cannot be
+    /// serialized, or `RegistryError::Database` if writing fails.
+    fn store_tool_from(&self, tool: &ToolSchema, hash: Hash, origin: &str) -> Result<()> {
+        self.store_tool(tool, hash)?;
+        self.store_origin(&tool.name, origin)
+    }
+
+    /// Lists all registered tool names.
+    ///
+    /// # Returns
+    ///
+    /// A vector of tool names in lexicographic order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::storage::{SledStorage, Storage};
+    ///
+    /// let storage = SledStorage::temporary().unwrap();
+    /// let tools = storage.list_tools().unwrap();
+    /// println!("Registered tools: {:?}", tools);
+    /// ```
+    fn list_tools(&self) -> Result<Vec<String>>;
+
+    /// Lists the name of every stored schema or hash without decoding either.
+    ///
+    /// For integrity checks that must keep going past a corrupt entry. The
+    /// default combines [`list_tools`](Self::list_tools) and
+    /// [`load_all_hashes`](Self::load_all_hashes), so it still fails on a
+    /// malformed hash; backends that can list keys directly override it.
+    ///
+    /// # Returns
+    ///
+    /// Names in lexicographic order, without duplicates. A name that is not
+    /// valid UTF-8 is converted lossily.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails.
+    fn list_entry_names(&self) -> Result<Vec<String>> {
+        let mut names: BTreeSet<String> = self.list_tools()?.into_iter().collect();
+        names.extend(self.load_all_hashes()?.into_keys());
+        Ok(names.into_iter().collect())
+    }
+
+    /// Iterates over every stored tool with its hash, in lexicographic order.
+    ///
+    /// A schema without a stored hash is skipped, matching
+    /// [`load_tool`](Self::load_tool). The default lists the names up front
+    /// and loads each tool as the iterator advances.
+    ///
+    /// # Errors
+    ///
+    /// Each item is `Err` with `RegistryError::Database` if reading fails,
+    /// `RegistryError::Serialization` if the stored schema is corrupted, or
+    /// `RegistryError::InvalidProof` if the name or hash is malformed.
+    fn iter_tools(&self) -> Box<dyn Iterator<Item = Result<(String, ToolSchema, Hash)>> + '_> {
+        let names = match self.list_tools() {
+            Ok(names) => names,
+            Err(e) => return Box::new(std::iter::once(Err(e))),
+        };
+        Box::new(names.into_iter().filter_map(move |name| {
+            self.load_tool(&name)
+                .map(|found| found.map(|(tool, hash)| (name, tool, hash)))
+                .transpose()
+        }))
+    }
+
+    /// Checks if a tool is registered.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tool to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tool exists, `false` otherwise.
+    fn contains(&self, name: &str) -> Result<bool> {
+        Ok(self.load_tool(name)?.is_some())
+    }
+
+    /// Removes a tool's schema, hash and origin.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tool to remove
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tool was removed, `false` if it didn't exist.
+    fn remove_tool(&self, name: &str) -> Result<bool>;
+
+    /// Stores a registration and its version history, bumps the epoch and
+    /// appends its audit entry.
+    ///
+    /// The default calls [`store_tool`](Self::store_tool) (or
+    /// [`store_tool_from`](Self::store_tool_from) when an origin is given),
+    /// [`store_history`](Self::store_history),
+    /// [`bump_epoch`](Self::bump_epoch) and
+    /// [`append_audit`](Self::append_audit) in turn. Backends that can
+    /// should override it to write all of it in one transaction, so a crash
+    /// never leaves a change without its history, epoch or audit record.
+    ///
+    /// # Arguments
+    ///
+    /// * `tool` - The tool schema to store
+    /// * `hash` - The schema's hash
+    /// * `origin` - Origin to pin the tool to, if any
+    /// * `history` - The tool's accepted versions, oldest first, ending with `tool`
+    /// * `timestamp` - Time of the change, in seconds since the Unix epoch
+    ///
+    /// # Returns
+    ///
+    /// The new epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Serialization` if the schema cannot be
+    /// serialized, `RegistryError::Corrupt` if the stored epoch is corrupt,
+    /// or `RegistryError::Database` if writing fails.
+    fn commit_registration(
+        &self,
+        tool: &ToolSchema,
+        hash: Hash,
+        origin: Option<&str>,
+        history: &[ToolSchema],
+        timestamp: u64,
+    ) -> Result<u64> {
+        match origin {
+            Some(origin) => self.store_tool_from(tool, hash, origin)?,
+            None => self.store_tool(tool, hash)?,
+        }
+        self.store_history(&tool.name, history)?;
+        let epoch = self.bump_epoch()?;
+        self.append_audit(&AuditEntry {
+            epoch,
+            action: AuditAction::Registered,
+            tool: tool.name.clone(),
+            hash: Some(hash),
+            timestamp,
+        })?;
+        Ok(epoch)
+    }
+
+    /// Removes a tool, bumps the epoch and appends its audit entry.
+    ///
+    /// The default calls [`remove_tool`](Self::remove_tool), then
+    /// [`bump_epoch`](Self::bump_epoch) and
+    /// [`append_audit`](Self::append_audit) if the tool existed. Backends
+    /// that can should override it to write all of it in one transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tool to remove
+    /// * `timestamp` - Time of the change, in seconds since the Unix epoch
+    ///
+    /// # Returns
+    ///
+    /// The new epoch, or `None` if the tool didn't exist (nothing is
+    /// written).
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Corrupt` if the stored epoch is corrupt, or
+    /// `RegistryError::Database` if writing fails.
+    fn commit_removal(&self, name: &str, timestamp: u64) -> Result<Option<u64>> {
+        if !self.remove_tool(name)? {
+            return Ok(None);
+        }
+        let epoch = self.bump_epoch()?;
+        self.append_audit(&AuditEntry {
+            epoch,
+            action: AuditAction::Removed,
+            tool: name.to_string(),
+            hash: None,
+            timestamp,
+        })?;
+        Ok(Some(epoch))
+    }
+
+    /// Loads the current Merkle root epoch.
+    ///
+    /// # Returns
+    ///
+    /// The stored epoch, or 0 if the registry has never been modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails, or
+    /// `RegistryError::Corrupt` if the stored value is not a valid epoch.
+    fn load_epoch(&self) -> Result<u64>;
+
+    /// Atomically increments the Merkle root epoch.
+    ///
+    /// Called whenever the set of registered hashes changes, so every root
+    /// has a distinct, monotonically increasing epoch.
+    ///
+    /// # Returns
+    ///
+    /// The new epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if the update fails, or
+    /// `RegistryError::Corrupt` if the stored value is not a valid epoch (it
+    /// is left unchanged rather than reset).
+    fn bump_epoch(&self) -> Result<u64>;
+
+    /// Loads the hash algorithm the stored hashes were computed with.
+    ///
+    /// # Returns
+    ///
+    /// The recorded algorithm, or `HashAlgorithm::Sha256` for registries
+    /// that have never been migrated.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails, or
+    /// `RegistryError::Corrupt` if the stored identifier is unknown.
+    fn load_hash_algorithm(&self) -> Result<HashAlgorithm>;
+
+    /// Replaces every stored hash in a single transaction.
+    ///
+    /// Writes the new hashes, records `algorithm` as active, bumps the epoch
+    /// and appends a [`HashMigration`] record and an
+    /// [`AuditAction::Rehashed`] audit entry. Either all of it lands or none
+    /// of it does.
+    ///
+    /// # Arguments
+    ///
+    /// * `hashes` - New hash for each tool, keyed by tool name
+    /// * `algorithm` - The algorithm the new hashes were computed with
+    /// * `timestamp` - Time of the change, in seconds since the Unix epoch
+    ///
+    /// # Returns
+    ///
+    /// The migration record that was stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if the transaction fails, or
+    /// `RegistryError::Corrupt` if the stored epoch or algorithm is corrupt.
+    fn replace_hashes(
+        &self,
+        hashes: &[(String, Hash)],
+        algorithm: HashAlgorithm,
+        timestamp: u64,
+    ) -> Result<HashMigration>;
+
+    /// Loads the hash algorithm migration history, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails, or
+    /// `RegistryError::Serialization` if a record is corrupt.
+    fn load_migrations(&self) -> Result<Vec<HashMigration>>;
+
+    /// Appends an entry to the audit log, keyed by its epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if the write fails.
+    fn append_audit(&self, entry: &AuditEntry) -> Result<()>;
+
+    /// Replaces a tool's accepted schema versions.
+    ///
+    /// History outlives the tool: [`remove_tool`](Self::remove_tool) leaves
+    /// it in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the tool
+    /// * `versions` - Accepted versions, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Serialization` if a schema cannot be
+    /// serialized, or `RegistryError::Database` if writing fails.
+    fn store_history(&self, name: &str, versions: &[ToolSchema]) -> Result<()>;
+
+    /// Loads a tool's accepted schema versions, oldest first.
+    ///
+    /// # Returns
+    ///
+    /// The stored versions, or an empty list if none were recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails, or
+    /// `RegistryError::Serialization` if the record is corrupt.
+    fn load_history(&self, name: &str) -> Result<Vec<ToolSchema>>;
+
+    /// Loads the audit log, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading fails, or
+    /// `RegistryError::Serialization` if a record is corrupt.
+    fn load_audit_log(&self) -> Result<Vec<AuditEntry>>;
+
+    /// Removes the audit entries with the given epochs in a single
+    /// transaction. Epochs with no entry are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if the transaction fails.
+    fn remove_audit(&self, epochs: &[u64]) -> Result<()>;
+
+    /// Removes audit entries outside `retention`.
+    ///
+    /// # Arguments
+    ///
+    /// * `retention` - Entry count and age limits
+    /// * `now` - Current wall-clock time, in seconds since the Unix epoch
+    ///
+    /// # Returns
+    ///
+    /// The number of entries removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::Database` if reading or the transaction
+    /// fails, or `RegistryError::Serialization` if a record is corrupt.
+    fn prune_audit(&self, retention: &AuditRetention, now: u64) -> Result<usize> {
+        if retention.is_unbounded() {
+            return Ok(0);
+        }
+
+        let entries = self.load_audit_log()?;
+        let excess = retention
+            .max_entries
+            .map_or(0, |max| entries.len().saturating_sub(max));
+        let cutoff = retention
+            .max_age
+            .map(|age| now.saturating_sub(age.as_secs()));
+
+        let doomed: Vec<u64> = entries
+            .iter()
+            .enumerate()
+            .filter(|(index, entry)| {
+                *index < excess || cutoff.is_some_and(|cutoff| entry.timestamp < cutoff)
+            })
+            .map(|(_, entry)| entry.epoch)
+            .collect();
+
+        self.remove_audit(&doomed)?;
+        Ok(doomed.len())
+    }
+
+    /// Returns the number of registered tools.
+    ///
+    /// The default counts [`list_tools`](Self::list_tools), reporting 0 if
+    /// listing fails.
+    fn len(&self) -> usize {
+        self.list_tools().map_or(0, |tools| tools.len())
+    }
+
+    /// Returns true if no tools are registered.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Flushes all pending writes to durable storage.
+    ///
+    /// # Returns
+    ///
+    /// The number of bytes flushed, or 0 if the backend does not track it.
+    fn flush(&self) -> Result<usize>;
+}
+
+/// [`Storage`] backed by a Sled database.
+///
+/// The default backend. The database is opened in durable mode to ensure
+/// data survives crashes.
+///
+/// # Thread Safety
+///
+/// The underlying Sled database is thread-safe. Multiple threads can
+/// read and write concurrently.
 #[derive(Clone)]
-pub struct Storage {
+pub struct SledStorage {
     /// The underlying Sled database.
     db: sled::Db,
 
@@ -86,9 +638,21 @@ pub struct Storage {
 
     /// Tree for storing hashes.
     hashes: sled::Tree,
+
+    /// Tree for storing tool origins.
+    origins: sled::Tree,
+
+    /// Tree for storing hash algorithm migrations.
+    migrations: sled::Tree,
+
+    /// Tree for the append-only audit log.
+    audit: sled::Tree,
+
+    /// Tree for each tool's accepted schema versions.
+    history: sled::Tree,
 }
 
-impl Storage {
+impl SledStorage {
     /// Opens or creates a storage database at the given path.
     ///
     /// If the database doesn't exist, it will be created. If it exists,
@@ -100,10 +664,13 @@ impl Storage {
     ///
     /// # Returns
     ///
-    /// A new `Storage` instance or an error if the database cannot be opened.
+    /// A new `SledStorage` instance or an error if the database cannot be opened.
     ///
     /// # Errors
     ///
+    /// Returns `RegistryError::DatabaseLocked` if another process holds the
+    /// database lock (retried briefly before failing).
+    ///
     /// Returns `RegistryError::Database` if:
     /// - The path is invalid
     /// - Permissions are insufficient
@@ -112,91 +679,116 @@ impl Storage {
     /// # Example
     ///
     /// ```rust,no_run
-    /// use sentinel_registry::storage::Storage;
+    /// use sentinel_registry::storage::SledStorage;
     ///
-    /// let storage = Storage::open("./data/registry").unwrap();
+    /// let storage = SledStorage::open("./data/registry").unwrap();
     /// ```
     pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let db = sled::open(path)?;
-        let schemas = db.open_tree(SCHEMA_TREE)?;
-        let hashes = db.open_tree(HASH_TREE)?;
+        Self::from_db(Self::open_db(path.as_ref())?)
+    }
 
-        Ok(Storage {
-            db,
-            schemas,
-            hashes,
-        })
+    /// Opens an existing database, refusing to create one.
+    ///
+    /// Inspection tools (`sentinel status`, `sentinel diff`) use this so a
+    /// mistyped path is reported instead of leaving an empty database behind.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RegistryError::DatabaseMissing` if nothing exists at `path`,
+    /// otherwise as for [`open`](Self::open).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use sentinel_registry::storage::SledStorage;
+    /// use sentinel_registry::RegistryError;
+    ///
+    /// let dir = tempfile::tempdir()?;
+    /// let missing = dir.path().join("registry");
+    ///
+    /// assert!(matches!(
+    ///     SledStorage::open_existing(&missing),
+    ///     Err(RegistryError::DatabaseMissing { .. })
+    /// ));
+    /// assert!(!missing.exists());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn open_existing<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(RegistryError::DatabaseMissing {
+                path: path.display().to_string(),
+            });
+        }
+        Self::open(path)
+    }
+
+    /// Opens the sled database, mapping lock contention to a dedicated error.
+    fn open_db(path: &Path) -> Result<sled::Db> {
+        let mut attempt = 1;
+        loop {
+            match sled::open(path) {
+                Ok(db) => return Ok(db),
+                Err(e) if is_lock_error(&e) => {
+                    if attempt >= LOCK_ATTEMPTS {
+                        return Err(RegistryError::DatabaseLocked {
+                            path: path.display().to_string(),
+                        });
+                    }
+                    attempt += 1;
+                    thread::sleep(LOCK_RETRY_DELAY);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
     }
 
     /// Creates a temporary in-memory storage for testing.
     ///
     /// The database exists only in memory and is lost when the
-    /// `Storage` instance is dropped.
+    /// `SledStorage` instance is dropped.
     ///
     /// # Returns
     ///
-    /// A new in-memory `Storage` instance.
+    /// A new in-memory `SledStorage` instance.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use sentinel_registry::storage::Storage;
+    /// use sentinel_registry::storage::SledStorage;
     ///
-    /// let storage = Storage::temporary().unwrap();
+    /// let storage = SledStorage::temporary().unwrap();
     /// // Use for testing...
     /// // Data is lost when storage is dropped
     /// ```
     pub fn temporary() -> Result<Self> {
         let config = sled::Config::new().temporary(true);
-        let db = config.open()?;
+        Self::from_db(config.open()?)
+    }
+
+    /// Opens the registry's trees in `db`.
+    fn from_db(db: sled::Db) -> Result<Self> {
         let schemas = db.open_tree(SCHEMA_TREE)?;
         let hashes = db.open_tree(HASH_TREE)?;
+        let origins = db.open_tree(ORIGIN_TREE)?;
+        let migrations = db.open_tree(MIGRATION_TREE)?;
+        let audit = db.open_tree(AUDIT_TREE)?;
+        let history = db.open_tree(HISTORY_TREE)?;
 
-        Ok(Storage {
+        Ok(SledStorage {
             db,
             schemas,
             hashes,
+            origins,
+            migrations,
+            audit,
+            history,
         })
     }
+}
 
-    /// Stores a tool schema and its hash.
-    ///
-    /// If a tool with the same name already exists, it will be overwritten.
-    /// Use `load_tool` first if you need to check for existing entries.
-    ///
-    /// # Arguments
-    ///
-    /// * `tool` - The tool schema to store
-    /// * `hash` - The canonical hash of the tool schema
-    ///
-    /// # Returns
-    ///
-    /// `Ok(())` on success, or an error if storage fails.
-    ///
-    /// # Errors
-    ///
-    /// Returns `RegistryError::Serialization` if the schema cannot be serialized.
-    /// Returns `RegistryError::Database` if writing to the database fails.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use sentinel_registry::storage::Storage;
-    /// use sentinel_registry::ToolSchema;
-    /// use serde_json::json;
-    ///
-    /// let storage = Storage::temporary().unwrap();
-    ///
-    /// let tool = ToolSchema {
-    ///     name: "test".to_string(),
-    ///     description: "Test tool".to_string(),
-    ///     input_schema: json!({}),
-    ///     output_schema: json!({}),
-    /// };
-    ///
-    /// storage.store_tool(&tool, [0u8; 32]).unwrap();
-    /// ```
-    pub fn store_tool(&self, tool: &ToolSchema, hash: Hash) -> Result<()> {
+impl Storage for SledStorage {
+    fn store_tool(&self, tool: &ToolSchema, hash: Hash) -> Result<()> {
         let key = tool.name.as_bytes();
         let schema_bytes = serde_json::to_vec(tool)?;
 
@@ -209,34 +801,7 @@ impl Storage {
         Ok(())
     }
 
-    /// Loads a tool schema and its hash by name.
-    ///
-    /// # Arguments
-    ///
-    /// * `name` - The name of the tool to load
-    ///
-    /// # Returns
-    ///
-    /// `Some((schema, hash))` if found, `None` if not found.
-    ///
-    /// # Errors
-    ///
-    /// Returns `RegistryError::Serialization` if the stored schema is corrupted.
-    /// Returns `RegistryError::Database` if reading from the database fails.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use sentinel_registry::storage::Storage;
-    ///
-    /// let storage = Storage::temporary().unwrap();
-    ///
-    /// match storage.load_tool("read_file").unwrap() {
-    ///     Some((tool, hash)) => println!("Found: {}", tool.name),
-    ///     None => println!("Tool not registered"),
-    /// }
-    /// ```
-    pub fn load_tool(&self, name: &str) -> Result<Option<(ToolSchema, Hash)>> {
+    fn load_tool(&self, name: &str) -> Result<Option<(ToolSchema, Hash)>> {
         let key = name.as_bytes();
 
         let schema_bytes = match self.schemas.get(key)? {
@@ -260,51 +825,63 @@ impl Storage {
         Ok(Some((tool, hash)))
     }
 
-    /// Loads only the hash for a tool.
-    ///
-    /// This is more efficient than `load_tool` when only the hash is needed.
-    ///
-    /// # Arguments
-    ///
-    /// * `name` - The name of the tool
-    ///
-    /// # Returns
-    ///
-    /// `Some(hash)` if found, `None` if not found.
-    pub fn load_hash(&self, name: &str) -> Result<Option<Hash>> {
+    fn load_hash(&self, name: &str) -> Result<Option<Hash>> {
         let key = name.as_bytes();
 
-        match self.hashes.get(key)? {
-            Some(bytes) => {
-                if bytes.len() != HASH_SIZE {
-                    return Err(RegistryError::InvalidProof);
-                }
-                let hash: Hash = bytes
-                    .as_ref()
-                    .try_into()
-                    .map_err(|_| RegistryError::InvalidProof)?;
-                Ok(Some(hash))
-            }
+        self.hashes
+            .get(key)?
+            .map(|bytes| decode_hash(&bytes))
+            .transpose()
+    }
+
+    /// Loads every stored hash in a single pass over the database.
+    fn load_all_hashes(&self) -> Result<HashMap<String, Hash>> {
+        self.hashes
+            .iter()
+            .map(|entry| {
+                let (key, bytes) = entry?;
+                let name =
+                    String::from_utf8(key.to_vec()).map_err(|_| RegistryError::InvalidProof)?;
+                Ok((name, decode_hash(&bytes)?))
+            })
+            .collect()
+    }
+
+    fn store_origin(&self, name: &str, origin: &str) -> Result<()> {
+        self.origins.insert(name.as_bytes(), origin.as_bytes())?;
+        Ok(())
+    }
+
+    fn load_origin(&self, name: &str) -> Result<Option<String>> {
+        match self.origins.get(name.as_bytes())? {
+            Some(bytes) => String::from_utf8(bytes.to_vec()).map(Some).map_err(|_| {
+                RegistryError::Corrupt(format!("stored origin of '{}' is not UTF-8", name))
+            }),
             None => Ok(None),
         }
     }
 
-    /// Lists all registered tool names.
-    ///
-    /// # Returns
-    ///
-    /// A vector of tool names in lexicographic order.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use sentinel_registry::storage::Storage;
-    ///
-    /// let storage = Storage::temporary().unwrap();
-    /// let tools = storage.list_tools().unwrap();
-    /// println!("Registered tools: {:?}", tools);
-    /// ```
-    pub fn list_tools(&self) -> Result<Vec<String>> {
+    /// Writes the schema, hash and origin in one transaction.
+    fn store_tool_from(&self, tool: &ToolSchema, hash: Hash, origin: &str) -> Result<()> {
+        let key = tool.name.as_bytes();
+        let schema_bytes = serde_json::to_vec(tool)?;
+
+        let result = (&self.schemas, &self.hashes, &self.origins).transaction(
+            |(schemas, hashes, origins)| {
+                schemas.insert(key, schema_bytes.as_slice())?;
+                hashes.insert(key, hash.as_slice())?;
+                origins.insert(key, origin.as_bytes())?;
+                Ok(())
+            },
+        );
+
+        result.map_err(|e: TransactionError<RegistryError>| match e {
+            TransactionError::Abort(e) => e,
+            TransactionError::Storage(e) => e.into(),
+        })
+    }
+
+    fn list_tools(&self) -> Result<Vec<String>> {
         let mut tools = Vec::new();
 
         for result in self.schemas.iter() {
@@ -316,69 +893,349 @@ impl Storage {
         Ok(tools)
     }
 
-    /// Checks if a tool is registered.
-    ///
-    /// # Arguments
-    ///
-    /// * `name` - The name of the tool to check
-    ///
-    /// # Returns
-    ///
-    /// `true` if the tool exists, `false` otherwise.
-    pub fn contains(&self, name: &str) -> Result<bool> {
+    /// Reads keys only, so a corrupt value cannot fail the listing.
+    fn list_entry_names(&self) -> Result<Vec<String>> {
+        let mut names = BTreeSet::new();
+        for key in self.schemas.iter().keys().chain(self.hashes.iter().keys()) {
+            names.insert(String::from_utf8_lossy(&key?).into_owned());
+        }
+        Ok(names.into_iter().collect())
+    }
+
+    /// Reads entries from the database lazily, one at a time.
+    fn iter_tools(&self) -> Box<dyn Iterator<Item = Result<(String, ToolSchema, Hash)>> + '_> {
+        Box::new(self.schemas.iter().filter_map(move |entry| {
+            let decode = || {
+                let (key, schema_bytes) = entry?;
+                let name =
+                    String::from_utf8(key.to_vec()).map_err(|_| RegistryError::InvalidProof)?;
+                let Some(hash) = self.load_hash(&name)? else {
+                    return Ok(None);
+                };
+                let tool: ToolSchema = serde_json::from_slice(&schema_bytes)?;
+                Ok(Some((name, tool, hash)))
+            };
+            decode().transpose()
+        }))
+    }
+
+    fn contains(&self, name: &str) -> Result<bool> {
         Ok(self.schemas.contains_key(name.as_bytes())?)
     }
 
-    /// Removes a tool from storage.
-    ///
-    /// # Arguments
-    ///
-    /// * `name` - The name of the tool to remove
-    ///
-    /// # Returns
-    ///
-    /// `true` if the tool was removed, `false` if it didn't exist.
-    pub fn remove_tool(&self, name: &str) -> Result<bool> {
+    fn remove_tool(&self, name: &str) -> Result<bool> {
         let key = name.as_bytes();
 
-        // Remove from both trees
+        // Remove from all trees
         let existed = self.schemas.remove(key)?.is_some();
         self.hashes.remove(key)?;
+        self.origins.remove(key)?;
 
         Ok(existed)
     }
 
-    /// Returns the number of registered tools.
-    pub fn len(&self) -> usize {
+    /// Writes the schema, hash, origin, epoch and audit entry in one
+    /// transaction.
+    fn commit_registration(
+        &self,
+        tool: &ToolSchema,
+        hash: Hash,
+        origin: Option<&str>,
+        history: &[ToolSchema],
+        timestamp: u64,
+    ) -> Result<u64> {
+        let key = tool.name.as_bytes();
+        let schema_bytes = serde_json::to_vec(tool)?;
+        let history_bytes = serde_json::to_vec(history)?;
+        let meta: &sled::Tree = &self.db;
+
+        let result = (
+            &self.schemas,
+            &self.hashes,
+            &self.origins,
+            &self.history,
+            meta,
+            &self.audit,
+        )
+            .transaction(|(schemas, hashes, origins, versions, meta, audit)| {
+                schemas.insert(key, schema_bytes.as_slice())?;
+                hashes.insert(key, hash.as_slice())?;
+                if let Some(origin) = origin {
+                    origins.insert(key, origin.as_bytes())?;
+                }
+                versions.insert(key, history_bytes.as_slice())?;
+                let epoch = bump_epoch_in(meta)?;
+                append_audit_in(
+                    audit,
+                    &AuditEntry {
+                        epoch,
+                        action: AuditAction::Registered,
+                        tool: tool.name.clone(),
+                        hash: Some(hash),
+                        timestamp,
+                    },
+                )?;
+                Ok(epoch)
+            });
+
+        result.map_err(|e| match e {
+            TransactionError::Abort(e) => e,
+            TransactionError::Storage(e) => e.into(),
+        })
+    }
+
+    /// Removes the tool and writes the epoch and audit entry in one
+    /// transaction.
+    fn commit_removal(&self, name: &str, timestamp: u64) -> Result<Option<u64>> {
+        let key = name.as_bytes();
+        let meta: &sled::Tree = &self.db;
+
+        let result = (
+            &self.schemas,
+            &self.hashes,
+            &self.origins,
+            meta,
+            &self.audit,
+        )
+            .transaction(|(schemas, hashes, origins, meta, audit)| {
+                let existed = schemas.remove(key)?.is_some();
+                hashes.remove(key)?;
+                origins.remove(key)?;
+                if !existed {
+                    return Ok(None);
+                }
+                let epoch = bump_epoch_in(meta)?;
+                append_audit_in(
+                    audit,
+                    &AuditEntry {
+                        epoch,
+                        action: AuditAction::Removed,
+                        tool: name.to_string(),
+                        hash: None,
+                        timestamp,
+                    },
+                )?;
+                Ok(Some(epoch))
+            });
+
+        result.map_err(|e| match e {
+            TransactionError::Abort(e) => e,
+            TransactionError::Storage(e) => e.into(),
+        })
+    }
+
+    fn load_epoch(&self) -> Result<u64> {
+        match self.db.get(EPOCH_KEY)? {
+            Some(bytes) => decode_epoch(&bytes).ok_or_else(corrupt_epoch),
+            None => Ok(0),
+        }
+    }
+
+    fn bump_epoch(&self) -> Result<u64> {
+        let updated = self.db.update_and_fetch(EPOCH_KEY, |old| match old {
+            // Leave a corrupt value in place so it is reported, not reset
+            Some(bytes) => match decode_epoch(bytes) {
+                Some(current) => Some(current.saturating_add(1).to_be_bytes().to_vec()),
+                None => Some(bytes.to_vec()),
+            },
+            None => Some(1u64.to_be_bytes().to_vec()),
+        })?;
+
+        updated
+            .as_deref()
+            .and_then(decode_epoch)
+            .ok_or_else(corrupt_epoch)
+    }
+
+    fn load_hash_algorithm(&self) -> Result<HashAlgorithm> {
+        match self.db.get(HASH_ALGORITHM_KEY)? {
+            Some(bytes) => std::str::from_utf8(&bytes)
+                .ok()
+                .and_then(HashAlgorithm::from_name)
+                .ok_or_else(|| {
+                    RegistryError::Corrupt("stored hash algorithm is unknown".to_string())
+                }),
+            None => Ok(HashAlgorithm::default()),
+        }
+    }
+
+    fn replace_hashes(
+        &self,
+        hashes: &[(String, Hash)],
+        algorithm: HashAlgorithm,
+        timestamp: u64,
+    ) -> Result<HashMigration> {
+        let from = self.load_hash_algorithm()?;
+        let meta: &sled::Tree = &self.db;
+
+        let result = (&self.hashes, meta, &self.migrations, &self.audit).transaction(
+            |(hash_tree, meta, migrations, audit)| {
+                for (name, hash) in hashes {
+                    hash_tree.insert(name.as_bytes(), hash.as_slice())?;
+                }
+
+                let epoch = bump_epoch_in(meta)?;
+                meta.insert(HASH_ALGORITHM_KEY, algorithm.as_str().as_bytes())?;
+
+                let migration = HashMigration {
+                    from,
+                    to: algorithm,
+                    epoch,
+                    tools: hashes.len(),
+                };
+                let record = serde_json::to_vec(&migration)
+                    .map_err(|e| ConflictableTransactionError::Abort(e.into()))?;
+                migrations.insert(epoch.to_be_bytes().to_vec(), record)?;
+                append_audit_in(
+                    audit,
+                    &AuditEntry {
+                        epoch,
+                        action: AuditAction::Rehashed,
+                        tool: String::new(),
+                        hash: None,
+                        timestamp,
+                    },
+                )?;
+
+                Ok(migration)
+            },
+        );
+
+        result.map_err(|e| match e {
+            TransactionError::Abort(e) => e,
+            TransactionError::Storage(e) => e.into(),
+        })
+    }
+
+    fn load_migrations(&self) -> Result<Vec<HashMigration>> {
+        let mut migrations = Vec::new();
+
+        for result in self.migrations.iter() {
+            let (_, bytes) = result?;
+            migrations.push(serde_json::from_slice(&bytes)?);
+        }
+
+        Ok(migrations)
+    }
+
+    fn append_audit(&self, entry: &AuditEntry) -> Result<()> {
+        let record = serde_json::to_vec(entry)?;
+        self.audit.insert(entry.epoch.to_be_bytes(), record)?;
+        Ok(())
+    }
+
+    fn store_history(&self, name: &str, versions: &[ToolSchema]) -> Result<()> {
+        let record = serde_json::to_vec(versions)?;
+        self.history.insert(name.as_bytes(), record)?;
+        Ok(())
+    }
+
+    fn load_history(&self, name: &str) -> Result<Vec<ToolSchema>> {
+        match self.history.get(name.as_bytes())? {
+            Some(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn load_audit_log(&self) -> Result<Vec<AuditEntry>> {
+        let mut entries = Vec::new();
+
+        for result in self.audit.iter() {
+            let (_, bytes) = result?;
+            entries.push(serde_json::from_slice(&bytes)?);
+        }
+
+        Ok(entries)
+    }
+
+    fn remove_audit(&self, epochs: &[u64]) -> Result<()> {
+        let result = self.audit.transaction(|audit| {
+            for epoch in epochs {
+                audit.remove(&epoch.to_be_bytes())?;
+            }
+            Ok::<_, ConflictableTransactionError<RegistryError>>(())
+        });
+
+        result.map_err(|e| match e {
+            TransactionError::Abort(e) => e,
+            TransactionError::Storage(e) => e.into(),
+        })
+    }
+
+    fn len(&self) -> usize {
         self.schemas.len()
     }
 
-    /// Returns true if no tools are registered.
-    pub fn is_empty(&self) -> bool {
+    fn is_empty(&self) -> bool {
         self.schemas.is_empty()
     }
 
-    /// Flushes all pending writes to disk.
-    ///
-    /// Sled is asynchronous by default. This method ensures all data
-    /// is persisted before returning.
-    ///
-    /// # Returns
-    ///
-    /// The number of bytes flushed.
-    pub fn flush(&self) -> Result<usize> {
+    /// Sled is asynchronous by default. This ensures all data is persisted
+    /// before returning.
+    fn flush(&self) -> Result<usize> {
         Ok(self.db.flush()?)
     }
 }
 
-impl std::fmt::Debug for Storage {
+impl std::fmt::Debug for SledStorage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Storage")
+        f.debug_struct("SledStorage")
             .field("tools_count", &self.len())
             .finish()
     }
 }
 
+/// Decodes a big-endian epoch value.
+fn decode_epoch(bytes: &[u8]) -> Option<u64> {
+    bytes.try_into().ok().map(u64::from_be_bytes)
+}
+
+/// Increments the epoch inside a transaction, returning the new value.
+fn bump_epoch_in(meta: &TransactionalTree) -> ConflictableTransactionResult<u64, RegistryError> {
+    let current = match meta.get(EPOCH_KEY)? {
+        Some(bytes) => decode_epoch(&bytes)
+            .ok_or_else(|| ConflictableTransactionError::Abort(corrupt_epoch()))?,
+        None => 0,
+    };
+    let epoch = current.saturating_add(1);
+    meta.insert(EPOCH_KEY, epoch.to_be_bytes().to_vec())?;
+    Ok(epoch)
+}
+
+/// Appends an audit entry inside a transaction.
+fn append_audit_in(
+    audit: &TransactionalTree,
+    entry: &AuditEntry,
+) -> ConflictableTransactionResult<(), RegistryError> {
+    let record =
+        serde_json::to_vec(entry).map_err(|e| ConflictableTransactionError::Abort(e.into()))?;
+    audit.insert(&entry.epoch.to_be_bytes(), record)?;
+    Ok(())
+}
+
+/// Error for a stored epoch that is not a big-endian `u64`.
+fn corrupt_epoch() -> RegistryError {
+    RegistryError::Corrupt("stored epoch is not a valid u64".to_string())
+}
+
+/// Returns true if a sled error indicates the database file lock is held.
+fn is_lock_error(error: &sled::Error) -> bool {
+    match error {
+        sled::Error::Io(io) => {
+            io.kind() == ErrorKind::WouldBlock || io.to_string().contains("could not acquire lock")
+        }
+        _ => false,
+    }
+}
+
+/// Decodes a stored hash, rejecting values of the wrong length.
+fn decode_hash(bytes: &[u8]) -> Result<Hash> {
+    if bytes.len() != HASH_SIZE {
+        return Err(RegistryError::InvalidProof);
+    }
+    bytes.try_into().map_err(|_| RegistryError::InvalidProof)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -395,14 +1252,14 @@ mod tests {
 
     #[test]
     fn test_temporary_storage() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
         assert!(storage.is_empty());
         assert_eq!(storage.len(), 0);
     }
 
     #[test]
     fn test_store_and_load() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
         let tool = make_tool("test_tool");
         let hash = [42u8; 32];
 
@@ -415,13 +1272,13 @@ mod tests {
 
     #[test]
     fn test_load_nonexistent() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
         assert!(storage.load_tool("nonexistent").unwrap().is_none());
     }
 
     #[test]
     fn test_load_hash() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
         let tool = make_tool("test");
         let hash = [1u8; 32];
 
@@ -433,7 +1290,7 @@ mod tests {
 
     #[test]
     fn test_list_tools() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
 
         storage.store_tool(&make_tool("alpha"), [1u8; 32]).unwrap();
         storage.store_tool(&make_tool("beta"), [2u8; 32]).unwrap();
@@ -448,7 +1305,7 @@ mod tests {
 
     #[test]
     fn test_contains() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
         let tool = make_tool("exists");
 
         assert!(!storage.contains("exists").unwrap());
@@ -461,7 +1318,7 @@ mod tests {
 
     #[test]
     fn test_remove_tool() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
         let tool = make_tool("removable");
 
         storage.store_tool(&tool, [0u8; 32]).unwrap();
@@ -477,7 +1334,7 @@ mod tests {
 
     #[test]
     fn test_overwrite() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
         let tool = make_tool("overwrite");
 
         storage.store_tool(&tool, [1u8; 32]).unwrap();
@@ -491,7 +1348,7 @@ mod tests {
 
     #[test]
     fn test_len_and_empty() {
-        let storage = Storage::temporary().unwrap();
+        let storage = SledStorage::temporary().unwrap();
 
         assert!(storage.is_empty());
         assert_eq!(storage.len(), 0);
@@ -503,4 +1360,116 @@ mod tests {
         storage.store_tool(&make_tool("b"), [0u8; 32]).unwrap();
         assert_eq!(storage.len(), 2);
     }
+
+    #[test]
+    fn test_store_tool_from_writes_origin() {
+        let storage = SledStorage::temporary().unwrap();
+
+        storage
+            .store_tool_from(&make_tool("pinned"), [3u8; 32], "stdio:fs")
+            .unwrap();
+        assert_eq!(storage.load_hash("pinned").unwrap(), Some([3u8; 32]));
+        assert_eq!(
+            storage.load_origin("pinned").unwrap().as_deref(),
+            Some("stdio:fs")
+        );
+    }
+
+    #[test]
+    fn test_corrupt_origin_is_storage_error() {
+        let storage = SledStorage::temporary().unwrap();
+        storage.origins.insert(b"t", vec![0xff, 0xfe]).unwrap();
+
+        assert!(matches!(
+            storage.load_origin("t"),
+            Err(RegistryError::Corrupt(_))
+        ));
+    }
+
+    #[test]
+    fn test_corrupt_epoch_is_storage_error() {
+        let storage = SledStorage::temporary().unwrap();
+        storage.db.insert(EPOCH_KEY, b"bad".to_vec()).unwrap();
+
+        assert!(matches!(
+            storage.load_epoch(),
+            Err(RegistryError::Corrupt(_))
+        ));
+        assert!(matches!(
+            storage.bump_epoch(),
+            Err(RegistryError::Corrupt(_))
+        ));
+        // The corrupt value is not silently reset to a low epoch
+        assert!(storage.load_epoch().is_err());
+    }
+
+    #[test]
+    fn test_unknown_hash_algorithm_is_storage_error() {
+        let storage = SledStorage::temporary().unwrap();
+        storage
+            .db
+            .insert(HASH_ALGORITHM_KEY, b"md5".to_vec())
+            .unwrap();
+
+        assert!(matches!(
+            storage.load_hash_algorithm(),
+            Err(RegistryError::Corrupt(_))
+        ));
+    }
+
+    #[test]
+    fn test_commit_registration_writes_epoch_and_audit() {
+        let storage = SledStorage::temporary().unwrap();
+        let tool = make_tool("audited");
+
+        let epoch = storage
+            .commit_registration(
+                &tool,
+                [1u8; 32],
+                Some("server-a"),
+                std::slice::from_ref(&tool),
+                42,
+            )
+            .unwrap();
+        assert_eq!(epoch, 1);
+        assert_eq!(storage.load_epoch().unwrap(), 1);
+        assert_eq!(storage.load_history("audited").unwrap(), vec![tool.clone()]);
+        assert_eq!(
+            storage.load_origin("audited").unwrap().as_deref(),
+            Some("server-a")
+        );
+        assert_eq!(
+            storage.load_audit_log().unwrap(),
+            vec![AuditEntry {
+                epoch: 1,
+                action: AuditAction::Registered,
+                tool: "audited".to_string(),
+                hash: Some([1u8; 32]),
+                timestamp: 42,
+            }]
+        );
+
+        assert_eq!(storage.commit_removal("audited", 43).unwrap(), Some(2));
+        assert_eq!(storage.commit_removal("audited", 44).unwrap(), None);
+        let log = storage.load_audit_log().unwrap();
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[1].action, AuditAction::Removed);
+    }
+
+    #[test]
+    fn test_commit_registration_is_atomic() {
+        let storage = SledStorage::temporary().unwrap();
+        storage.db.insert(EPOCH_KEY, b"bad".to_vec()).unwrap();
+
+        // The epoch bump fails, so the schema, history and audit entry are
+        // not written
+        let tool = make_tool("orphan");
+        assert!(matches!(
+            storage.commit_registration(&tool, [1u8; 32], None, std::slice::from_ref(&tool), 0),
+            Err(RegistryError::Corrupt(_))
+        ));
+        assert!(storage.load_tool("orphan").unwrap().is_none());
+        assert!(storage.load_history("orphan").unwrap().is_empty());
+        assert!(storage.load_audit_log().unwrap().is_empty());
+    }
 }
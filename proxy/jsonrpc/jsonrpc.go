@@ -31,9 +31,16 @@
 //   - Method string format
 //
 // Invalid messages return parsing errors rather than partial results.
+//
+// # Batches
+//
+// JSON-RPC 2.0 allows an array of messages to be sent as a batch. Use
+// IsBatch to detect one and ParseBatch to split it into raw elements,
+// which are then parsed and validated individually with Parse.
 package jsonrpc
 
 import (
+	"bytes"
 	"encoding/json"
 	"errors"
 	"fmt"
@@ -48,6 +55,7 @@ var (
 	ErrInvalidVersion = errors.New("jsonrpc: version must be 2.0")
 	ErrMissingMethod  = errors.New("jsonrpc: missing method field")
 	ErrInvalidID      = errors.New("jsonrpc: invalid id type")
+	ErrEmptyBatch     = errors.New("jsonrpc: empty batch")
 )
 
 // JSON-RPC 2.0 error codes.
@@ -193,6 +201,44 @@ func Parse(data []byte) (*Message, error) {
 	return &msg, nil
 }
 
+// IsBatch reports whether data is a JSON-RPC batch (a JSON array).
+//
+// Only the first non-whitespace byte is inspected; use ParseBatch to
+// split and validate the batch.
+func IsBatch(data []byte) bool {
+	trimmed := bytes.TrimLeft(data, " \t\r\n")
+	return len(trimmed) > 0 && trimmed[0] == '['
+}
+
+// ParseBatch splits a JSON-RPC batch into its raw elements.
+//
+// Elements are returned unparsed so each can be validated independently
+// with Parse: per JSON-RPC 2.0, one malformed element must not invalidate
+// the rest of the batch.
+//
+// # Arguments
+//   - data: Raw JSON bytes of the batch array
+//
+// # Returns
+//   - Raw element bytes, in batch order
+//   - ErrInvalidJSON if data is not a JSON array
+//   - ErrEmptyBatch if the array has no elements
+func ParseBatch(data []byte) ([]json.RawMessage, error) {
+	var elems []json.RawMessage
+	if err := json.Unmarshal(data, &elems); err != nil {
+		return nil, fmt.Errorf("%w: %v", ErrInvalidJSON, err)
+	}
+	if len(elems) == 0 {
+		return nil, ErrEmptyBatch
+	}
+	return elems, nil
+}
+
+// SerializeBatch combines serialized messages into a JSON-RPC batch array.
+func SerializeBatch(msgs []json.RawMessage) ([]byte, error) {
+	return json.Marshal(msgs)
+}
+
 // Serialize converts a Message to JSON bytes.
 //
 // # Arguments
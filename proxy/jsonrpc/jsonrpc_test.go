@@ -84,6 +84,49 @@ func TestParse_WrongVersion(t *testing.T) {
 	}
 }
 
+func TestIsBatch(t *testing.T) {
+	tests := []struct {
+		data     string
+		expected bool
+	}{
+		{`[{"jsonrpc":"2.0","method":"ping","id":1}]`, true},
+		{"  \n[]", true},
+		{`{"jsonrpc":"2.0","method":"ping","id":1}`, false},
+		{"", false},
+	}
+
+	for _, tt := range tests {
+		if result := IsBatch([]byte(tt.data)); result != tt.expected {
+			t.Errorf("IsBatch(%q) = %v, expected %v", tt.data, result, tt.expected)
+		}
+	}
+}
+
+func TestParseBatch(t *testing.T) {
+	data := []byte(`[{"jsonrpc":"2.0","method":"ping","id":1},{"jsonrpc":"1.0"}]`)
+	elems, err := ParseBatch(data)
+	if err != nil {
+		t.Fatalf("ParseBatch failed: %v", err)
+	}
+	if len(elems) != 2 {
+		t.Fatalf("expected 2 elements, got %d", len(elems))
+	}
+
+	// Elements are validated individually
+	if _, err := Parse(elems[0]); err != nil {
+		t.Errorf("expected first element to parse: %v", err)
+	}
+	if _, err := Parse(elems[1]); err != ErrInvalidVersion {
+		t.Errorf("expected ErrInvalidVersion for second element, got %v", err)
+	}
+}
+
+func TestParseBatch_Empty(t *testing.T) {
+	if _, err := ParseBatch([]byte(`[]`)); err != ErrEmptyBatch {
+		t.Errorf("expected ErrEmptyBatch, got %v", err)
+	}
+}
+
 func TestSerialize(t *testing.T) {
 	msg := &Message{
 		JSONRPC: Version,
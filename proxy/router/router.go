@@ -29,11 +29,20 @@
 //
 // Router is safe for concurrent use. Multiple goroutines can
 // call RouteMessage simultaneously.
+//
+// # Batches
+//
+// JSON-RPC batches are screened element by element so a malicious
+// request cannot ride along with benign ones. Blocked elements are
+// answered locally with errors; allowed elements are forwarded to the
+// server together (partial forward). See routeBatch for details.
 package router
 
 import (
+	"bytes"
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"sync"
 	"sync/atomic"
@@ -70,6 +79,10 @@ type Router struct {
 	// forwardFunc sends messages to the MCP server
 	// Can be replaced for testing
 	forwardFunc func([]byte) ([]byte, error)
+
+	// checkFunc runs security checks for a tool call
+	// Can be replaced for testing
+	checkFunc func(*jsonrpc.Message) (*sentinel.CheckResult, error)
 }
 
 // Stats contains routing statistics.
@@ -121,8 +134,9 @@ func NewWithConfig(t transport.Transport, s *sentinel.Client, cfg *Config) *Rout
 		sessionID:     cfg.SessionID,
 		previousTools: make([]string, 0, 100),
 	}
-	// Default forward function (can be replaced for testing)
+	// Default forward and check functions (can be replaced for testing)
 	r.forwardFunc = r.defaultForward
+	r.checkFunc = r.checkToolCall
 	return r
 }
 
@@ -133,11 +147,14 @@ func NewWithConfig(t transport.Transport, s *sentinel.Client, cfg *Config) *Rout
 //   2. Runs security checks for tool calls
 //   3. Forwards allowed messages or returns error responses
 //
+// Batches (JSON arrays) are dispatched to routeBatch.
+//
 // # Arguments
-//   - data: Raw JSON-RPC message bytes
+//   - data: Raw JSON-RPC message or batch bytes
 //
 // # Returns
-//   - Response bytes (forwarded response or error)
+//   - Response bytes (forwarded response or error); empty when a batch
+//     produces no responses (e.g. it contains only notifications)
 //   - Error if processing fails
 //
 // # Security Notes
@@ -145,6 +162,10 @@ func NewWithConfig(t transport.Transport, s *sentinel.Client, cfg *Config) *Rout
 // All tool call messages (tools/call) are checked by sentinel.
 // Non-tool messages are forwarded without security checks.
 func (r *Router) RouteMessage(data []byte) ([]byte, error) {
+	if jsonrpc.IsBatch(data) {
+		return r.routeBatch(data)
+	}
+
 	r.stats.MessagesReceived.Add(1)
 
 	// Parse JSON-RPC message
@@ -155,16 +176,8 @@ func (r *Router) RouteMessage(data []byte) ([]byte, error) {
 	}
 
 	// Only check tool calls
-	if msg.Method == "tools/call" {
-		result, err := r.checkToolCall(msg)
-		if err != nil {
-			r.stats.Errors.Add(1)
-			return r.errorResponse(msg.ID, jsonrpc.InternalError, "Security check failed", err.Error())
-		}
-		if !result.Allowed {
-			r.stats.MessagesBlocked.Add(1)
-			return r.errorResponse(msg.ID, jsonrpc.InvalidRequest, "Blocked by security", result.Reason)
-		}
+	if rejection, err := r.screen(msg); rejection != nil || err != nil {
+		return rejection, err
 	}
 
 	// Forward message to server
@@ -178,6 +191,114 @@ func (r *Router) RouteMessage(data []byte) ([]byte, error) {
 	return response, nil
 }
 
+// routeBatch routes a JSON-RPC batch, screening each element independently.
+//
+// Batch semantics (partial forward):
+//   - Each element is parsed and, for tools/call, security-checked on its
+//     own; the outcome of one element never affects another.
+//   - Malformed or blocked elements are answered locally with a JSON-RPC
+//     error carrying that element's id. Blocked notifications are dropped
+//     without a response, since notifications are never answered.
+//   - Allowed elements are forwarded to the server together as a single
+//     batch, and the server's responses are merged with the local errors.
+//     Response order is unspecified, as permitted by JSON-RPC 2.0.
+//   - An empty batch is answered with a single InvalidRequest error.
+//   - If the batch yields no responses at all, nil is returned and the
+//     caller must not send anything back.
+func (r *Router) routeBatch(data []byte) ([]byte, error) {
+	elems, err := jsonrpc.ParseBatch(data)
+	if err != nil {
+		r.stats.MessagesReceived.Add(1)
+		r.stats.Errors.Add(1)
+		if errors.Is(err, jsonrpc.ErrEmptyBatch) {
+			return r.errorResponse(nil, jsonrpc.InvalidRequest, "Invalid Request", err.Error())
+		}
+		return r.errorResponse(nil, jsonrpc.ParseError, "Parse error", err.Error())
+	}
+	r.stats.MessagesReceived.Add(uint64(len(elems)))
+
+	var forward, responses []json.RawMessage
+	for _, elem := range elems {
+		msg, err := jsonrpc.Parse(elem)
+		if err != nil {
+			r.stats.Errors.Add(1)
+			resp, rerr := r.errorResponse(nil, jsonrpc.InvalidRequest, "Invalid Request", err.Error())
+			if rerr != nil {
+				return nil, rerr
+			}
+			responses = append(responses, resp)
+			continue
+		}
+
+		rejection, err := r.screen(msg)
+		if err != nil {
+			return nil, err
+		}
+		if rejection != nil {
+			if msg.Type() != jsonrpc.TypeNotification {
+				responses = append(responses, rejection)
+			}
+			continue
+		}
+
+		forward = append(forward, elem)
+	}
+
+	if len(forward) > 0 {
+		batch, err := jsonrpc.SerializeBatch(forward)
+		if err != nil {
+			return nil, fmt.Errorf("router: encode batch failed: %w", err)
+		}
+
+		upstream, err := r.forwardFunc(batch)
+		if err != nil {
+			r.stats.Errors.Add(1)
+			return nil, fmt.Errorf("router: forward failed: %w", err)
+		}
+		r.stats.MessagesForwarded.Add(uint64(len(forward)))
+
+		switch {
+		case len(bytes.TrimSpace(upstream)) == 0:
+			// Server had nothing to say (all forwarded elements were notifications)
+		case jsonrpc.IsBatch(upstream):
+			var upstreamResponses []json.RawMessage
+			if err := json.Unmarshal(upstream, &upstreamResponses); err != nil {
+				r.stats.Errors.Add(1)
+				return nil, fmt.Errorf("router: invalid batch response: %w", err)
+			}
+			responses = append(responses, upstreamResponses...)
+		default:
+			responses = append(responses, upstream)
+		}
+	}
+
+	if len(responses) == 0 {
+		return nil, nil
+	}
+	return jsonrpc.SerializeBatch(responses)
+}
+
+// screen runs security checks on a parsed message.
+//
+// Returns a serialized error response if the message must not be
+// forwarded, or nil if it may proceed. Non-tool messages always proceed.
+func (r *Router) screen(msg *jsonrpc.Message) ([]byte, error) {
+	if msg.Method != "tools/call" {
+		return nil, nil
+	}
+
+	result, err := r.checkFunc(msg)
+	if err != nil {
+		r.stats.Errors.Add(1)
+		return r.errorResponse(msg.ID, jsonrpc.InternalError, "Security check failed", err.Error())
+	}
+	if !result.Allowed {
+		r.stats.MessagesBlocked.Add(1)
+		return r.errorResponse(msg.ID, jsonrpc.InvalidRequest, "Blocked by security", result.Reason)
+	}
+	return nil, nil
+}
+
 // checkToolCall runs security checks for a tool call message.
 func (r *Router) checkToolCall(msg *jsonrpc.Message) (*sentinel.CheckResult, error) {
 	toolName := jsonrpc.ExtractToolName(msg)
@@ -277,6 +398,10 @@ func (r *Router) Run(ctx context.Context) error {
 			// Log error but continue processing
 			continue
 		}
+		if len(response) == 0 {
+			// Nothing to answer (e.g. a batch of notifications)
+			continue
+		}
 
 		// Send response back to client
 		if err := r.transport.Send(response); err != nil {
@@ -212,6 +212,103 @@ func TestRouteMessage_HighRiskTool(t *testing.T) {
 	}
 }
 
+func TestRouteMessage_BatchPartialForward(t *testing.T) {
+	mt := &mockTransport{}
+	s := sentinel.NewClient()
+	r := New(mt, s)
+
+	// Stand in for the registry: block any tool that is not registered
+	known := map[string]bool{"read_file": true}
+	r.checkFunc = func(msg *jsonrpc.Message) (*sentinel.CheckResult, error) {
+		name := jsonrpc.ExtractToolName(msg)
+		if !known[name] {
+			return &sentinel.CheckResult{Allowed: false, Reason: "unknown tool: " + name}, nil
+		}
+		return &sentinel.CheckResult{Allowed: true}, nil
+	}
+
+	// Capture what reaches the server
+	var forwarded []json.RawMessage
+	r.forwardFunc = func(data []byte) ([]byte, error) {
+		if err := json.Unmarshal(data, &forwarded); err != nil {
+			t.Fatalf("expected a batch to be forwarded: %v", err)
+		}
+		resp, _ := jsonrpc.NewResponse(json.RawMessage(`1`), "ok")
+		out, _ := jsonrpc.Serialize(resp)
+		return jsonrpc.SerializeBatch([]json.RawMessage{out})
+	}
+
+	allowed, _ := jsonrpc.NewRequest("tools/call", map[string]interface{}{"name": "read_file"}, 1)
+	blocked, _ := jsonrpc.NewRequest("tools/call", map[string]interface{}{"name": "evil_tool"}, 2)
+	a, _ := jsonrpc.Serialize(allowed)
+	b, _ := jsonrpc.Serialize(blocked)
+	batch, _ := jsonrpc.SerializeBatch([]json.RawMessage{a, b})
+
+	response, err := r.RouteMessage(batch)
+	if err != nil {
+		t.Fatalf("RouteMessage failed: %v", err)
+	}
+
+	// Only the allowed element reaches the server
+	if len(forwarded) != 1 {
+		t.Fatalf("expected 1 forwarded element, got %d", len(forwarded))
+	}
+	fwd, _ := jsonrpc.Parse(forwarded[0])
+	if name := jsonrpc.ExtractToolName(fwd); name != "read_file" {
+		t.Errorf("expected read_file to be forwarded, got %q", name)
+	}
+
+	// Both elements are answered: success for id 1, error for id 2
+	var responses []json.RawMessage
+	if err := json.Unmarshal(response, &responses); err != nil {
+		t.Fatalf("expected batch response: %v", err)
+	}
+	if len(responses) != 2 {
+		t.Fatalf("expected 2 responses, got %d", len(responses))
+	}
+
+	byID := make(map[string]*jsonrpc.Message)
+	for _, raw := range responses {
+		msg, err := jsonrpc.Parse(raw)
+		if err != nil {
+			t.Fatalf("failed to parse response element: %v", err)
+		}
+		byID[string(msg.ID)] = msg
+	}
+	if resp := byID["1"]; resp == nil || resp.Error != nil {
+		t.Errorf("expected success response for id 1, got %+v", resp)
+	}
+	if resp := byID["2"]; resp == nil || resp.Error == nil {
+		t.Errorf("expected error response for id 2, got %+v", resp)
+	} else if resp.Error.Code != jsonrpc.InvalidRequest {
+		t.Errorf("expected InvalidRequest code %d, got %d", jsonrpc.InvalidRequest, resp.Error.Code)
+	}
+
+	received, fwdCount, blockedCount, _ := r.GetStats()
+	if received != 2 || fwdCount != 1 || blockedCount != 1 {
+		t.Errorf("expected 2/1/1 received/forwarded/blocked, got %d/%d/%d", received, fwdCount, blockedCount)
+	}
+}
+
+func TestRouteMessage_EmptyBatch(t *testing.T) {
+	mt := &mockTransport{}
+	s := sentinel.NewClient()
+	r := New(mt, s)
+
+	response, err := r.RouteMessage([]byte(`[]`))
+	if err != nil {
+		t.Fatalf("RouteMessage failed: %v", err)
+	}
+
+	resp, err := jsonrpc.Parse(response)
+	if err != nil {
+		t.Fatalf("failed to parse error response: %v", err)
+	}
+	if resp.Error == nil || resp.Error.Code != jsonrpc.InvalidRequest {
+		t.Errorf("expected InvalidRequest error, got %+v", resp.Error)
+	}
+}
+
 func TestIsHighRiskTool(t *testing.T) {
 	tests := []struct {
 		name     string